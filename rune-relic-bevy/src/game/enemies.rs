@@ -8,7 +8,7 @@ use bevy::prelude::*;
 use rand::Rng;
 
 use super::{
-    Velocity, MoveSpeed, Radius,
+    ArenaBounds, Velocity, MoveSpeed, Radius,
     Enemy, ChaseTarget, Player, SnakeHead, BodyEmitter,
 };
 
@@ -314,18 +314,16 @@ pub fn enemy_ai(
 
 /// Keep enemies within arena bounds
 pub fn clamp_enemies_to_arena(
+    arena_bounds: Res<ArenaBounds>,
     mut query: Query<&mut Transform, With<Enemy>>,
 ) {
-    const ARENA_RADIUS: f32 = 950.0;
+    // Enemies may drift a little past the edge the player is held to
+    const EDGE_MARGIN: f32 = 50.0;
+    let half_extents = arena_bounds.0 + Vec2::splat(EDGE_MARGIN);
 
     for mut transform in query.iter_mut() {
-        let pos = transform.translation.truncate();
-        let distance = pos.length();
-
-        if distance > ARENA_RADIUS {
-            let clamped = pos.normalize() * ARENA_RADIUS;
-            transform.translation.x = clamped.x;
-            transform.translation.y = clamped.y;
-        }
+        let clamped = transform.translation.truncate().clamp(-half_extents, half_extents);
+        transform.translation.x = clamped.x;
+        transform.translation.y = clamped.y;
     }
 }
@@ -86,6 +86,7 @@ impl Plugin for GamePlugin {
             .init_resource::<GameTime>()
             .init_resource::<WaveState>()
             .init_resource::<BodyState>()
+            .init_resource::<ArenaBounds>()
 
             // Events
             .add_event::<EnemyDeathEvent>()
@@ -150,6 +151,16 @@ pub struct GameTime {
     pub elapsed: f32,
 }
 
+/// Half extents of the arena rectangle, from the server's walkable map
+#[derive(Resource, Clone, Copy)]
+pub struct ArenaBounds(pub Vec2);
+
+impl Default for ArenaBounds {
+    fn default() -> Self {
+        Self(Vec2::splat(900.0))
+    }
+}
+
 // ============================================================================
 // EVENTS
 // ============================================================================
@@ -175,6 +186,7 @@ fn setup_game(
     mut game_time: ResMut<GameTime>,
     mut wave_state: ResMut<WaveState>,
     mut body_state: ResMut<BodyState>,
+    arena_bounds: Res<ArenaBounds>,
 ) {
     info!("Setting up True Slither.io style game...");
 
@@ -190,7 +202,7 @@ fn setup_game(
     commands.spawn((
         Sprite {
             color: Color::srgba(0.08, 0.08, 0.12, 0.8),
-            custom_size: Some(Vec2::splat(2000.0)),
+            custom_size: Some(arena_bounds.0 * 2.0 + Vec2::splat(200.0)),
             ..default()
         },
         Transform::from_translation(Vec3::new(0.0, 0.0, -100.0)),
@@ -198,19 +210,27 @@ fn setup_game(
     ));
 
     // Spawn arena boundary visualization
-    spawn_arena_boundary(&mut commands);
+    spawn_arena_boundary(&mut commands, arena_bounds.0);
 
     info!("Game ready! Cut off enemies with your body!");
 }
 
-/// Spawn arena boundary ring (visual only, no collision death)
-fn spawn_arena_boundary(commands: &mut Commands) {
-    const ARENA_RADIUS: f32 = 900.0;
-    const RING_SEGMENTS: usize = 64;
-
-    for i in 0..RING_SEGMENTS {
-        let angle = (i as f32 / RING_SEGMENTS as f32) * std::f32::consts::TAU;
-        let pos = Vec2::new(angle.cos(), angle.sin()) * ARENA_RADIUS;
+/// Spawn arena boundary outline (visual only, no collision death)
+fn spawn_arena_boundary(commands: &mut Commands, half_extents: Vec2) {
+    const DOTS_PER_SIDE: usize = 16;
+
+    let corners = [
+        Vec2::new(-half_extents.x, -half_extents.y),
+        Vec2::new(half_extents.x, -half_extents.y),
+        Vec2::new(half_extents.x, half_extents.y),
+        Vec2::new(-half_extents.x, half_extents.y),
+    ];
+
+    for i in 0..corners.len() * DOTS_PER_SIDE {
+        let (side, step) = (i / DOTS_PER_SIDE, i % DOTS_PER_SIDE);
+        let from = corners[side];
+        let to = corners[(side + 1) % corners.len()];
+        let pos = from.lerp(to, step as f32 / DOTS_PER_SIDE as f32);
 
         commands.spawn((
             Sprite {
@@ -3,7 +3,7 @@
 use bevy::prelude::*;
 
 use super::{
-    PlayerForm, Velocity, MoveSpeed, Radius,
+    ArenaBounds, PlayerForm, Velocity, MoveSpeed, Radius,
     Player, LocalPlayer, SnakeHead, BodyEmitter,
 };
 
@@ -109,18 +109,14 @@ pub fn apply_velocity(
 
 /// Keep player within arena bounds (soft clamp, not death)
 pub fn clamp_to_arena(
+    arena_bounds: Res<ArenaBounds>,
     mut query: Query<&mut Transform, With<Player>>,
 ) {
-    const ARENA_RADIUS: f32 = 900.0;
+    let half_extents = arena_bounds.0;
 
     for mut transform in query.iter_mut() {
-        let pos = transform.translation.truncate();
-        let distance = pos.length();
-
-        if distance > ARENA_RADIUS {
-            let clamped = pos.normalize() * ARENA_RADIUS;
-            transform.translation.x = clamped.x;
-            transform.translation.y = clamped.y;
-        }
+        let clamped = transform.translation.truncate().clamp(-half_extents, half_extents);
+        transform.translation.x = clamped.x;
+        transform.translation.y = clamped.y;
     }
 }
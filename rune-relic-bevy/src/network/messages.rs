@@ -105,6 +105,11 @@ pub enum ServerMessage {
         start_tick: u32,
         players: Vec<PlayerInfo>,
         block_hash: [u8; 32],
+        /// Walkable map half extents (Q16.16); zero from older servers.
+        #[serde(default)]
+        map_half_width: i32,
+        #[serde(default)]
+        map_half_height: i32,
     },
     State {
         tick: u32,
@@ -228,6 +233,9 @@ pub enum GameEvent {
 /// Fixed-point scale factor (Q16.16)
 pub const FIXED_SCALE: f32 = 65536.0;
 
+/// World pixels per server unit (the stock map fits the old 900 px ring)
+pub const WORLD_UNITS_PER_SERVER_UNIT: f32 = 4.5;
+
 /// Convert fixed-point to float
 pub fn fixed_to_float(fixed: i32) -> f32 {
     fixed as f32 / FIXED_SCALE
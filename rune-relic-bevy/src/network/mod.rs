@@ -9,6 +9,7 @@ pub use client::*;
 use bevy::prelude::*;
 
 use crate::AppState;
+use crate::game::ArenaBounds;
 
 pub struct NetworkPlugin;
 
@@ -66,7 +67,7 @@ pub enum NetworkEvent {
     AuthSuccess { session_id: String },
     AuthFailed { error: String },
     MatchFound { match_id: [u8; 16], players: Vec<[u8; 16]> },
-    MatchStart { rng_seed: u64 },
+    MatchStart { rng_seed: u64, map_half_extents: Option<Vec2> },
     MatchEnd { winner: Option<[u8; 16]> },
     Error(String),
 }
@@ -115,6 +116,7 @@ fn handle_network_events(
     mut events: EventReader<NetworkEvent>,
     mut state: ResMut<NetworkState>,
     mut app_state: ResMut<NextState<AppState>>,
+    mut arena_bounds: ResMut<ArenaBounds>,
 ) {
     for event in events.read() {
         match event {
@@ -152,8 +154,9 @@ fn handle_network_events(
                 info!("Match found! {} players", players.len());
                 app_state.set(AppState::ReadyCheck);
             }
-            NetworkEvent::MatchStart { rng_seed } => {
+            NetworkEvent::MatchStart { rng_seed, map_half_extents } => {
                 info!("Match starting! Seed: {}", rng_seed);
+                *arena_bounds = map_half_extents.map_or_else(ArenaBounds::default, ArenaBounds);
                 state.in_match = true;
                 app_state.set(AppState::Playing);
             }
@@ -209,8 +212,12 @@ fn process_server_messages(
                     players: player_ids
                 });
             }
-            ServerMessage::MatchStart { rng_seed, .. } => {
-                events.send(NetworkEvent::MatchStart { rng_seed });
+            ServerMessage::MatchStart { rng_seed, map_half_width, map_half_height, .. } => {
+                let map_half_extents = (map_half_width > 0 && map_half_height > 0).then(|| {
+                    Vec2::new(fixed_to_float(map_half_width), fixed_to_float(map_half_height))
+                        * WORLD_UNITS_PER_SERVER_UNIT
+                });
+                events.send(NetworkEvent::MatchStart { rng_seed, map_half_extents });
             }
             ServerMessage::MatchEnd { winner_id, .. } => {
                 events.send(NetworkEvent::MatchEnd { winner: winner_id });
@@ -114,6 +114,23 @@ namespace RuneRelic.Game
             }
         }
 
+        /// <summary>
+        /// Place the player at an externally smoothed position (spectator mode).
+        /// </summary>
+        public void SetSmoothedPosition(Vector3 position)
+        {
+            if (!_alive) return;
+
+            Vector3 direction = position - transform.position;
+            transform.position = position;
+
+            if (direction.sqrMagnitude > 0.001f)
+            {
+                Quaternion targetRotation = Quaternion.LookRotation(direction);
+                transform.rotation = Quaternion.Slerp(transform.rotation, targetRotation, Time.deltaTime * 10f);
+            }
+        }
+
         /// <summary>
         /// Change player form (evolution).
         /// </summary>
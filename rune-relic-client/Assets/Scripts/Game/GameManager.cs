@@ -136,7 +136,7 @@ namespace RuneRelic.Game
             }
 
             // Setup arena
-            SetupArena();
+            SetupArena(info);
         }
 
         private void HandleStateUpdate(GameStateUpdate update)
@@ -298,6 +298,15 @@ namespace RuneRelic.Game
 
         private void UpdatePlayerInterpolation()
         {
+            if (_matchState != null && _matchState.Smoothing == SmoothingMode.Spectator)
+            {
+                foreach (var kvp in _playerVisuals)
+                {
+                    kvp.Value.SetSmoothedPosition(_matchState.GetInterpolatedPosition(kvp.Key));
+                }
+                return;
+            }
+
             float t = _matchState?.GetInterpolationT() ?? 0f;
 
             foreach (var visual in _playerVisuals.Values)
@@ -482,14 +491,23 @@ namespace RuneRelic.Game
         // Setup & Cleanup
         // =====================================================================
 
-        private void SetupArena()
+        private void SetupArena(MatchStartInfo info)
         {
+            // Size the floor to the walkable map the server sends; the constants
+            // are only a fallback for servers that do not send it
+            float width = info.map_half_width > 0
+                ? FixedPoint.ToFloat(info.map_half_width) * 2f
+                : Constants.ARENA_WIDTH;
+            float height = info.map_half_height > 0
+                ? FixedPoint.ToFloat(info.map_half_height) * 2f
+                : Constants.ARENA_HEIGHT;
+
             if (arenaFloor != null)
             {
                 arenaFloor.transform.localScale = new Vector3(
-                    Constants.ARENA_WIDTH / 10f,
+                    width / 10f,
                     1f,
-                    Constants.ARENA_HEIGHT / 10f
+                    height / 10f
                 );
             }
         }
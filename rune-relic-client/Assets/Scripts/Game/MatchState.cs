@@ -27,9 +27,16 @@ namespace RuneRelic.Game
         private GameStateUpdate _currentState;
         private float _lastUpdateTime;
 
+        // Spectator smoothing (always fed so switching modes is seamless)
+        private readonly SpectatorSmoothing _spectatorSmoothing = new SpectatorSmoothing();
+        public SmoothingMode Smoothing { get; private set; } = SmoothingMode.Standard;
+
         // Player states (latest)
         public Dictionary<string, PlayerState> Players { get; } = new Dictionary<string, PlayerState>();
 
+        // Server standings in canonical placement order (for HUD / spectators)
+        public List<LeaderboardEntry> Leaderboard { get; private set; } = new List<LeaderboardEntry>();
+
         public MatchState(MatchStartInfo startInfo)
         {
             MatchId = startInfo.match_id;
@@ -76,6 +83,10 @@ namespace RuneRelic.Game
 
             LastServerTick = update.tick;
             TimeRemaining = update.time_remaining;
+            if (update.leaderboard != null)
+            {
+                Leaderboard = update.leaderboard;
+            }
 
             // Update player states
             foreach (var playerUpdate in update.players)
@@ -92,6 +103,15 @@ namespace RuneRelic.Game
                 state.PreviousPosition = state.TargetPosition;
                 state.TargetPosition = FixedPoint.ToVector3(playerUpdate.position);
                 state.Velocity = FixedPoint.VelocityToVector3(playerUpdate.velocity);
+                if (playerUpdate.alive)
+                {
+                    _spectatorSmoothing.AddSnapshot(id, update.tick, _lastUpdateTime, state.TargetPosition, state.Velocity);
+                }
+                else
+                {
+                    // Eliminated players are no longer rendered; drop their history
+                    _spectatorSmoothing.Remove(id);
+                }
                 state.Form = (Form)playerUpdate.form;
                 state.Score = playerUpdate.score;
                 state.Alive = playerUpdate.alive;
@@ -109,6 +129,14 @@ namespace RuneRelic.Game
             }
         }
 
+        /// <summary>
+        /// Select how player positions are smoothed for rendering.
+        /// </summary>
+        public void SetSmoothingMode(SmoothingMode mode)
+        {
+            Smoothing = mode;
+        }
+
         /// <summary>
         /// Get interpolation factor (0-1) between previous and current state.
         /// </summary>
@@ -131,6 +159,12 @@ namespace RuneRelic.Game
             if (!Players.TryGetValue(playerId, out var state))
                 return Vector3.zero;
 
+            if (Smoothing == SmoothingMode.Spectator &&
+                _spectatorSmoothing.TrySample(playerId, Time.time, out var smoothed))
+            {
+                return smoothed;
+            }
+
             float t = GetInterpolationT();
             return Vector3.Lerp(state.PreviousPosition, state.TargetPosition, t);
         }
@@ -20,6 +20,9 @@ namespace RuneRelic.Game
         [SerializeField] private float minZoom = 5f;
         [SerializeField] private float maxZoom = 30f;
 
+        [Header("Smoothing")]
+        [SerializeField] private SmoothingMode smoothingMode = SmoothingMode.Spectator;
+
         [Header("UI")]
         [SerializeField] private GameObject spectatorUI;
         [SerializeField] private UnityEngine.UI.Text spectatingText;
@@ -75,11 +78,20 @@ namespace RuneRelic.Game
         }
 
         /// <summary>
-        /// Activate spectator mode.
+        /// Activate spectator mode using the configured smoothing mode.
         /// </summary>
         public void Activate()
+        {
+            Activate(smoothingMode);
+        }
+
+        /// <summary>
+        /// Activate spectator mode with the given position smoothing.
+        /// </summary>
+        public void Activate(SmoothingMode smoothing)
         {
             _isActive = true;
+            GameManager.Instance?.CurrentMatch?.SetSmoothingMode(smoothing);
 
             if (spectatorUI != null)
             {
@@ -100,6 +112,7 @@ namespace RuneRelic.Game
         public void Deactivate()
         {
             _isActive = false;
+            GameManager.Instance?.CurrentMatch?.SetSmoothingMode(SmoothingMode.Standard);
 
             if (spectatorUI != null)
             {
@@ -0,0 +1,165 @@
+using System.Collections.Generic;
+using UnityEngine;
+
+namespace RuneRelic.Game
+{
+    /// <summary>
+    /// How remote player positions are smoothed for rendering.
+    /// </summary>
+    public enum SmoothingMode
+    {
+        /// <summary>Lerp between the last two snapshots (lowest latency, for playing).</summary>
+        Standard,
+        /// <summary>Buffered interpolation with velocity extrapolation (for watching).</summary>
+        Spectator
+    }
+
+    /// <summary>
+    /// Snapshot smoothing tuned for spectators.
+    /// Snapshots are placed on the server tick they describe, not on when
+    /// they arrived, so network jitter does not turn into motion jitter.
+    /// A local estimate of the server clock (nudged toward each new tick)
+    /// picks the render tick, a fixed delay behind the newest snapshot, so a
+    /// single late or dropped update is bridged by interpolation; velocity
+    /// extrapolation is used only when the buffer runs dry.
+    /// Pure C# (time is passed in) so it can be exercised headlessly.
+    /// </summary>
+    public class SpectatorSmoothing
+    {
+        // Server simulation rate (ticks per second)
+        public float TickRate = 60f;
+
+        // Three update intervals (3 x 50ms) so one missing snapshot never starves the buffer
+        public float InterpolationDelay = 0.15f;
+
+        // Cap on how far past the newest snapshot we extrapolate
+        public float MaxExtrapolation = 0.25f;
+
+        // Fraction of the clock error corrected per new server tick
+        public float ClockCorrection = 0.05f;
+
+        // Clock error (seconds) beyond which the estimate snaps instead of drifting
+        public float ClockResyncThreshold = 0.5f;
+
+        // Snapshots kept per entity
+        public int BufferSize = 8;
+
+        private struct Snapshot
+        {
+            public uint Tick;
+            public Vector3 Position;
+            public Vector3 Velocity;
+        }
+
+        private readonly Dictionary<string, List<Snapshot>> _snapshots = new Dictionary<string, List<Snapshot>>();
+
+        // Estimated server tick = now * TickRate + _clockOffset
+        private bool _hasClock;
+        private uint _latestTick;
+        private double _clockOffset;
+
+        /// <summary>
+        /// Record a server snapshot for <paramref name="tick"/>, received at
+        /// local time <paramref name="now"/> (seconds).
+        /// </summary>
+        public void AddSnapshot(string id, uint tick, float now, Vector3 position, Vector3 velocity)
+        {
+            ObserveTick(tick, now);
+
+            if (!_snapshots.TryGetValue(id, out var buffer))
+            {
+                buffer = new List<Snapshot>(BufferSize);
+                _snapshots[id] = buffer;
+            }
+
+            // Ignore out-of-order arrivals
+            if (buffer.Count > 0 && tick <= buffer[buffer.Count - 1].Tick)
+                return;
+
+            buffer.Add(new Snapshot { Tick = tick, Position = position, Velocity = velocity });
+            while (buffer.Count > BufferSize)
+            {
+                buffer.RemoveAt(0);
+            }
+        }
+
+        /// <summary>
+        /// Get the smoothed position for an entity at local time <paramref name="now"/>.
+        /// Returns false if no snapshots have been recorded for it.
+        /// </summary>
+        public bool TrySample(string id, float now, out Vector3 position)
+        {
+            position = Vector3.zero;
+            if (!_snapshots.TryGetValue(id, out var buffer) || buffer.Count == 0)
+                return false;
+
+            double renderTick = now * (double)TickRate + _clockOffset - InterpolationDelay * TickRate;
+
+            if (renderTick <= buffer[0].Tick)
+            {
+                position = buffer[0].Position;
+                return true;
+            }
+
+            for (int i = buffer.Count - 1; i > 0; i--)
+            {
+                Snapshot from = buffer[i - 1];
+                Snapshot to = buffer[i];
+                if (renderTick >= from.Tick && renderTick <= to.Tick)
+                {
+                    float t = (float)((renderTick - from.Tick) / (to.Tick - from.Tick));
+                    position = Vector3.Lerp(from.Position, to.Position, t);
+                    return true;
+                }
+            }
+
+            // Buffer ran dry: extrapolate from the newest snapshot
+            Snapshot last = buffer[buffer.Count - 1];
+            float ahead = Mathf.Min((float)((renderTick - last.Tick) / TickRate), MaxExtrapolation);
+            position = last.Position + last.Velocity * ahead;
+            return true;
+        }
+
+        /// <summary>
+        /// Forget an entity (e.g. after elimination).
+        /// </summary>
+        public void Remove(string id)
+        {
+            _snapshots.Remove(id);
+        }
+
+        /// <summary>
+        /// Forget all entities and the clock estimate.
+        /// </summary>
+        public void Clear()
+        {
+            _snapshots.Clear();
+            _hasClock = false;
+            _latestTick = 0;
+            _clockOffset = 0;
+        }
+
+        /// <summary>
+        /// Steer the server clock estimate toward a newly seen tick.
+        /// </summary>
+        private void ObserveTick(uint tick, float now)
+        {
+            if (_hasClock && tick <= _latestTick)
+                return;
+
+            double offset = tick - now * (double)TickRate;
+            double error = offset - _clockOffset;
+            if (!_hasClock || System.Math.Abs(error) > ClockResyncThreshold * TickRate)
+            {
+                _clockOffset = offset;
+            }
+            else
+            {
+                _clockOffset += error * ClockCorrection;
+            }
+
+            _hasClock = true;
+            _latestTick = tick;
+        }
+    }
+}
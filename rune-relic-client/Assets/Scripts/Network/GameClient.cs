@@ -38,6 +38,7 @@ namespace RuneRelic.Network
         public event Action<MatchFoundInfo> OnMatchFound;
         public event Action<MatchStartInfo> OnMatchStart;
         public event Action<GameStateUpdate> OnStateUpdate;
+        public event Action<CatchUpInfo> OnCatchUp;
         public event Action<MatchEvent> OnMatchEvent;
         public event Action<MatchEndInfo> OnMatchEnd;
         public event Action<InputAck> OnInputAck;
@@ -230,6 +231,11 @@ namespace RuneRelic.Network
                         OnStateUpdate?.Invoke(stateUpdate);
                         break;
 
+                    case "catch_up":
+                        var catchUp = JsonUtility.FromJson<CatchUpInfo>(json);
+                        OnCatchUp?.Invoke(catchUp);
+                        break;
+
                     case "event":
                         var matchEvent = JsonUtility.FromJson<MatchEvent>(json);
                         HandleMatchEvent(matchEvent);
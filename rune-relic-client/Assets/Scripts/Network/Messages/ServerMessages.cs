@@ -57,6 +57,8 @@ namespace RuneRelic.Network.Messages
         public List<InitialPlayerInfo> players;
         public byte[] config_hash;
         public byte[] block_hash;
+        public int map_half_width;     // Fixed-point, walkable map extent
+        public int map_half_height;    // Fixed-point, walkable map extent
     }
 
     [Serializable]
@@ -79,9 +81,48 @@ namespace RuneRelic.Network.Messages
         public List<PlayerStateUpdate> players;
         public List<RuneUpdate> runes;
         public List<ShrineUpdate> shrines;
+        public List<BoostPadUpdate> boost_pads;  // Null when the map has none
+        public uint? wave;  // Survivor mode only
+        public List<EnemyUpdate> enemies;  // Survivor mode only
+        public List<LeaderboardEntry> leaderboard;  // Canonical placement order
         public byte[] state_hash;
     }
 
+    [Serializable]
+    public class CatchUpInfo
+    {
+        public uint since_tick;  // Tick the player disconnected at
+        public List<EliminationSummary> eliminations;
+        public List<FormChangeSummary> form_changes;
+        public GameStateUpdate state;
+    }
+
+    [Serializable]
+    public class EliminationSummary
+    {
+        public uint tick;
+        public byte[] victim_id;
+        public byte[] killer_id;  // Nullable
+        public int placement;
+    }
+
+    [Serializable]
+    public class FormChangeSummary
+    {
+        public uint tick;
+        public byte[] player_id;
+        public int old_form;
+        public int new_form;
+    }
+
+    [Serializable]
+    public class LeaderboardEntry
+    {
+        public byte[] player_id;
+        public uint score;
+        public uint kills;
+    }
+
     [Serializable]
     public class PlayerStateUpdate
     {
@@ -89,6 +130,7 @@ namespace RuneRelic.Network.Messages
         public int[] position;   // Fixed-point [x, y]
         public int[] velocity;   // Fixed-point [x, y]
         public int form;
+        public int tier;         // Ladder tier, equals form on the classic ladder
         public uint score;
         public bool alive;
         public int spawn_zone_id;
@@ -105,6 +147,8 @@ namespace RuneRelic.Network.Messages
         public uint shield;
         public uint invulnerable;
         public List<int> shrine_buffs;
+        public int energy;       // Fixed-point
+        public int max_energy;   // Fixed-point, 0 when energy is disabled
     }
 
     [Serializable]
@@ -128,6 +172,25 @@ namespace RuneRelic.Network.Messages
         public float channel_progress;  // 0-1 progress of channeling
     }
 
+    [Serializable]
+    public class BoostPadUpdate
+    {
+        public byte id;
+        public int[] position;  // Fixed-point [x, y]
+        public int radius;      // Fixed-point
+        public int[] impulse;   // Fixed-point launch velocity [x, y]
+    }
+
+    [Serializable]
+    public class EnemyUpdate
+    {
+        public uint id;
+        public int enemy_type;
+        public int[] position;  // Fixed-point [x, y]
+        public int[] velocity;  // Fixed-point [x, y]
+        public int radius;      // Fixed-point
+    }
+
     // =========================================================================
     // Game Events
     // =========================================================================
@@ -155,6 +218,7 @@ namespace RuneRelic.Network.Messages
         public byte[] victim_id;
         public byte[] killer_id;
         public int victim_form;
+        public int placement;
 
         // AbilityUsed
         public int ability_type;
@@ -165,6 +229,19 @@ namespace RuneRelic.Network.Messages
 
         // Countdown
         public uint seconds;
+
+        // RuneCombo
+        public uint combo;
+        public uint bonus;
+
+        // PlayerRespawned
+        public uint deaths;
+
+        // WaveStarted / EnemyDefeated (survivor mode)
+        public uint wave;
+        public uint enemy_count;
+        public uint enemy_id;
+        public int enemy_type;
     }
 
     // =========================================================================
@@ -179,6 +256,7 @@ namespace RuneRelic.Network.Messages
         public byte[] winner_id;  // Nullable
         public List<PlayerPlacement> placements;
         public byte[] final_state_hash;
+        public byte[] result_hash;  // Outcome-only commitment
         public byte[] transcript;  // Nullable, for ranked
     }
 
@@ -64,7 +64,8 @@ namespace RuneRelic.Utils
         Casual = 0,
         Ranked = 1,
         Private = 2,
-        Practice = 3
+        Practice = 3,
+        Survivor = 4
     }
 
     /// <summary>
@@ -112,7 +113,8 @@ namespace RuneRelic.Utils
         Wisdom = 0,
         Power = 1,
         Speed = 2,
-        Shield = 3
+        Shield = 3,
+        Defensive = 4
     }
 
     /// <summary>
@@ -0,0 +1,129 @@
+using NUnit.Framework;
+using UnityEngine;
+using RuneRelic.Game;
+
+namespace RuneRelic.Tests
+{
+    public class SpectatorSmoothingTests
+    {
+        private const string PlayerId = "player";
+        private const float UpdateInterval = 0.05f; // 20Hz state updates
+        private const float FrameTime = 1f / 60f;   // 60fps render
+        private const float Speed = 10f;            // units per second
+        private const uint TicksPerUpdate = 3;      // 60Hz sim, 20Hz updates
+
+        private static Vector3 PositionAt(float time) => new Vector3(Speed * time, 0f, 0f);
+
+        private static uint TickOf(int snapshot) => (uint)snapshot * TicksPerUpdate;
+
+        [Test]
+        public void DroppedSnapshotDoesNotCauseJump()
+        {
+            var smoothing = new SpectatorSmoothing();
+            Vector3 velocity = new Vector3(Speed, 0f, 0f);
+            const int droppedIndex = 8;
+
+            int nextSnapshot = 0;
+            Vector3? previous = null;
+            float maxStep = 0f;
+
+            for (float now = 0f; now < 1.5f; now += FrameTime)
+            {
+                // Deliver every snapshot that has "arrived" by now, except the dropped one
+                while (nextSnapshot * UpdateInterval <= now)
+                {
+                    float sentAt = nextSnapshot * UpdateInterval;
+                    if (nextSnapshot != droppedIndex)
+                    {
+                        smoothing.AddSnapshot(PlayerId, TickOf(nextSnapshot), now, PositionAt(sentAt), velocity);
+                    }
+                    nextSnapshot++;
+                }
+
+                Assert.IsTrue(smoothing.TrySample(PlayerId, now, out var position));
+                if (previous.HasValue)
+                {
+                    maxStep = Mathf.Max(maxStep, Vector3.Distance(previous.Value, position));
+                }
+                previous = position;
+            }
+
+            // A steady mover covers Speed * FrameTime per frame; allow a small margin
+            Assert.LessOrEqual(maxStep, Speed * FrameTime * 1.5f);
+        }
+
+        [Test]
+        public void ArrivalJitterDoesNotCauseMotionJitter()
+        {
+            var smoothing = new SpectatorSmoothing();
+            Vector3 velocity = new Vector3(Speed, 0f, 0f);
+            var random = new System.Random(2475);
+
+            // Each snapshot arrives up to 40ms late, independently
+            var arrivals = new float[40];
+            for (int i = 0; i < arrivals.Length; i++)
+            {
+                arrivals[i] = i * UpdateInterval + (float)random.NextDouble() * 0.04f;
+            }
+
+            int nextSnapshot = 0;
+            Vector3? previous = null;
+            float maxStep = 0f;
+            float minStep = float.MaxValue;
+
+            for (float now = 0f; now < 1.8f; now += FrameTime)
+            {
+                while (nextSnapshot < arrivals.Length && arrivals[nextSnapshot] <= now)
+                {
+                    float sentAt = nextSnapshot * UpdateInterval;
+                    smoothing.AddSnapshot(PlayerId, TickOf(nextSnapshot), now, PositionAt(sentAt), velocity);
+                    nextSnapshot++;
+                }
+
+                Assert.IsTrue(smoothing.TrySample(PlayerId, now, out var position));
+                if (previous.HasValue && now > 0.5f)
+                {
+                    float step = Vector3.Distance(previous.Value, position);
+                    maxStep = Mathf.Max(maxStep, step);
+                    minStep = Mathf.Min(minStep, step);
+                }
+                previous = position;
+            }
+
+            // Once the clock has settled, every frame advances about the same distance
+            Assert.LessOrEqual(maxStep, Speed * FrameTime * 1.25f);
+            Assert.GreaterOrEqual(minStep, Speed * FrameTime * 0.75f);
+        }
+
+        [Test]
+        public void RemovedEntityHasNoSample()
+        {
+            var smoothing = new SpectatorSmoothing();
+            smoothing.AddSnapshot(PlayerId, TickOf(0), 0f, PositionAt(0f), Vector3.zero);
+            smoothing.Remove(PlayerId);
+            Assert.IsFalse(smoothing.TrySample(PlayerId, 0.1f, out _));
+        }
+
+        [Test]
+        public void ExtrapolationIsCappedWhenStreamStops()
+        {
+            var smoothing = new SpectatorSmoothing();
+            Vector3 velocity = new Vector3(Speed, 0f, 0f);
+
+            smoothing.AddSnapshot(PlayerId, TickOf(0), 0f, PositionAt(0f), velocity);
+            smoothing.AddSnapshot(PlayerId, TickOf(1), UpdateInterval, PositionAt(UpdateInterval), velocity);
+
+            // Long after the last snapshot the entity stops drifting
+            Assert.IsTrue(smoothing.TrySample(PlayerId, 10f, out var position));
+            float maxX = PositionAt(UpdateInterval).x + Speed * smoothing.MaxExtrapolation;
+            Assert.AreEqual(maxX, position.x, 0.001f);
+        }
+
+        [Test]
+        public void UnknownEntityHasNoSample()
+        {
+            var smoothing = new SpectatorSmoothing();
+            Assert.IsFalse(smoothing.TrySample("missing", 1f, out _));
+        }
+    }
+}
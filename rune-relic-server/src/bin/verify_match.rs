@@ -0,0 +1,110 @@
+//! Offline Match Verifier
+//!
+//! Replays a recorded match transcript and reports whether its outcome
+//! matches, optionally checking it against a commit-reveal pair.
+//!
+//! ```text
+//! verify-match <transcript> [<commitment.json> <reveal.json>]
+//! ```
+//!
+//! The transcript may be bincode or portable JSON. Exits 0 when verification
+//! passes, 1 when it fails and 2 on usage or read errors.
+
+use std::path::Path;
+use std::process::ExitCode;
+
+use rune_relic::proof::{
+    verify_match, FullVerificationResult, MatchCommitment, MatchReveal, MatchTranscript,
+};
+
+const EXIT_INVALID: u8 = 1;
+const EXIT_USAGE: u8 = 2;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (transcript_path, reveal_paths) = match args.as_slice() {
+        [transcript] => (transcript, None),
+        [transcript, commitment, reveal] => (transcript, Some((commitment, reveal))),
+        _ => {
+            eprintln!("usage: verify-match <transcript> [<commitment.json> <reveal.json>]");
+            return ExitCode::from(EXIT_USAGE);
+        }
+    };
+
+    let transcript = match load_transcript(Path::new(transcript_path)) {
+        Ok(t) => t,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::from(EXIT_USAGE);
+        }
+    };
+
+    let reveal = match reveal_paths {
+        Some((commitment, reveal)) => {
+            match (load_json::<MatchCommitment>(commitment), load_json::<MatchReveal>(reveal)) {
+                (Ok(c), Ok(r)) => Some((c, r)),
+                (Err(e), _) | (_, Err(e)) => {
+                    eprintln!("error: {}", e);
+                    return ExitCode::from(EXIT_USAGE);
+                }
+            }
+        }
+        None => None,
+    };
+
+    let result = verify_match(&transcript, reveal.as_ref().map(|(c, r)| (c, r)));
+    print_result(&transcript, &result);
+
+    if result.is_valid() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(EXIT_INVALID)
+    }
+}
+
+/// Load a transcript, accepting portable JSON or bincode.
+fn load_transcript(path: &Path) -> Result<MatchTranscript, String> {
+    let data = std::fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let is_json = data.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'{');
+    let transcript = if is_json {
+        let value: serde_json::Value = serde_json::from_slice(&data)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        MatchTranscript::from_portable_json(&value)
+    } else {
+        MatchTranscript::from_bytes(&data)
+    };
+
+    transcript.map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn load_json<T: serde::de::DeserializeOwned>(path: &str) -> Result<T, String> {
+    let data = std::fs::read(path).map_err(|e| format!("{}: {}", path, e))?;
+    serde_json::from_slice(&data).map_err(|e| format!("{}: {}", path, e))
+}
+
+fn print_result(transcript: &MatchTranscript, result: &FullVerificationResult) {
+    let replay = &result.replay;
+    let failed_checkpoints = replay.checkpoint_results.iter().filter(|c| !c.valid).count();
+
+    println!("match:        {}", hex::encode(transcript.metadata.match_id));
+    println!("replay:       {}", if replay.valid { "ok" } else { "FAILED" });
+    println!("expected:     {}", hex::encode(replay.expected_final_hash));
+    println!("computed:     {}", hex::encode(replay.computed_final_hash));
+    println!(
+        "checkpoints:  {} checked, {} mismatched",
+        replay.checkpoint_results.len(),
+        failed_checkpoints
+    );
+    if let Some(error) = &replay.error {
+        println!("error:        {}", error);
+    }
+
+    match &result.commitment {
+        Some(Ok(())) => println!("commitment:   ok"),
+        Some(Err(e)) => println!("commitment:   FAILED ({})", e),
+        None => println!("commitment:   not checked"),
+    }
+
+    println!("result:       {}", if result.is_valid() { "VALID" } else { "INVALID" });
+}
@@ -96,6 +96,12 @@ pub const SCORE_PER_RUNE: u32 = 10;
 /// Points per player eliminated
 pub const SCORE_PER_KILL: u32 = 100;
 
+/// Canonical score bound: `M31_PRIME - 1` (2^31 - 2).
+///
+/// Scores saturate here so every score is a single M31 element, encoded
+/// the same way in placements, proof public inputs and in-circuit.
+pub const MAX_SCORE: u32 = 2147483646;
+
 // =============================================================================
 // CORE OPERATIONS (All deterministic, wrapping semantics)
 // =============================================================================
@@ -213,6 +219,15 @@ pub fn fixed_clamp(value: Fixed, min: Fixed, max: Fixed) -> Fixed {
     fixed_max(min, fixed_min(max, value))
 }
 
+/// Approximate equality: true if `a` and `b` differ by at most `epsilon`.
+///
+/// For reconciliation and tests that tolerate LSB rounding. Verification
+/// must keep using exact equality.
+#[inline]
+pub fn fixed_approx_eq(a: Fixed, b: Fixed, epsilon: Fixed) -> bool {
+    (a as i64 - b as i64).abs() <= epsilon as i64
+}
+
 /// Linear interpolation: a + (b - a) * t
 /// where t is in fixed-point (0.0 = 0, 1.0 = FIXED_ONE)
 #[inline]
@@ -274,6 +289,12 @@ impl FixedNum {
     pub fn sqrt(self) -> Self {
         Self(fixed_sqrt(self.0))
     }
+
+    /// Approximate equality within epsilon
+    #[inline]
+    pub fn approx_eq(self, other: Self, epsilon: Self) -> bool {
+        fixed_approx_eq(self.0, other.0, epsilon.0)
+    }
 }
 
 impl Add for FixedNum {
@@ -435,6 +456,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fixed_approx_eq() {
+        let a = to_fixed(1.0);
+        assert!(fixed_approx_eq(a, a + 2, 2));
+        assert!(fixed_approx_eq(a, a - 2, 2));
+        assert!(!fixed_approx_eq(a, a + 3, 2));
+        assert!(!fixed_approx_eq(a, a - 3, 2));
+
+        // Extremes must not overflow
+        assert!(!fixed_approx_eq(Fixed::MIN, Fixed::MAX, FIXED_ONE));
+        assert!(FixedNum::from_raw(a).approx_eq(FixedNum::from_raw(a + 1), FixedNum::from_raw(1)));
+    }
+
     #[test]
     fn test_fixednum_wrapper() {
         let a = FixedNum::from_int(5);
@@ -33,6 +33,11 @@ impl StateHasher {
         Self::new(b"RUNE_RELIC_STATE_V1")
     }
 
+    /// Create hasher for a match outcome (see `MatchState::compute_result_hash`).
+    pub fn for_match_result() -> Self {
+        Self::new(b"RUNE_RELIC_RESULT_V1")
+    }
+
     /// Create hasher for input buffer.
     pub fn for_input_buffer() -> Self {
         Self::new(b"RUNE_RELIC_INPUTS_V1")
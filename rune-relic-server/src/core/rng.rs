@@ -6,7 +6,8 @@
 use serde::{Serialize, Deserialize};
 use sha2::{Sha256, Digest};
 
-use super::fixed::{Fixed, ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT};
+use super::fixed::Fixed;
+use super::hash::StateHasher;
 use super::vec2::FixedVec2;
 
 /// Deterministic PRNG using Xorshift128+ algorithm.
@@ -28,6 +29,39 @@ use super::vec2::FixedVec2;
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DeterministicRng {
     state: [u64; 2],
+
+    /// Unused little-endian bytes of the last `fill_bytes` draw
+    spare: u64,
+
+    /// Number of bytes still available in `spare`
+    spare_len: u8,
+
+    /// Draw counters (debug instrumentation, never serialized or hashed)
+    #[cfg(feature = "rng-audit")]
+    #[serde(skip)]
+    audit: RngAudit,
+}
+
+/// Per-subsystem `next_u64` call counts, enabled by the `rng-audit` feature.
+///
+/// Purely observational: recording never touches the generator state.
+#[cfg(feature = "rng-audit")]
+#[derive(Clone, Debug, Default)]
+struct RngAudit {
+    /// Subsystem the next draws are attributed to
+    subsystem: &'static str,
+    /// Counts in order of first use since the last `take_audit`
+    counts: Vec<(&'static str, u32)>,
+}
+
+#[cfg(feature = "rng-audit")]
+impl RngAudit {
+    fn record(&mut self) {
+        match self.counts.iter_mut().find(|(name, _)| *name == self.subsystem) {
+            Some((_, count)) => *count += 1,
+            None => self.counts.push((self.subsystem, 1)),
+        }
+    }
 }
 
 impl Default for DeterministicRng {
@@ -53,7 +87,24 @@ impl DeterministicRng {
             [state0, state1]
         };
 
-        Self { state }
+        Self {
+            state,
+            spare: 0,
+            spare_len: 0,
+            #[cfg(feature = "rng-audit")]
+            audit: RngAudit::default(),
+        }
+    }
+
+    /// Create an independent sub-stream keyed by `stream` and `index`.
+    ///
+    /// Sub-streams let optional mechanics draw randomness without
+    /// advancing the main match RNG, so toggling them leaves every other
+    /// random outcome unchanged.
+    pub fn substream(seed: u64, stream: u64, index: u64) -> Self {
+        let mut s = seed ^ stream.wrapping_mul(0x9E3779B97F4A7C15);
+        let key = splitmix64(&mut s) ^ index;
+        Self::new(key)
     }
 
     /// Create RNG from match parameters.
@@ -80,6 +131,9 @@ impl DeterministicRng {
         let mut s1 = self.state[1];
         let result = s0.wrapping_add(s1);
 
+        #[cfg(feature = "rng-audit")]
+        self.audit.record();
+
         s1 ^= s0;
         self.state[0] = s0.rotate_left(24) ^ s1 ^ (s1 << 16);
         self.state[1] = s1.rotate_left(37);
@@ -137,11 +191,11 @@ impl DeterministicRng {
         min.wrapping_add(self.next_fixed(range))
     }
 
-    /// Generate a random position within arena bounds.
+    /// Generate a random position within an arena with the given half extents.
     #[inline]
-    pub fn random_position(&mut self) -> FixedVec2 {
-        let x = self.next_fixed_range(-ARENA_HALF_WIDTH, ARENA_HALF_WIDTH);
-        let y = self.next_fixed_range(-ARENA_HALF_HEIGHT, ARENA_HALF_HEIGHT);
+    pub fn random_position(&mut self, half_width: Fixed, half_height: Fixed) -> FixedVec2 {
+        let x = self.next_fixed_range(-half_width, half_width);
+        let y = self.next_fixed_range(-half_height, half_height);
         FixedVec2::new(x, y)
     }
 
@@ -195,6 +249,35 @@ impl DeterministicRng {
         }
     }
 
+    /// Fill `buf` with random bytes.
+    ///
+    /// Bytes come from successive `next_u64` draws written little endian.
+    /// The unused tail of the last draw is kept and handed out first by the
+    /// next fill, so the byte stream is the same however callers split
+    /// their buffers.
+    pub fn fill_bytes(&mut self, buf: &mut [u8]) {
+        for byte in buf.iter_mut() {
+            if self.spare_len == 0 {
+                self.spare = self.next_u64();
+                self.spare_len = 8;
+            }
+            *byte = self.spare as u8;
+            self.spare >>= 8;
+            self.spare_len -= 1;
+        }
+    }
+
+    /// Hash the bytes left over from `fill_bytes`.
+    ///
+    /// Writes nothing while the buffer is empty, so states that never
+    /// call `fill_bytes` hash exactly as before.
+    pub fn hash_spare_into(&self, hasher: &mut StateHasher) {
+        if self.spare_len > 0 {
+            hasher.update_u8(self.spare_len);
+            hasher.update_u64(self.spare);
+        }
+    }
+
     /// Select a random element from a slice.
     pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
         if slice.is_empty() {
@@ -211,8 +294,38 @@ impl DeterministicRng {
     }
 
     /// Restore from saved state.
+    ///
+    /// Drops any bytes left over from `fill_bytes`.
     pub fn set_state(&mut self, state: [u64; 2]) {
         self.state = state;
+        self.spare = 0;
+        self.spare_len = 0;
+    }
+
+    /// Attribute subsequent draws to `subsystem`.
+    ///
+    /// No-op unless the `rng-audit` feature is enabled.
+    #[inline]
+    #[cfg_attr(not(feature = "rng-audit"), allow(unused_variables))]
+    pub fn audit_subsystem(&mut self, subsystem: &'static str) {
+        #[cfg(feature = "rng-audit")]
+        {
+            self.audit.subsystem = subsystem;
+        }
+    }
+
+    /// Take the `(subsystem, next_u64 calls)` counts recorded so far.
+    ///
+    /// Always empty unless the `rng-audit` feature is enabled.
+    pub fn take_audit(&mut self) -> Vec<(&'static str, u32)> {
+        #[cfg(feature = "rng-audit")]
+        {
+            std::mem::take(&mut self.audit.counts)
+        }
+        #[cfg(not(feature = "rng-audit"))]
+        {
+            Vec::new()
+        }
     }
 }
 
@@ -274,7 +387,7 @@ pub fn derive_match_seed(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::fixed::to_fixed;
+    use crate::core::fixed::{to_fixed, ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT};
 
     #[test]
     fn test_rng_determinism() {
@@ -312,6 +425,28 @@ mod tests {
         assert_eq!(val3, 9768315062676884790);
     }
 
+    #[cfg(feature = "rng-audit")]
+    #[test]
+    fn test_audit_counts_draws_without_changing_sequence() {
+        let mut audited = DeterministicRng::new(42);
+        let mut plain = DeterministicRng::new(42);
+
+        audited.audit_subsystem("a");
+        audited.next_u64();
+        audited.next_u64();
+        audited.audit_subsystem("b");
+        audited.next_int(10);
+        audited.audit_subsystem("a");
+        audited.next_fixed(1000);
+        for _ in 0..4 {
+            plain.next_u64();
+        }
+
+        assert_eq!(audited.take_audit(), vec![("a", 3), ("b", 1)]);
+        assert!(audited.take_audit().is_empty());
+        assert_eq!(audited.state(), plain.state());
+    }
+
     #[test]
     fn test_next_int() {
         let mut rng = DeterministicRng::new(1234);
@@ -358,9 +493,15 @@ mod tests {
         let mut rng = DeterministicRng::new(7777);
 
         for _ in 0..100 {
-            let pos = rng.random_position();
-            assert!(pos.is_in_arena());
+            let pos = rng.random_position(ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT);
+            assert!(pos.is_in_arena(ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT));
         }
+
+        // A wider arena widens the range
+        let wide = to_fixed(250.0);
+        let positions: Vec<_> = (0..200).map(|_| rng.random_position(wide, wide)).collect();
+        assert!(positions.iter().all(|p| p.is_in_arena(wide, wide)));
+        assert!(positions.iter().any(|p| !p.is_in_arena(ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT)));
     }
 
     #[test]
@@ -377,6 +518,55 @@ mod tests {
         assert_eq!(arr1, arr2);
     }
 
+    #[test]
+    fn test_fill_bytes_deterministic_and_chunk_independent() {
+        let mut whole = [0u8; 24];
+        DeterministicRng::new(2468).fill_bytes(&mut whole);
+
+        let mut again = [0u8; 24];
+        DeterministicRng::new(2468).fill_bytes(&mut again);
+        assert_eq!(whole, again);
+        assert_ne!(whole, [0u8; 24]);
+
+        // Same bytes when filled in aligned pieces
+        let mut rng = DeterministicRng::new(2468);
+        let mut pieces = [0u8; 24];
+        let (head, tail) = pieces.split_at_mut(8);
+        rng.fill_bytes(head);
+        rng.fill_bytes(tail);
+        assert_eq!(whole, pieces);
+
+        // ...and in unaligned pieces
+        let mut rng = DeterministicRng::new(2468);
+        let mut pieces = [0u8; 24];
+        let (head, tail) = pieces.split_at_mut(5);
+        rng.fill_bytes(head);
+        rng.fill_bytes(tail);
+        assert_eq!(whole, pieces);
+
+        let mut rng = DeterministicRng::new(2468);
+        let mut pieces = [0u8; 24];
+        for chunk in pieces.chunks_mut(3) {
+            rng.fill_bytes(chunk);
+        }
+        assert_eq!(whole, pieces);
+
+        // A short fill is a prefix; the rest of its draw is kept
+        let mut rng = DeterministicRng::new(2468);
+        let mut short = [0u8; 5];
+        rng.fill_bytes(&mut short);
+        assert_eq!(short, whole[..5]);
+        let mut rest = [0u8; 3];
+        rng.fill_bytes(&mut rest);
+        assert_eq!(rest, whole[5..8]);
+        assert_eq!(rng.next_u64().to_le_bytes(), whole[8..16]);
+
+        // Different seed, different bytes
+        let mut other = [0u8; 24];
+        DeterministicRng::new(2469).fill_bytes(&mut other);
+        assert_ne!(whole, other);
+    }
+
     #[test]
     fn test_derive_match_seed() {
         let block_hash = [0u8; 32];
@@ -418,4 +608,18 @@ mod tests {
             assert_eq!(rng.next_u64(), expected);
         }
     }
+
+    #[test]
+    fn test_substream_independence() {
+        let mut a = DeterministicRng::substream(12345, 1, 7);
+        let mut b = DeterministicRng::substream(12345, 1, 7);
+        assert_eq!(a.next_u64(), b.next_u64());
+
+        // Different index or stream gives a different sequence
+        let mut c = DeterministicRng::substream(12345, 1, 8);
+        let mut d = DeterministicRng::substream(12345, 2, 7);
+        let first = DeterministicRng::substream(12345, 1, 7).next_u64();
+        assert_ne!(c.next_u64(), first);
+        assert_ne!(d.next_u64(), first);
+    }
 }
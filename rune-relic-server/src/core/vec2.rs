@@ -9,8 +9,7 @@ use serde::{Serialize, Deserialize};
 
 use super::fixed::{
     Fixed, FIXED_ONE, FIXED_SCALE,
-    fixed_mul, fixed_div, fixed_sqrt, fixed_clamp,
-    ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT,
+    fixed_mul, fixed_div, fixed_sqrt, fixed_clamp, fixed_approx_eq,
 };
 
 /// 2D vector with fixed-point components.
@@ -121,6 +120,17 @@ impl FixedVec2 {
         fixed_mul(dx, dx).wrapping_add(fixed_mul(dy, dy))
     }
 
+    /// Squared distance widened to i64 (same Q16.16 scale).
+    ///
+    /// `distance_squared` wraps once points are ~181 units apart; use this
+    /// for comparisons that may span the whole arena.
+    #[inline]
+    pub fn distance_squared_wide(self, other: Self) -> i64 {
+        let dx = self.x as i64 - other.x as i64;
+        let dy = self.y as i64 - other.y as i64;
+        (dx * dx + dy * dy) >> FIXED_SCALE
+    }
+
     /// Distance to another point. Prefer `distance_squared` when possible.
     #[inline]
     pub fn distance(self, other: Self) -> Fixed {
@@ -162,22 +172,22 @@ impl FixedVec2 {
         }
     }
 
-    /// Clamp to arena bounds.
+    /// Clamp to the bounds of an arena with the given half extents.
     #[inline]
-    pub fn clamp_to_arena(self) -> Self {
+    pub fn clamp_to_arena(self, half_width: Fixed, half_height: Fixed) -> Self {
         Self {
-            x: fixed_clamp(self.x, -ARENA_HALF_WIDTH, ARENA_HALF_WIDTH),
-            y: fixed_clamp(self.y, -ARENA_HALF_HEIGHT, ARENA_HALF_HEIGHT),
+            x: fixed_clamp(self.x, -half_width, half_width),
+            y: fixed_clamp(self.y, -half_height, half_height),
         }
     }
 
-    /// Check if position is within arena bounds.
+    /// Check if position is within an arena with the given half extents.
     #[inline]
-    pub fn is_in_arena(self) -> bool {
-        self.x >= -ARENA_HALF_WIDTH
-            && self.x <= ARENA_HALF_WIDTH
-            && self.y >= -ARENA_HALF_HEIGHT
-            && self.y <= ARENA_HALF_HEIGHT
+    pub fn is_in_arena(self, half_width: Fixed, half_height: Fixed) -> bool {
+        self.x >= -half_width
+            && self.x <= half_width
+            && self.y >= -half_height
+            && self.y <= half_height
     }
 
     /// Linear interpolation between two vectors.
@@ -210,6 +220,16 @@ impl FixedVec2 {
         }
     }
 
+    /// Approximate equality: each component within `epsilon`.
+    ///
+    /// Exact `==` remains the rule for verification; this is for client
+    /// reconciliation (deciding when to snap) and tests that tolerate
+    /// rounding from sqrt-based operations.
+    #[inline]
+    pub fn approx_eq(self, other: Self, epsilon: Fixed) -> bool {
+        fixed_approx_eq(self.x, other.x, epsilon) && fixed_approx_eq(self.y, other.y, epsilon)
+    }
+
     /// Convert to float tuple for rendering.
     #[inline]
     pub fn to_floats(self) -> (f32, f32) {
@@ -266,7 +286,7 @@ impl fmt::Display for FixedVec2 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::fixed::to_fixed;
+    use crate::core::fixed::{to_fixed, ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT};
 
     #[test]
     fn test_vec2_constants() {
@@ -321,6 +341,12 @@ mod tests {
         let b = FixedVec2::new(to_fixed(3.0), to_fixed(4.0));
         let dist_sq = a.distance_squared(b);
         assert_eq!(dist_sq, to_fixed(25.0));
+        assert_eq!(a.distance_squared_wide(b), to_fixed(25.0) as i64);
+
+        // Across the arena the narrow version wraps, the wide one does not
+        let c = FixedVec2::new(to_fixed(-150.0), 0);
+        let d = FixedVec2::new(to_fixed(150.0), 0);
+        assert_eq!(c.distance_squared_wide(d), 90000 * FIXED_ONE as i64);
     }
 
     #[test]
@@ -350,13 +376,32 @@ mod tests {
     fn test_vec2_clamp_to_arena() {
         // Inside bounds - unchanged
         let inside = FixedVec2::new(to_fixed(10.0), to_fixed(20.0));
-        assert_eq!(inside.clamp_to_arena(), inside);
+        assert_eq!(inside.clamp_to_arena(ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT), inside);
 
         // Outside bounds - clamped
         let outside = FixedVec2::new(to_fixed(250.0), to_fixed(-250.0));
-        let clamped = outside.clamp_to_arena();
+        let clamped = outside.clamp_to_arena(ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT);
         assert_eq!(clamped.x, ARENA_HALF_WIDTH);
         assert_eq!(clamped.y, -ARENA_HALF_HEIGHT);
+
+        // A larger arena clamps further out
+        let wide = to_fixed(240.0);
+        assert_eq!(outside.clamp_to_arena(wide, wide), FixedVec2::new(wide, -wide));
+    }
+
+    #[test]
+    fn test_vec2_approx_eq() {
+        let epsilon = 4;
+        let a = FixedVec2::new(to_fixed(3.0), to_fixed(-4.0));
+
+        assert!(a.approx_eq(a, 0));
+        assert!(a.approx_eq(FixedVec2::new(a.x + epsilon, a.y - epsilon), epsilon));
+        assert!(!a.approx_eq(FixedVec2::new(a.x + epsilon + 1, a.y), epsilon));
+        assert!(!a.approx_eq(FixedVec2::new(a.x, a.y - epsilon - 1), epsilon));
+
+        // Normalizing is only accurate to a few LSBs
+        let unit = FixedVec2::new(to_fixed(3.0), to_fixed(4.0)).normalize();
+        assert!(unit.approx_eq(FixedVec2::new(to_fixed(0.6), to_fixed(0.8)), 16));
     }
 
     #[test]
@@ -1,11 +1,161 @@
 //! Form-Specific Abilities
 //!
 //! Each evolution form has a unique ability with different cooldowns.
+//!
+//! # Combo matrix
+//!
+//! When `AbilityConfig::combos_enabled` is set, abilities interact with
+//! enemy gravity wells at activation time:
+//!
+//! | Ability | Near enemy Gravity Well | Effect                     |
+//! |---------|-------------------------|----------------------------|
+//! | Repel   | well center in range    | well is dispelled          |
+//! | Dash    | caster inside well      | dash velocity halved       |
+//!
+//! Every interaction emits an `AbilityCombo` event.
+//!
+//! # Mines
+//!
+//! `AbilityConfig::mine_form` swaps that form's ability for `Mine`: an
+//! area-denial zone dropped at the caster's position. Enemies inside take
+//! `mine_damage_per_tick` and are slowed until the mine expires; the first
+//! contact per enemy emits a `MineTriggered` event.
+//!
+//! # Tethers
+//!
+//! `AbilityConfig::tether_form` swaps that form's ability for `Tether`: a
+//! spring between the caster and the nearest enemy within `tether_range`.
+//! While the two are farther apart than `tether_rest_length`, both are
+//! pulled together at `tether_stiffness` per unit of stretch. The tether
+//! breaks when it expires or either end is eliminated; attaching and
+//! releasing emit `TetherAttached` / `TetherReleased`.
+//!
+//! # Energy
+//!
+//! With `AbilityConfig::energy_enabled`, abilities cost `energy_cost` from a
+//! regenerating pool instead of starting a cooldown, so a full pool can
+//! burst several activations back to back.
+//!
+//! # Overcharge
+//!
+//! With `AbilityConfig::overcharge_enabled`, every activation adds
+//! `overcharge_per_use` to a per-player meter that drains by
+//! `overcharge_decay_per_tick`. Filling it to `overcharge_max` empties the
+//! meter and locks the player's ability for `overcharge_lockout_ticks`, so
+//! cheap builds cannot spam casts back to back; spaced-out casts never
+//! fill it.
+//!
+//! # Ordering
+//!
+//! Abilities resolve during input processing, before any collision, in
+//! player ID order. A player who casts and is then eliminated (or downed)
+//! later in the same tick keeps the cast, its cost and its `AbilityUsed`
+//! event, but their lingering effects are removed the moment they leave
+//! play, so nothing outlives its caster. Tethers are the exception: they
+//! are released by `process_tethers` later that tick so `TetherReleased`
+//! is still emitted.
 
+use serde::{Serialize, Deserialize};
 use crate::core::fixed::{Fixed, FIXED_ONE, fixed_mul};
 use crate::core::vec2::FixedVec2;
-use crate::game::state::{MatchState, PlayerId, Form, AbilityType, ActiveAbilityEffect};
+use crate::game::state::{
+    MatchState, PlayerId, Form, AbilityType, ActiveAbilityEffect, ComboType,
+};
 use crate::game::events::GameEvent;
+use crate::game::shrine::get_shield_multiplier;
+
+/// Configuration for ability mechanics.
+#[derive(Serialize, Deserialize)]
+pub struct AbilityConfig {
+    /// Enable ability interactions from the combo matrix (default off)
+    pub combos_enabled: bool,
+    /// Dash charges while the ability button is held and fires on release
+    /// (default off: dash fires immediately on press)
+    pub charge_dash_enabled: bool,
+    /// Ticks of holding needed to reach full charge
+    pub dash_max_charge_ticks: u32,
+    /// Dash velocity multiplier at full charge (Fixed, FIXED_ONE = no bonus)
+    pub dash_max_charge_mult: Fixed,
+    /// Form whose ability is replaced by `Mine` (None = mines disabled)
+    pub mine_form: Option<Form>,
+    /// Mine zone radius
+    pub mine_radius: Fixed,
+    /// Ticks a deployed mine stays active
+    pub mine_duration_ticks: u32,
+    /// Damage per tick to enemies inside a mine
+    pub mine_damage_per_tick: Fixed,
+    /// Movement speed multiplier for enemies inside a mine
+    pub mine_slow_mult: Fixed,
+    /// Form whose ability is replaced by `Tether` (None = tethers disabled)
+    pub tether_form: Option<Form>,
+    /// Maximum distance to the enemy being tethered
+    pub tether_range: Fixed,
+    /// Ticks a tether lasts
+    pub tether_duration_ticks: u32,
+    /// Separation the spring pulls back to
+    pub tether_rest_length: Fixed,
+    /// Pull speed per unit of stretch beyond the rest length (per second)
+    pub tether_stiffness: Fixed,
+    /// Gate abilities on an energy pool instead of cooldowns (default off)
+    pub energy_enabled: bool,
+    /// Energy pool size
+    pub max_energy: Fixed,
+    /// Energy regenerated per tick
+    pub energy_regen_per_tick: Fixed,
+    /// Energy spent per activation
+    pub energy_cost: Fixed,
+    /// Lock out abilities after casting too often (default off)
+    pub overcharge_enabled: bool,
+    /// Overcharge added per activation
+    pub overcharge_per_use: u32,
+    /// Overcharge drained per tick
+    pub overcharge_decay_per_tick: u32,
+    /// Overcharge that triggers a lockout
+    pub overcharge_max: u32,
+    /// Ticks abilities stay locked once overcharged
+    pub overcharge_lockout_ticks: u32,
+}
+
+impl Default for AbilityConfig {
+    fn default() -> Self {
+        Self {
+            combos_enabled: false,
+            charge_dash_enabled: false,
+            dash_max_charge_ticks: 60,      // 1 second
+            dash_max_charge_mult: 163840,   // 2.5 * 65536
+            mine_form: None,
+            mine_radius: 196608,            // 3.0 * 65536
+            mine_duration_ticks: 300,       // 5 seconds
+            mine_damage_per_tick: 1092,     // full health in ~1 second
+            mine_slow_mult: 39322,          // 0.6 * 65536
+            tether_form: None,
+            tether_range: 786432,           // 12.0 * 65536
+            tether_duration_ticks: 180,     // 3 seconds
+            tether_rest_length: 196608,     // 3.0 * 65536
+            tether_stiffness: 131072,       // 2.0 * 65536
+            energy_enabled: false,
+            max_energy: 6553600,            // 100.0 * 65536
+            energy_regen_per_tick: 21845,   // 20 energy per second
+            energy_cost: 2621440,           // 40.0 * 65536
+            overcharge_enabled: false,
+            overcharge_per_use: 40,
+            overcharge_decay_per_tick: 1,   // a cast drains in 40 ticks
+            overcharge_max: 100,            // third cast in quick succession
+            overcharge_lockout_ticks: 120,  // 2 seconds
+        }
+    }
+}
+
+impl AbilityConfig {
+    /// Energy an activation costs (0 when the energy pool is disabled).
+    pub fn activation_cost(&self) -> Fixed {
+        if self.energy_enabled {
+            self.energy_cost
+        } else {
+            0
+        }
+    }
+}
 
 /// Ability cooldowns by form (in ticks at 60Hz).
 pub const ABILITY_COOLDOWNS: [u32; 5] = [
@@ -37,6 +187,15 @@ const GRAVITY_WELL_TICKS: u32 = 180; // 3 seconds
 /// Consume extended radius multiplier for Ancient ability.
 const CONSUME_RADIUS_MULT: Fixed = 98304; // 1.5 * 65536
 
+/// Dash velocity multiplier when launched inside an enemy gravity well.
+const WELL_DASH_MULT: Fixed = 32768; // 0.5 * 65536
+
+/// Ticks of movement slow from standing in a mine (refreshed every tick inside).
+const MINE_SLOW_TICKS: u32 = 1;
+
+/// Fastest a tether spring pulls either end (units per second).
+const TETHER_MAX_PULL: Fixed = 786432; // 12.0 * 65536
+
 /// Get ability type for a form.
 pub fn ability_for_form(form: Form) -> AbilityType {
     match form {
@@ -48,13 +207,29 @@ pub fn ability_for_form(form: Form) -> AbilityType {
     }
 }
 
+/// Get the ability a form uses under `config` (`Mine` or `Tether` may
+/// replace one; `Mine` wins if both name the same form).
+pub fn configured_ability(form: Form, config: &AbilityConfig) -> AbilityType {
+    if config.mine_form == Some(form) {
+        AbilityType::Mine
+    } else if config.tether_form == Some(form) {
+        AbilityType::Tether
+    } else {
+        ability_for_form(form)
+    }
+}
+
 /// Activate a player's ability.
 /// Returns an event if ability was successfully activated.
-pub fn activate_ability(state: &mut MatchState, player_id: PlayerId) -> Option<GameEvent> {
+pub fn activate_ability(
+    state: &mut MatchState,
+    player_id: PlayerId,
+    config: &AbilityConfig,
+) -> Option<GameEvent> {
     // Check if player exists and can use ability
     let (form, position, velocity, can_activate) = {
         let player = state.players.get(&player_id)?;
-        if !player.alive || !player.ability_ready() {
+        if !player.alive || !player.ability_ready(config.activation_cost()) {
             return None;
         }
         (player.form, player.position, player.velocity, true)
@@ -64,13 +239,13 @@ pub fn activate_ability(state: &mut MatchState, player_id: PlayerId) -> Option<G
         return None;
     }
 
-    let ability_type = ability_for_form(form);
+    let ability_type = configured_ability(form, config);
     let cooldown = (ABILITY_COOLDOWNS[form as usize] as i64 * FIXED_ONE as i64) as Fixed;
 
     // Apply ability effect
     match ability_type {
         AbilityType::Dash => {
-            activate_dash(state, player_id, velocity);
+            activate_dash(state, player_id, velocity, config);
         }
         AbilityType::PhaseShift => {
             activate_phase_shift(state, player_id);
@@ -84,11 +259,36 @@ pub fn activate_ability(state: &mut MatchState, player_id: PlayerId) -> Option<G
         AbilityType::Consume => {
             activate_consume(state, player_id);
         }
+        AbilityType::Mine => {
+            activate_mine(state, player_id, position, config);
+        }
+        AbilityType::Tether => {
+            // Nothing in range: the ability fizzles without cost
+            if !activate_tether(state, player_id, position, config) {
+                return None;
+            }
+        }
     }
 
-    // Set cooldown
+    // Resolve interactions with other active abilities
+    if config.combos_enabled {
+        apply_combos(state, player_id, ability_type, position);
+    }
+
+    // Pay energy, or start the cooldown
     if let Some(player) = state.players.get_mut(&player_id) {
-        player.ability_cooldown = cooldown;
+        if config.energy_enabled {
+            player.energy -= config.energy_cost;
+        } else {
+            player.ability_cooldown = cooldown;
+        }
+        if config.overcharge_enabled {
+            player.overcharge = player.overcharge.saturating_add(config.overcharge_per_use);
+            if player.overcharge >= config.overcharge_max {
+                player.overcharge = 0;
+                player.overcharge_lockout_ticks = config.overcharge_lockout_ticks;
+            }
+        }
     }
 
     Some(GameEvent::ability_used(
@@ -98,8 +298,47 @@ pub fn activate_ability(state: &mut MatchState, player_id: PlayerId) -> Option<G
     ))
 }
 
+/// Regenerate every alive player's energy pool.
+pub fn regen_energy(state: &mut MatchState, config: &AbilityConfig) {
+    if !config.energy_enabled {
+        return;
+    }
+    for player in state.players.values_mut() {
+        if !player.alive {
+            continue;
+        }
+        player.max_energy = config.max_energy;
+        player.energy = player.energy
+            .saturating_add(config.energy_regen_per_tick)
+            .min(config.max_energy);
+    }
+}
+
+/// Drain overcharge meters and count down lockouts.
+///
+/// A locked-out player's meter stays empty until the lockout ends.
+pub fn decay_overcharge(state: &mut MatchState, config: &AbilityConfig) {
+    if !config.overcharge_enabled {
+        return;
+    }
+    for player in state.players.values_mut() {
+        if player.overcharge_lockout_ticks > 0 {
+            player.overcharge_lockout_ticks -= 1;
+        } else {
+            player.overcharge = player.overcharge.saturating_sub(config.overcharge_decay_per_tick);
+        }
+    }
+}
+
 /// Spark ability: Quick dash in movement direction.
-fn activate_dash(state: &mut MatchState, player_id: PlayerId, current_velocity: FixedVec2) {
+///
+/// Any accumulated charge scales the dash speed and is consumed.
+fn activate_dash(
+    state: &mut MatchState,
+    player_id: PlayerId,
+    current_velocity: FixedVec2,
+    config: &AbilityConfig,
+) {
     if let Some(player) = state.players.get_mut(&player_id) {
         let direction = if current_velocity.length_squared() > 0 {
             current_velocity.normalize()
@@ -107,10 +346,21 @@ fn activate_dash(state: &mut MatchState, player_id: PlayerId, current_velocity:
             // Default to right if no velocity
             FixedVec2::new(FIXED_ONE, 0)
         };
-        player.dash_velocity = Some(direction.scale(DASH_SPEED));
+        let speed = fixed_mul(DASH_SPEED, dash_charge_multiplier(player.dash_charge_ticks, config));
+        player.dash_charge_ticks = 0;
+        player.dash_velocity = Some(direction.scale(speed));
     }
 }
 
+/// Dash speed multiplier for a given charge, linear from 1.0 to the
+/// configured maximum.
+pub fn dash_charge_multiplier(charge_ticks: u32, config: &AbilityConfig) -> Fixed {
+    let max_ticks = config.dash_max_charge_ticks.max(1);
+    let charge = charge_ticks.min(max_ticks) as i64;
+    let bonus = (config.dash_max_charge_mult - FIXED_ONE) as i64;
+    FIXED_ONE + (bonus * charge / max_ticks as i64) as Fixed
+}
+
 /// Glyph ability: Brief invulnerability.
 fn activate_phase_shift(state: &mut MatchState, player_id: PlayerId) {
     if let Some(player) = state.players.get_mut(&player_id) {
@@ -151,6 +401,8 @@ fn activate_gravity_well(state: &mut MatchState, player_id: PlayerId, position:
         position,
         remaining_ticks: GRAVITY_WELL_TICKS,
         radius: GRAVITY_WELL_RADIUS,
+        triggered: Vec::new(),
+        target_player: None,
     };
     state.active_abilities.push(effect);
 }
@@ -168,12 +420,130 @@ fn activate_consume(state: &mut MatchState, player_id: PlayerId) {
         position,
         remaining_ticks: 60, // 1 second of extended range
         radius: CONSUME_RADIUS_MULT,
+        triggered: Vec::new(),
+        target_player: None,
+    };
+    state.active_abilities.push(effect);
+}
+
+/// Mine ability: Drop an area-denial zone at the caster's position.
+fn activate_mine(
+    state: &mut MatchState,
+    player_id: PlayerId,
+    position: FixedVec2,
+    config: &AbilityConfig,
+) {
+    let effect = ActiveAbilityEffect {
+        ability_type: AbilityType::Mine,
+        source_player: player_id,
+        position,
+        remaining_ticks: config.mine_duration_ticks,
+        radius: config.mine_radius,
+        triggered: Vec::new(),
+        target_player: None,
     };
     state.active_abilities.push(effect);
 }
 
+/// Tether ability: Bind the nearest enemy within range to the caster.
+///
+/// Ties go to the lower player ID. Returns false if no enemy is in range.
+fn activate_tether(
+    state: &mut MatchState,
+    player_id: PlayerId,
+    position: FixedVec2,
+    config: &AbilityConfig,
+) -> bool {
+    let team = state.players.get(&player_id).and_then(|p| p.team);
+    let range_sq = (config.tether_range as i64 * config.tether_range as i64) >> 16;
+
+    let mut nearest: Option<(PlayerId, i64)> = None;
+    for (id, other) in &state.players {
+        if *id == player_id || !other.alive || (team.is_some() && other.team == team) {
+            continue;
+        }
+        let dist_sq = position.distance_squared_wide(other.position);
+        if dist_sq <= range_sq && nearest.is_none_or(|(_, best)| dist_sq < best) {
+            nearest = Some((*id, dist_sq));
+        }
+    }
+    let Some((target_id, _)) = nearest else {
+        return false;
+    };
+
+    state.active_abilities.push(ActiveAbilityEffect {
+        ability_type: AbilityType::Tether,
+        source_player: player_id,
+        position,
+        remaining_ticks: config.tether_duration_ticks,
+        radius: config.tether_rest_length,
+        triggered: Vec::new(),
+        target_player: Some(target_id),
+    });
+    state.push_event(GameEvent::tether_attached(state.tick, player_id, target_id));
+    true
+}
+
+/// Apply combo matrix interactions for a freshly activated ability.
+fn apply_combos(
+    state: &mut MatchState,
+    player_id: PlayerId,
+    ability_type: AbilityType,
+    position: FixedVec2,
+) {
+    match ability_type {
+        AbilityType::Repel => {
+            let range_sq = fixed_mul(REPEL_RADIUS, REPEL_RADIUS);
+            let mut dispelled: Vec<PlayerId> = Vec::new();
+
+            state.active_abilities.retain(|effect| {
+                let hit = effect.ability_type == AbilityType::GravityWell
+                    && effect.source_player != player_id
+                    && position.distance_squared(effect.position) < range_sq;
+                if hit {
+                    dispelled.push(effect.source_player);
+                }
+                !hit
+            });
+
+            for owner in dispelled {
+                let event = GameEvent::ability_combo(
+                    state.tick,
+                    player_id,
+                    owner,
+                    ComboType::RepelDispelsWell,
+                );
+                state.push_event(event);
+            }
+        }
+        AbilityType::Dash => {
+            let well_owner = state.active_abilities.iter()
+                .find(|e| {
+                    e.ability_type == AbilityType::GravityWell
+                        && e.source_player != player_id
+                        && position.distance_squared(e.position) < fixed_mul(e.radius, e.radius)
+                })
+                .map(|e| e.source_player);
+
+            if let Some(owner) = well_owner {
+                if let Some(player) = state.players.get_mut(&player_id) {
+                    player.dash_velocity = player.dash_velocity.map(|v| v.scale(WELL_DASH_MULT));
+                }
+                let event = GameEvent::ability_combo(
+                    state.tick,
+                    player_id,
+                    owner,
+                    ComboType::WellDampensDash,
+                );
+                state.push_event(event);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Process active ability effects each tick.
-pub fn process_active_abilities(state: &mut MatchState) {
+pub fn process_active_abilities(state: &mut MatchState, config: &AbilityConfig) {
     let gravity_wells: Vec<(FixedVec2, Fixed, PlayerId)> = state.active_abilities
         .iter()
         .filter(|e| e.ability_type == AbilityType::GravityWell)
@@ -197,6 +567,9 @@ pub fn process_active_abilities(state: &mut MatchState) {
         }
     }
 
+    process_mines(state, config);
+    process_tethers(state, config);
+
     // Decay ability timers
     state.active_abilities.retain_mut(|effect| {
         effect.remaining_ticks = effect.remaining_ticks.saturating_sub(1);
@@ -204,6 +577,110 @@ pub fn process_active_abilities(state: &mut MatchState) {
     });
 }
 
+/// Damage and slow enemies standing in mines.
+///
+/// Mines resolve in placement order and players in ID order. An enemy
+/// killed by a mine is credited to the mine's owner.
+fn process_mines(state: &mut MatchState, config: &AbilityConfig) {
+    let mut lethal: Vec<(PlayerId, PlayerId)> = Vec::new();
+    let mut triggered: Vec<(PlayerId, PlayerId)> = Vec::new();
+
+    for mine in state.active_abilities.iter_mut() {
+        if mine.ability_type != AbilityType::Mine {
+            continue;
+        }
+        let radius_sq = (mine.radius as i64 * mine.radius as i64) >> 16;
+
+        for (player_id, player) in state.players.iter_mut() {
+            if *player_id == mine.source_player || !player.alive || player.invulnerable_ticks > 0 {
+                continue;
+            }
+            if mine.position.distance_squared_wide(player.position) >= radius_sq {
+                continue;
+            }
+            if lethal.iter().any(|(victim, _)| victim == player_id) {
+                continue;
+            }
+
+            if !mine.triggered.contains(player_id) {
+                mine.triggered.push(*player_id);
+                triggered.push((*player_id, mine.source_player));
+            }
+
+            // Mines run after movement, so slow the next tick's input speed
+            player.slow_mult = if player.slow_ticks > 0 {
+                player.slow_mult.min(config.mine_slow_mult)
+            } else {
+                config.mine_slow_mult
+            };
+            player.slow_ticks = MINE_SLOW_TICKS;
+            let damage = fixed_mul(config.mine_damage_per_tick, get_shield_multiplier(player));
+            player.health = player.health.saturating_sub(damage);
+            if player.health <= 0 {
+                lethal.push((*player_id, mine.source_player));
+            }
+        }
+    }
+
+    for (player_id, owner) in triggered {
+        state.push_event(GameEvent::mine_triggered(state.tick, player_id, owner));
+    }
+
+    for (victim, owner) in lethal {
+        if let Some(placement) = state.eliminate_player(&victim, Some(&owner)) {
+            state.push_event(GameEvent::player_eliminated(state.tick, victim, Some(owner), placement));
+        }
+    }
+}
+
+/// Set each player's tether spring pull for the next physics step.
+///
+/// Tethers on their last tick, or with an eliminated end, are released
+/// instead (and removed by the timer decay that follows).
+fn process_tethers(state: &mut MatchState, config: &AbilityConfig) {
+    let mut pulls: Vec<(PlayerId, FixedVec2)> = Vec::new();
+    let mut released: Vec<(PlayerId, PlayerId)> = Vec::new();
+
+    for tether in state.active_abilities.iter_mut() {
+        if tether.ability_type != AbilityType::Tether {
+            continue;
+        }
+        let Some(target_id) = tether.target_player else {
+            continue;
+        };
+        let ends = state.players.get(&tether.source_player)
+            .zip(state.players.get(&target_id))
+            .filter(|(caster, target)| caster.alive && target.alive);
+        let Some((caster, target)) = ends.filter(|_| tether.remaining_ticks > 1) else {
+            tether.remaining_ticks = 1;
+            released.push((tether.source_player, target_id));
+            continue;
+        };
+
+        let offset = caster.position.sub(target.position);
+        let stretch = offset.length() - tether.radius;
+        if stretch <= 0 {
+            continue;
+        }
+        let speed = fixed_mul(config.tether_stiffness, stretch).min(TETHER_MAX_PULL);
+        let pull = offset.normalize().scale(speed);
+        pulls.push((target_id, pull));
+        pulls.push((tether.source_player, pull.negate()));
+    }
+
+    for player in state.players.values_mut() {
+        player.tether_pull = FixedVec2::ZERO;
+    }
+    for (player_id, pull) in pulls {
+        if let Some(player) = state.players.get_mut(&player_id) {
+            player.tether_pull = player.tether_pull.add(pull);
+        }
+    }
+    for (caster, target) in released {
+        state.push_event(GameEvent::tether_released(state.tick, caster, target));
+    }
+}
+
 /// Check if a player has extended consume radius active.
 pub fn has_consume_active(state: &MatchState, player_id: PlayerId) -> bool {
     state.active_abilities.iter().any(|e| {
@@ -246,7 +723,7 @@ mod tests {
         state.alive_count = 1;
 
         // Activate ability
-        let event = activate_ability(&mut state, player_id);
+        let event = activate_ability(&mut state, player_id, &AbilityConfig::default());
         assert!(event.is_some());
 
         // Check dash velocity was set
@@ -255,6 +732,15 @@ mod tests {
         assert!(player.ability_cooldown > 0);
     }
 
+    #[test]
+    fn test_dash_charge_multiplier() {
+        let config = AbilityConfig::default();
+        assert_eq!(dash_charge_multiplier(0, &config), FIXED_ONE);
+        assert_eq!(dash_charge_multiplier(config.dash_max_charge_ticks, &config), config.dash_max_charge_mult);
+        // Capped past full charge
+        assert_eq!(dash_charge_multiplier(u32::MAX, &config), config.dash_max_charge_mult);
+    }
+
     #[test]
     fn test_phase_shift_invulnerability() {
         let mut state = MatchState::new([0; 16], 12345);
@@ -267,7 +753,7 @@ mod tests {
         state.alive_count = 1;
 
         // Activate ability
-        let event = activate_ability(&mut state, player_id);
+        let event = activate_ability(&mut state, player_id, &AbilityConfig::default());
         assert!(event.is_some());
 
         // Check invulnerability
@@ -287,7 +773,7 @@ mod tests {
         state.alive_count = 1;
 
         // Activate ability
-        let event = activate_ability(&mut state, player_id);
+        let event = activate_ability(&mut state, player_id, &AbilityConfig::default());
         assert!(event.is_some());
 
         // Check gravity well was created
@@ -306,11 +792,335 @@ mod tests {
         state.alive_count = 1;
 
         // First activation should succeed
-        let event1 = activate_ability(&mut state, player_id);
+        let event1 = activate_ability(&mut state, player_id, &AbilityConfig::default());
         assert!(event1.is_some());
 
         // Second activation should fail (cooldown)
-        let event2 = activate_ability(&mut state, player_id);
+        let event2 = activate_ability(&mut state, player_id, &AbilityConfig::default());
         assert!(event2.is_none());
     }
+
+    #[test]
+    fn test_mine_damages_enemy_and_expires() {
+        use crate::game::events::GameEventData;
+
+        let config = AbilityConfig {
+            mine_form: Some(Form::Ward),
+            mine_duration_ticks: 30,
+            mine_damage_per_tick: 1000,
+            ..Default::default()
+        };
+        let mut state = MatchState::new([0; 16], 12345);
+        state.phase = crate::game::state::MatchPhase::Playing;
+
+        let layer_id = PlayerId::new([1; 16]);
+        let enemy_id = PlayerId::new([2; 16]);
+        let mut layer = PlayerState::new(layer_id, FixedVec2::ZERO);
+        layer.form = Form::Ward;
+        state.players.insert(layer_id, layer);
+        // Enemy starts outside the mine
+        state.players.insert(enemy_id, PlayerState::new(enemy_id, FixedVec2::new(10 * FIXED_ONE, 0)));
+        state.alive_count = 2;
+
+        let event = activate_ability(&mut state, layer_id, &config).unwrap();
+        assert!(matches!(
+            event.data,
+            GameEventData::AbilityUsed { ability_type, .. } if ability_type == AbilityType::Mine as u8
+        ));
+
+        process_active_abilities(&mut state, &config);
+        assert_eq!(state.players[&enemy_id].health, FIXED_ONE);
+
+        // Enemy walks in
+        state.players.get_mut(&enemy_id).unwrap().position = FixedVec2::new(FIXED_ONE, 0);
+        state.take_events();
+        for _ in 1..10 {
+            process_active_abilities(&mut state, &config);
+        }
+        assert_eq!(state.players[&enemy_id].health, FIXED_ONE - 9 * 1000);
+        assert_eq!(state.players[&layer_id].health, FIXED_ONE, "owner is unaffected");
+        let triggers = state.take_events().iter()
+            .filter(|e| matches!(e.data, GameEventData::MineTriggered { player_id, .. } if player_id == enemy_id))
+            .count();
+        assert_eq!(triggers, 1);
+
+        // 10 ticks used so far; the mine expires after exactly 30
+        for _ in 10..29 {
+            process_active_abilities(&mut state, &config);
+        }
+        assert_eq!(state.active_abilities.len(), 1);
+        process_active_abilities(&mut state, &config);
+        assert!(state.active_abilities.is_empty());
+
+        let health = state.players[&enemy_id].health;
+        process_active_abilities(&mut state, &config);
+        assert_eq!(state.players[&enemy_id].health, health);
+    }
+
+    #[test]
+    fn test_mine_slows_enemy_movement() {
+        use crate::game::input::InputFrame;
+        use crate::game::tick::MatchConfig;
+        use std::collections::BTreeMap;
+
+        let config = MatchConfig {
+            ability: AbilityConfig {
+                mine_form: Some(Form::Ward),
+                mine_duration_ticks: 600,
+                mine_radius: 20 * FIXED_ONE,
+                mine_damage_per_tick: 1,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Distance the enemy walks in one tick, with or without a mine down
+        let step = |with_mine: bool| {
+            let mut state = MatchState::new([0; 16], 12345);
+            state.phase = crate::game::state::MatchPhase::Playing;
+            let layer_id = PlayerId::new([1; 16]);
+            let enemy_id = PlayerId::new([2; 16]);
+            let mut layer = PlayerState::new(layer_id, FixedVec2::new(-2 * FIXED_ONE, 0));
+            layer.form = Form::Ward;
+            state.players.insert(layer_id, layer);
+            state.players.insert(enemy_id, PlayerState::new(enemy_id, FixedVec2::ZERO));
+            state.alive_count = 2;
+            if with_mine {
+                activate_ability(&mut state, layer_id, &config.ability).unwrap();
+            }
+
+            let mut inputs = BTreeMap::new();
+            inputs.insert(enemy_id, InputFrame::with_movement(127, 0));
+            state.tick_once(&inputs, &config);
+            let x = state.players[&enemy_id].position.x;
+            state.tick_once(&inputs, &config);
+            state.players[&enemy_id].position.x - x
+        };
+
+        let free = step(false);
+        let slowed = step(true);
+        assert!(free > 0);
+        assert_eq!(slowed, fixed_mul(free, config.ability.mine_slow_mult));
+    }
+
+    #[test]
+    fn test_tether_pulls_enemy_then_releases() {
+        use crate::game::events::GameEventData;
+        use crate::game::tick::MatchConfig;
+        use std::collections::BTreeMap;
+
+        let config = MatchConfig {
+            ability: AbilityConfig {
+                tether_form: Some(Form::Ward),
+                tether_duration_ticks: 30,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut state = MatchState::new([0; 16], 12345);
+        state.phase = crate::game::state::MatchPhase::Playing;
+
+        let caster_id = PlayerId::new([1; 16]);
+        let enemy_id = PlayerId::new([2; 16]);
+        let far_id = PlayerId::new([3; 16]);
+        for (id, x) in [(caster_id, 0), (enemy_id, 8), (far_id, -20)] {
+            let mut player = PlayerState::new(id, FixedVec2::new(x * FIXED_ONE, 0));
+            player.form = Form::Ward;
+            state.players.insert(id, player);
+        }
+        state.alive_count = 3;
+
+        let event = activate_ability(&mut state, caster_id, &config.ability).unwrap();
+        assert!(matches!(
+            event.data,
+            GameEventData::AbilityUsed { ability_type, .. } if ability_type == AbilityType::Tether as u8
+        ));
+        assert_eq!(state.active_abilities[0].target_player, Some(enemy_id));
+        assert!(state.take_events().iter().any(|e| matches!(
+            e.data,
+            GameEventData::TetherAttached { player_id, target_id }
+                if player_id == caster_id && target_id == enemy_id
+        )));
+
+        // The enemy is reeled in toward the caster, tick after tick
+        let inputs = BTreeMap::new();
+        let mut last_x = state.players[&enemy_id].position.x;
+        for _ in 0..10 {
+            state.tick_once(&inputs, &config);
+            let x = state.players[&enemy_id].position.x;
+            if state.tick > 1 {
+                assert!(x < last_x, "enemy pulled closer at tick {}", state.tick);
+            }
+            last_x = x;
+        }
+        assert_eq!(state.players[&far_id].position.x, -20 * FIXED_ONE, "bystander untouched");
+
+        // Released once the 30 ticks are up
+        let mut released = false;
+        for _ in 10..30 {
+            let result = state.tick_once(&inputs, &config);
+            released |= result.events.iter().any(|e| matches!(
+                e.data,
+                GameEventData::TetherReleased { target_id, .. } if target_id == enemy_id
+            ));
+        }
+        assert!(released);
+        assert!(state.active_abilities.is_empty());
+        assert_eq!(state.players[&enemy_id].tether_pull, FixedVec2::ZERO);
+        let x = state.players[&enemy_id].position.x;
+        state.tick_once(&inputs, &config);
+        assert!(state.players[&enemy_id].position.x >= x, "no pull after release");
+    }
+
+    /// Place an enemy gravity well at `position`.
+    fn add_enemy_well(state: &mut MatchState, owner: PlayerId, position: FixedVec2) {
+        state.active_abilities.push(ActiveAbilityEffect {
+            ability_type: AbilityType::GravityWell,
+            source_player: owner,
+            position,
+            remaining_ticks: GRAVITY_WELL_TICKS,
+            radius: GRAVITY_WELL_RADIUS,
+            triggered: Vec::new(),
+            target_player: None,
+        });
+    }
+
+    #[test]
+    fn test_repel_dispels_nearby_well() {
+        let mut state = MatchState::new([0; 16], 12345);
+        state.phase = crate::game::state::MatchPhase::Playing;
+
+        let ward_id = PlayerId::new([1; 16]);
+        let arcane_id = PlayerId::new([2; 16]);
+        let mut ward = PlayerState::new(ward_id, FixedVec2::ZERO);
+        ward.form = Form::Ward;
+        state.players.insert(ward_id, ward);
+        add_enemy_well(&mut state, arcane_id, FixedVec2::new(2 * FIXED_ONE, 0));
+
+        let config = AbilityConfig { combos_enabled: true, ..Default::default() };
+        assert!(activate_ability(&mut state, ward_id, &config).is_some());
+
+        assert!(state.active_abilities.is_empty(), "Well should be dispelled");
+        let events = state.take_events();
+        assert!(events.iter().any(|e| matches!(
+            e.data,
+            crate::game::events::GameEventData::AbilityCombo {
+                player_id,
+                other_player,
+                combo: ComboType::RepelDispelsWell,
+            } if player_id == ward_id && other_player == arcane_id
+        )));
+    }
+
+    #[test]
+    fn test_combos_disabled_by_default() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let ward_id = PlayerId::new([1; 16]);
+        let mut ward = PlayerState::new(ward_id, FixedVec2::ZERO);
+        ward.form = Form::Ward;
+        state.players.insert(ward_id, ward);
+        add_enemy_well(&mut state, PlayerId::new([2; 16]), FixedVec2::new(2 * FIXED_ONE, 0));
+
+        activate_ability(&mut state, ward_id, &AbilityConfig::default());
+
+        assert_eq!(state.active_abilities.len(), 1);
+        assert!(state.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_well_dampens_dash() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let spark_id = PlayerId::new([1; 16]);
+        let mut spark = PlayerState::new(spark_id, FixedVec2::ZERO);
+        spark.velocity = FixedVec2::new(FIXED_ONE, 0);
+        state.players.insert(spark_id, spark);
+        add_enemy_well(&mut state, PlayerId::new([2; 16]), FixedVec2::ZERO);
+
+        let config = AbilityConfig { combos_enabled: true, ..Default::default() };
+        activate_ability(&mut state, spark_id, &config);
+
+        let dash = state.players[&spark_id].dash_velocity.unwrap();
+        assert_eq!(dash.x, fixed_mul(DASH_SPEED, WELL_DASH_MULT));
+        // Dash does not consume the well
+        assert_eq!(state.active_abilities.len(), 1);
+    }
+
+    #[test]
+    fn test_energy_gates_ability_until_regenerated() {
+        let mut state = MatchState::new([0; 16], 12345);
+        state.phase = crate::game::state::MatchPhase::Playing;
+        let player_id = PlayerId::new([1; 16]);
+        state.players.insert(player_id, PlayerState::new(player_id, FixedVec2::ZERO));
+        state.alive_count = 1;
+
+        let config = AbilityConfig { energy_enabled: true, ..Default::default() };
+
+        // Pool starts empty
+        assert!(activate_ability(&mut state, player_id, &config).is_none());
+
+        let ticks_to_afford = (config.energy_cost / config.energy_regen_per_tick + 1) as u32;
+        for _ in 0..ticks_to_afford - 1 {
+            regen_energy(&mut state, &config);
+        }
+        assert!(activate_ability(&mut state, player_id, &config).is_none());
+        regen_energy(&mut state, &config);
+        assert!(activate_ability(&mut state, player_id, &config).is_some());
+
+        let player = &state.players[&player_id];
+        assert!(player.energy < config.energy_cost);
+        assert_eq!(player.ability_cooldown, 0, "energy replaces the cooldown");
+
+        // A full pool bursts two activations back to back
+        for _ in 0..(config.max_energy / config.energy_regen_per_tick + 1) {
+            regen_energy(&mut state, &config);
+        }
+        assert_eq!(state.players[&player_id].energy, config.max_energy);
+        assert!(activate_ability(&mut state, player_id, &config).is_some());
+        assert!(activate_ability(&mut state, player_id, &config).is_some());
+        assert!(activate_ability(&mut state, player_id, &config).is_none());
+    }
+
+    /// Cast every `interval` ticks for `ticks`, returning the number of
+    /// successful casts and whether a lockout ever started.
+    fn cast_every(interval: u32, ticks: u32) -> (u32, bool) {
+        let mut state = MatchState::new([0; 16], 12345);
+        state.phase = crate::game::state::MatchPhase::Playing;
+        let player_id = PlayerId::new([1; 16]);
+        state.players.insert(player_id, PlayerState::new(player_id, FixedVec2::ZERO));
+        state.alive_count = 1;
+
+        // Cheap energy build: plenty of casts available every tick
+        let config = AbilityConfig {
+            energy_enabled: true,
+            energy_cost: FIXED_ONE,
+            energy_regen_per_tick: FIXED_ONE,
+            overcharge_enabled: true,
+            ..Default::default()
+        };
+        state.players.get_mut(&player_id).unwrap().energy = config.max_energy;
+
+        let (mut casts, mut locked) = (0, false);
+        for tick in 0..ticks {
+            if tick % interval == 0 && activate_ability(&mut state, player_id, &config).is_some() {
+                casts += 1;
+            }
+            locked |= state.players[&player_id].overcharge_lockout_ticks > 0;
+            decay_overcharge(&mut state, &config);
+            regen_energy(&mut state, &config);
+        }
+        (casts, locked)
+    }
+
+    #[test]
+    fn test_ability_spam_overcharges_into_lockout() {
+        // Every tick: third cast overcharges, then nothing for the lockout
+        let (casts, locked) = cast_every(1, 60);
+        assert!(locked);
+        assert_eq!(casts, 3);
+
+        // Spaced past the drain time the meter never fills
+        let (casts, locked) = cast_every(45, 600);
+        assert!(!locked);
+        assert_eq!(casts, 600u32.div_ceil(45));
+    }
 }
@@ -4,6 +4,7 @@
 
 use crate::core::fixed::{Fixed, fixed_mul};
 use crate::core::vec2::FixedVec2;
+use crate::game::form_table::FormTable;
 use crate::game::state::{PlayerId, PlayerState, RuneState, MatchState};
 
 /// Check if two circles overlap.
@@ -16,7 +17,8 @@ pub fn circles_overlap(
 ) -> bool {
     let combined_radius = radius_a + radius_b;
     let combined_radius_sq = fixed_mul(combined_radius, combined_radius);
-    pos_a.distance_squared(pos_b) <= combined_radius_sq
+    // Widened so far-apart circles cannot wrap into a false overlap
+    pos_a.distance_squared_wide(pos_b) <= combined_radius_sq as i64
 }
 
 /// Result of a player-vs-player collision.
@@ -31,9 +33,9 @@ pub struct PlayerCollision {
 /// Check collision between two players.
 ///
 /// Returns Some(collision) if they collide and one can eat the other.
-/// Uses player ID for tie-breaking when forms are equal.
+/// Uses player ID for tie-breaking when tiers are equal.
 /// Respects invulnerability and shield buffs.
-pub fn check_player_collision(a: &PlayerState, b: &PlayerState) -> Option<PlayerCollision> {
+pub fn check_player_collision(a: &PlayerState, b: &PlayerState, table: &FormTable) -> Option<PlayerCollision> {
     // Skip if either is dead
     if !a.alive || !b.alive {
         return None;
@@ -45,17 +47,17 @@ pub fn check_player_collision(a: &PlayerState, b: &PlayerState) -> Option<Player
     }
 
     // Check if circles overlap
-    if !circles_overlap(a.position, a.radius(), b.position, b.radius()) {
+    if !circles_overlap(a.position, a.radius(table), b.position, b.radius(table)) {
         return None;
     }
 
-    // Determine winner based on form
-    let (winner, loser) = if a.form > b.form {
+    // Determine winner based on ladder tier
+    let (winner, loser) = if table.can_eat(a.tier, b.tier) {
         (a.id, b.id)
-    } else if b.form > a.form {
+    } else if table.can_eat(b.tier, a.tier) {
         (b.id, a.id)
     } else {
-        // Same form - check shield buffs first
+        // Same tier - check shield buffs first
         let a_has_shield = a.shield_buff_ticks > 0
             || a.has_shrine_buff(crate::game::state::ShrineType::Shield);
         let b_has_shield = b.shield_buff_ticks > 0
@@ -99,7 +101,7 @@ pub fn check_all_player_collisions(state: &MatchState) -> Vec<PlayerCollision> {
             if let (Some(player_a), Some(player_b)) =
                 (state.players.get(id_a), state.players.get(id_b))
             {
-                if let Some(collision) = check_player_collision(player_a, player_b) {
+                if let Some(collision) = check_player_collision(player_a, player_b, &state.form_table) {
                     collisions.push(collision);
                 }
             }
@@ -117,14 +119,14 @@ pub struct RuneCollision {
 }
 
 /// Check if a player collides with a rune.
-pub fn check_rune_collision(player: &PlayerState, rune: &RuneState) -> bool {
+pub fn check_rune_collision(player: &PlayerState, rune: &RuneState, table: &FormTable) -> bool {
     if !player.alive || rune.collected {
         return false;
     }
 
     circles_overlap(
         player.position,
-        player.radius(),
+        player.radius(table),
         rune.position,
         RuneState::RADIUS,
     )
@@ -146,7 +148,7 @@ pub fn check_all_rune_collisions(state: &MatchState) -> Vec<RuneCollision> {
                 continue;
             }
 
-            if check_rune_collision(player, rune) {
+            if check_rune_collision(player, rune, &state.form_table) {
                 collisions.push(RuneCollision {
                     player_id: *player_id,
                     rune_id: *rune_id,
@@ -170,8 +172,7 @@ pub fn check_bounds_collision(state: &MatchState, player: &PlayerState) -> bool
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::fixed::to_fixed;
-    use crate::game::state::Form;
+    use crate::core::fixed::{to_fixed, FIXED_ONE};
 
     #[test]
     fn test_circles_overlap() {
@@ -185,6 +186,10 @@ mod tests {
         // Should not overlap
         let pos_c = FixedVec2::new(to_fixed(2.0), 0);
         assert!(!circles_overlap(pos_a, radius, pos_c, radius));
+
+        // Opposite sides of the arena must not overlap
+        let far = FixedVec2::new(to_fixed(240.0), 0);
+        assert!(!circles_overlap(pos_a, radius, far, radius));
     }
 
     #[test]
@@ -192,21 +197,44 @@ mod tests {
         let id1 = PlayerId::new([1; 16]);
         let id2 = PlayerId::new([2; 16]);
 
-        let mut player1 = PlayerState::new(id1, FixedVec2::new(0, 0));
+        let table = FormTable::default();
+        let player1 = PlayerState::new(id1, FixedVec2::new(0, 0));
         let mut player2 = PlayerState::new(id2, FixedVec2::new(to_fixed(0.5), 0));
 
         // Same form - id1 < id2, so id1 wins
-        let collision = check_player_collision(&player1, &player2).unwrap();
+        let collision = check_player_collision(&player1, &player2, &table).unwrap();
         assert_eq!(collision.winner, id1);
         assert_eq!(collision.loser, id2);
 
         // Player2 evolves - now player2 wins
-        player2.form = Form::Glyph;
-        let collision = check_player_collision(&player1, &player2).unwrap();
+        player2.set_tier(1, &table);
+        let collision = check_player_collision(&player1, &player2, &table).unwrap();
         assert_eq!(collision.winner, id2);
         assert_eq!(collision.loser, id1);
     }
 
+    #[test]
+    fn test_damaged_player_shrinks_when_configured() {
+        let id1 = PlayerId::new([1; 16]);
+        let id2 = PlayerId::new([2; 16]);
+
+        let mut table = FormTable::default();
+        let player1 = PlayerState::new(id1, FixedVec2::new(0, 0));
+        let mut player2 = PlayerState::new(id2, FixedVec2::new(to_fixed(0.9), 0));
+        player2.health = player2.max_health / 4;
+
+        // Disabled by default: health has no effect on radius
+        let base = player1.radius(&table);
+        assert_eq!(player2.radius(&table), base);
+        assert!(check_player_collision(&player1, &player2, &table).is_some());
+
+        // Half scale at zero health: a quarter-health player shrinks by 37.5%
+        table.set_damaged_radius_scale(FIXED_ONE / 2);
+        assert_eq!(player1.radius(&table), base);
+        assert!(player2.radius(&table) < base);
+        assert!(check_player_collision(&player1, &player2, &table).is_none());
+    }
+
     #[test]
     fn test_player_collision_dead_skipped() {
         let id1 = PlayerId::new([1; 16]);
@@ -216,7 +244,7 @@ mod tests {
         let mut player2 = PlayerState::new(id2, FixedVec2::ZERO);
         player2.alive = false;
 
-        assert!(check_player_collision(&player1, &player2).is_none());
+        assert!(check_player_collision(&player1, &player2, &FormTable::default()).is_none());
     }
 
     #[test]
@@ -225,10 +253,11 @@ mod tests {
         let player = PlayerState::new(id, FixedVec2::ZERO);
         let rune = RuneState::new(0, FixedVec2::new(to_fixed(0.3), 0), crate::game::state::RuneType::Wisdom);
 
-        assert!(check_rune_collision(&player, &rune));
+        let table = FormTable::default();
+        assert!(check_rune_collision(&player, &rune, &table));
 
         // Farther rune
         let far_rune = RuneState::new(1, FixedVec2::new(to_fixed(5.0), 0), crate::game::state::RuneType::Wisdom);
-        assert!(!check_rune_collision(&player, &far_rune));
+        assert!(!check_rune_collision(&player, &far_rune, &table));
     }
 }
@@ -4,7 +4,8 @@
 
 use serde::{Serialize, Deserialize};
 use crate::core::vec2::FixedVec2;
-use crate::game::state::{PlayerId, Form, RuneType};
+use crate::game::state::{PlayerId, Placement, Form, RuneType, ComboType};
+use crate::game::survivor::EnemyType;
 
 /// Priority for event processing order.
 ///
@@ -33,7 +34,42 @@ pub enum GameEventData {
     PlayerEliminated {
         victim_id: PlayerId,
         killer_id: Option<PlayerId>,
-        placement: u8,
+        /// Final placement (0 = the player will respawn)
+        placement: Placement,
+    },
+
+    /// Player joined a match in progress (backfill)
+    PlayerJoined {
+        /// Joining player
+        player_id: PlayerId,
+        /// Spawn position
+        position: FixedVec2,
+    },
+
+    /// Player was downed and awaits a team revive
+    PlayerDowned {
+        /// Downed player
+        player_id: PlayerId,
+        /// Player who downed them (if any)
+        killer_id: Option<PlayerId>,
+    },
+
+    /// Downed player was revived by a teammate
+    PlayerRevived {
+        /// Revived player
+        player_id: PlayerId,
+        /// Teammate who channeled the revive
+        reviver_id: PlayerId,
+    },
+
+    /// Eliminated player returned to play
+    PlayerRespawned {
+        /// Respawned player
+        player_id: PlayerId,
+        /// Respawn position
+        position: FixedVec2,
+        /// Deaths so far (including the one just respawned from)
+        deaths: u32,
     },
 
     /// Player collected a rune
@@ -52,6 +88,16 @@ pub enum GameEventData {
         new_form: Form,
     },
 
+    /// Player de-evolved to previous form (instead of being eliminated)
+    FormDevolved {
+        /// Player who lost a form
+        player_id: PlayerId,
+        /// Form before the hit
+        old_form: Form,
+        /// Form after de-evolving
+        new_form: Form,
+    },
+
     /// Shrine activation started
     ShrineChannelStarted {
         player_id: PlayerId,
@@ -70,12 +116,114 @@ pub enum GameEventData {
         shrine_id: u8,
     },
 
+    /// Another player captured the shrine this player was standing on
+    ShrineContestLost {
+        /// Near-miss player
+        player_id: PlayerId,
+        /// Contested shrine
+        shrine_id: u8,
+        /// Player who captured it
+        winner_id: PlayerId,
+    },
+
     /// Player used ability
     AbilityUsed {
         player_id: PlayerId,
         ability_type: u8,
     },
 
+    /// Enemy first stepped into a mine
+    MineTriggered {
+        /// Player caught in the mine
+        player_id: PlayerId,
+        /// Owner of the mine
+        owner_id: PlayerId,
+    },
+
+    /// Tether bound an enemy to its caster
+    TetherAttached {
+        /// Caster
+        player_id: PlayerId,
+        /// Enemy bound by the tether
+        target_id: PlayerId,
+    },
+
+    /// Tether expired or broke
+    TetherReleased {
+        /// Caster
+        player_id: PlayerId,
+        /// Enemy that was bound
+        target_id: PlayerId,
+    },
+
+    /// Two abilities interacted
+    AbilityCombo {
+        /// Player whose ability triggered the interaction
+        player_id: PlayerId,
+        /// Owner of the other ability involved
+        other_player: PlayerId,
+        /// Which interaction occurred
+        combo: ComboType,
+    },
+
+    /// First elimination of the match by a player
+    FirstBlood {
+        /// Player who scored first blood
+        player_id: PlayerId,
+        /// Player eliminated
+        victim_id: PlayerId,
+        /// Score bonus awarded
+        bonus: u32,
+    },
+
+    /// First evolution of the match
+    FirstEvolution {
+        /// Player who evolved
+        player_id: PlayerId,
+        /// Score bonus awarded
+        bonus: u32,
+    },
+
+    /// First shrine capture of the match
+    FirstShrineCapture {
+        /// Player who captured the shrine
+        player_id: PlayerId,
+        /// Shrine captured
+        shrine_id: u8,
+        /// Score bonus awarded
+        bonus: u32,
+    },
+
+    /// Rune combo chain reached a milestone
+    RuneCombo {
+        /// Player on the combo
+        player_id: PlayerId,
+        /// Runes chained so far
+        combo: u32,
+        /// Bonus points on the milestone rune
+        bonus: u32,
+    },
+
+    /// Survivor wave began
+    WaveStarted {
+        /// Wave number (first wave is 1)
+        wave: u32,
+        /// Enemies the wave will spawn
+        enemy_count: u32,
+    },
+
+    /// Player destroyed a survivor enemy
+    EnemyDefeated {
+        /// Player who destroyed it
+        player_id: PlayerId,
+        /// Enemy destroyed
+        enemy_id: u32,
+        /// Kind of enemy
+        enemy_type: EnemyType,
+        /// Essence (score) awarded
+        points: u32,
+    },
+
     /// Match phase changed
     PhaseChanged {
         old_phase: String,
@@ -117,12 +265,27 @@ impl GameEvent {
     pub fn new(tick: u32, priority: EventPriority, data: GameEventData) -> Self {
         let player_id = match &data {
             GameEventData::PlayerEliminated { victim_id, .. } => Some(*victim_id),
+            GameEventData::PlayerJoined { player_id, .. } => Some(*player_id),
+            GameEventData::PlayerDowned { player_id, .. } => Some(*player_id),
+            GameEventData::PlayerRevived { player_id, .. } => Some(*player_id),
+            GameEventData::PlayerRespawned { player_id, .. } => Some(*player_id),
             GameEventData::RuneCollected { player_id, .. } => Some(*player_id),
             GameEventData::FormEvolved { player_id, .. } => Some(*player_id),
+            GameEventData::FormDevolved { player_id, .. } => Some(*player_id),
             GameEventData::ShrineChannelStarted { player_id, .. } => Some(*player_id),
             GameEventData::ShrineActivated { player_id, .. } => Some(*player_id),
             GameEventData::ShrineChannelInterrupted { player_id, .. } => Some(*player_id),
+            GameEventData::ShrineContestLost { player_id, .. } => Some(*player_id),
             GameEventData::AbilityUsed { player_id, .. } => Some(*player_id),
+            GameEventData::AbilityCombo { player_id, .. } => Some(*player_id),
+            GameEventData::MineTriggered { player_id, .. } => Some(*player_id),
+            GameEventData::TetherAttached { player_id, .. } => Some(*player_id),
+            GameEventData::TetherReleased { player_id, .. } => Some(*player_id),
+            GameEventData::FirstBlood { player_id, .. } => Some(*player_id),
+            GameEventData::FirstEvolution { player_id, .. } => Some(*player_id),
+            GameEventData::FirstShrineCapture { player_id, .. } => Some(*player_id),
+            GameEventData::EnemyDefeated { player_id, .. } => Some(*player_id),
+            GameEventData::RuneCombo { player_id, .. } => Some(*player_id),
             GameEventData::MatchEnded { winner_id, .. } => *winner_id,
             _ => None,
         };
@@ -140,7 +303,7 @@ impl GameEvent {
         tick: u32,
         victim_id: PlayerId,
         killer_id: Option<PlayerId>,
-        placement: u8,
+        placement: Placement,
     ) -> Self {
         Self::new(
             tick,
@@ -153,6 +316,52 @@ impl GameEvent {
         )
     }
 
+    /// Create player joined (backfill) event.
+    pub fn player_joined(tick: u32, player_id: PlayerId, position: FixedVec2) -> Self {
+        Self::new(
+            tick,
+            EventPriority::Other,
+            GameEventData::PlayerJoined { player_id, position },
+        )
+    }
+
+    /// Create player downed event.
+    pub fn player_downed(tick: u32, player_id: PlayerId, killer_id: Option<PlayerId>) -> Self {
+        Self::new(
+            tick,
+            EventPriority::PlayerElimination,
+            GameEventData::PlayerDowned {
+                player_id,
+                killer_id,
+            },
+        )
+    }
+
+    /// Create player revived event.
+    pub fn player_revived(tick: u32, player_id: PlayerId, reviver_id: PlayerId) -> Self {
+        Self::new(
+            tick,
+            EventPriority::Other,
+            GameEventData::PlayerRevived {
+                player_id,
+                reviver_id,
+            },
+        )
+    }
+
+    /// Create player respawned event.
+    pub fn player_respawned(tick: u32, player_id: PlayerId, position: FixedVec2, deaths: u32) -> Self {
+        Self::new(
+            tick,
+            EventPriority::Other,
+            GameEventData::PlayerRespawned {
+                player_id,
+                position,
+                deaths,
+            },
+        )
+    }
+
     /// Create rune collected event.
     pub fn rune_collected(
         tick: u32,
@@ -206,6 +415,19 @@ impl GameEvent {
         )
     }
 
+    /// Create form devolved event.
+    pub fn form_devolved(tick: u32, player_id: PlayerId, old_form: Form, new_form: Form) -> Self {
+        Self::new(
+            tick,
+            EventPriority::FormEvolution,
+            GameEventData::FormDevolved {
+                player_id,
+                old_form,
+                new_form,
+            },
+        )
+    }
+
     /// Create match ended event.
     pub fn match_ended(tick: u32, winner_id: Option<PlayerId>) -> Self {
         Self::new(
@@ -218,6 +440,93 @@ impl GameEvent {
         )
     }
 
+    /// Create mine triggered event.
+    pub fn mine_triggered(tick: u32, player_id: PlayerId, owner_id: PlayerId) -> Self {
+        Self::new(
+            tick,
+            EventPriority::AbilityEffect,
+            GameEventData::MineTriggered { player_id, owner_id },
+        )
+    }
+
+    /// Create tether attached event.
+    pub fn tether_attached(tick: u32, player_id: PlayerId, target_id: PlayerId) -> Self {
+        Self::new(
+            tick,
+            EventPriority::AbilityEffect,
+            GameEventData::TetherAttached { player_id, target_id },
+        )
+    }
+
+    /// Create tether released event.
+    pub fn tether_released(tick: u32, player_id: PlayerId, target_id: PlayerId) -> Self {
+        Self::new(
+            tick,
+            EventPriority::AbilityEffect,
+            GameEventData::TetherReleased { player_id, target_id },
+        )
+    }
+
+    /// Create rune combo milestone event.
+    pub fn rune_combo(tick: u32, player_id: PlayerId, combo: u32, bonus: u32) -> Self {
+        Self::new(
+            tick,
+            EventPriority::RuneCollection,
+            GameEventData::RuneCombo { player_id, combo, bonus },
+        )
+    }
+
+    /// Create survivor wave started event.
+    pub fn wave_started(tick: u32, wave: u32, enemy_count: u32) -> Self {
+        Self::new(
+            tick,
+            EventPriority::Other,
+            GameEventData::WaveStarted { wave, enemy_count },
+        )
+    }
+
+    /// Create survivor enemy defeated event.
+    pub fn enemy_defeated(
+        tick: u32,
+        player_id: PlayerId,
+        enemy_id: u32,
+        enemy_type: EnemyType,
+        points: u32,
+    ) -> Self {
+        Self::new(
+            tick,
+            EventPriority::RuneCollection,
+            GameEventData::EnemyDefeated { player_id, enemy_id, enemy_type, points },
+        )
+    }
+
+    /// Create first blood milestone event.
+    pub fn first_blood(tick: u32, player_id: PlayerId, victim_id: PlayerId, bonus: u32) -> Self {
+        Self::new(
+            tick,
+            EventPriority::Other,
+            GameEventData::FirstBlood { player_id, victim_id, bonus },
+        )
+    }
+
+    /// Create first evolution milestone event.
+    pub fn first_evolution(tick: u32, player_id: PlayerId, bonus: u32) -> Self {
+        Self::new(
+            tick,
+            EventPriority::Other,
+            GameEventData::FirstEvolution { player_id, bonus },
+        )
+    }
+
+    /// Create first shrine capture milestone event.
+    pub fn first_shrine_capture(tick: u32, player_id: PlayerId, shrine_id: u8, bonus: u32) -> Self {
+        Self::new(
+            tick,
+            EventPriority::Other,
+            GameEventData::FirstShrineCapture { player_id, shrine_id, bonus },
+        )
+    }
+
     /// Create shrine channel started event.
     pub fn shrine_channel_started(tick: u32, player_id: PlayerId, shrine_id: u8) -> Self {
         Self::new(
@@ -245,6 +554,15 @@ impl GameEvent {
         )
     }
 
+    /// Create shrine contest lost event.
+    pub fn shrine_contest_lost(tick: u32, player_id: PlayerId, shrine_id: u8, winner_id: PlayerId) -> Self {
+        Self::new(
+            tick,
+            EventPriority::ShrineActivation,
+            GameEventData::ShrineContestLost { player_id, shrine_id, winner_id },
+        )
+    }
+
     /// Create ability used event.
     pub fn ability_used(tick: u32, player_id: PlayerId, ability_type: u8) -> Self {
         Self::new(
@@ -253,6 +571,24 @@ impl GameEvent {
             GameEventData::AbilityUsed { player_id, ability_type },
         )
     }
+
+    /// Create ability combo event.
+    pub fn ability_combo(
+        tick: u32,
+        player_id: PlayerId,
+        other_player: PlayerId,
+        combo: ComboType,
+    ) -> Self {
+        Self::new(
+            tick,
+            EventPriority::AbilityEffect,
+            GameEventData::AbilityCombo {
+                player_id,
+                other_player,
+                combo,
+            },
+        )
+    }
 }
 
 impl PartialEq for GameEvent {
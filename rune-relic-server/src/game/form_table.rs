@@ -0,0 +1,245 @@
+//! Form Ladder
+//!
+//! The evolution ladder as data: per-tier speed, radius and the score
+//! needed to reach the tier. The default table is the classic five-form
+//! ladder (Spark → Ancient); modes can install a longer one with up to
+//! `MAX_FORM_TIERS` tiers via `MatchConfig::form_table`, which is hashed
+//! into the config commitment and replayed by verification.
+//!
+//! Players climb by *tier*. Each tier also names the `Form` it presents
+//! as, which picks the ability and the client visuals, so a custom ladder
+//! may repeat forms.
+//!
+//! The table also carries `damaged_radius_scale`, which shrinks (or grows)
+//! a wounded player's collision radius; it is applied from
+//! `MatchConfig::damaged_radius_scale` when play starts.
+
+use serde::{Serialize, Deserialize};
+use crate::core::fixed::{
+    Fixed, FIXED_ONE, FORM_SPEEDS, FORM_RADII, SCORE_TO_EVOLVE, fixed_div, fixed_mul,
+};
+use crate::core::hash::StateHasher;
+use crate::game::state::Form;
+
+/// Maximum tiers in a form ladder.
+pub const MAX_FORM_TIERS: usize = 16;
+
+/// One rung of the form ladder.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormTier {
+    /// Form this tier presents as (ability and visuals)
+    pub form: Form,
+    /// Movement speed
+    pub speed: Fixed,
+    /// Collision radius
+    pub radius: Fixed,
+    /// Score needed to evolve into this tier (0 for the first tier)
+    pub entry_score: u32,
+}
+
+/// Ordered evolution ladder.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormTable {
+    tiers: Vec<FormTier>,
+    /// Radius multiplier at zero health (FIXED_ONE = radius ignores health)
+    #[serde(default = "full_scale")]
+    damaged_radius_scale: Fixed,
+}
+
+fn full_scale() -> Fixed {
+    FIXED_ONE
+}
+
+impl Default for FormTable {
+    fn default() -> Self {
+        let forms = [Form::Spark, Form::Glyph, Form::Ward, Form::Arcane, Form::Ancient];
+        let tiers = forms.iter()
+            .enumerate()
+            .map(|(i, form)| FormTier {
+                form: *form,
+                speed: FORM_SPEEDS[i],
+                radius: FORM_RADII[i],
+                entry_score: if i == 0 { 0 } else { SCORE_TO_EVOLVE[i - 1] },
+            })
+            .collect();
+        Self { tiers, damaged_radius_scale: FIXED_ONE }
+    }
+}
+
+impl FormTable {
+    /// Build a custom ladder.
+    ///
+    /// Returns None if the ladder is empty, longer than `MAX_FORM_TIERS`,
+    /// does not start at 0 points, or its entry scores are not strictly
+    /// increasing.
+    pub fn new(tiers: Vec<FormTier>) -> Option<Self> {
+        if tiers.is_empty() || tiers.len() > MAX_FORM_TIERS || tiers[0].entry_score != 0 {
+            return None;
+        }
+        if !tiers.windows(2).all(|pair| pair[0].entry_score < pair[1].entry_score) {
+            return None;
+        }
+        Some(Self { tiers, damaged_radius_scale: FIXED_ONE })
+    }
+
+    /// Set the radius multiplier for a player at zero health.
+    ///
+    /// The effective radius moves linearly from `scale` at zero health to
+    /// the tier radius at full health. Below FIXED_ONE wounded players are
+    /// harder to hit; above it they are easier.
+    pub fn set_damaged_radius_scale(&mut self, scale: Fixed) {
+        self.damaged_radius_scale = scale;
+    }
+
+    /// Effective collision radius at `tier` with `health` out of `max_health`.
+    pub fn effective_radius(&self, tier: u8, health: Fixed, max_health: Fixed) -> Fixed {
+        let radius = self.radius(tier);
+        if self.damaged_radius_scale == FIXED_ONE || max_health <= 0 {
+            return radius;
+        }
+        let health_frac = fixed_div(health.clamp(0, max_health), max_health);
+        let scale = self.damaged_radius_scale
+            + fixed_mul(FIXED_ONE - self.damaged_radius_scale, health_frac);
+        fixed_mul(radius, scale)
+    }
+
+    /// Number of tiers.
+    pub fn len(&self) -> usize {
+        self.tiers.len()
+    }
+
+    /// Always false: a ladder has at least one tier.
+    pub fn is_empty(&self) -> bool {
+        self.tiers.is_empty()
+    }
+
+    /// Tier data (out-of-range tiers read as the top tier).
+    pub fn tier(&self, tier: u8) -> &FormTier {
+        &self.tiers[(tier as usize).min(self.tiers.len() - 1)]
+    }
+
+    /// Movement speed at `tier`.
+    #[inline]
+    pub fn speed(&self, tier: u8) -> Fixed {
+        self.tier(tier).speed
+    }
+
+    /// Collision radius at `tier`.
+    #[inline]
+    pub fn radius(&self, tier: u8) -> Fixed {
+        self.tier(tier).radius
+    }
+
+    /// Form presented at `tier`.
+    #[inline]
+    pub fn form(&self, tier: u8) -> Form {
+        self.tier(tier).form
+    }
+
+    /// Next tier up (if not the top).
+    pub fn next(&self, tier: u8) -> Option<u8> {
+        let next = tier.checked_add(1)?;
+        ((next as usize) < self.tiers.len()).then_some(next)
+    }
+
+    /// Next tier down (if not the bottom).
+    pub fn prev(&self, tier: u8) -> Option<u8> {
+        tier.checked_sub(1)
+    }
+
+    /// Check if a player at `tier` can eat one at `other`.
+    #[inline]
+    pub fn can_eat(&self, tier: u8, other: u8) -> bool {
+        tier > other
+    }
+
+    /// Hash the ladder (it changes the rules, so it is part of the state).
+    pub fn hash_into(&self, hasher: &mut StateHasher) {
+        hasher.update_u8(self.tiers.len() as u8);
+        for tier in &self.tiers {
+            hasher.update_u8(tier.form as u8);
+            hasher.update_fixed(tier.speed);
+            hasher.update_fixed(tier.radius);
+            hasher.update_u32(tier.entry_score);
+        }
+        hasher.update_fixed(self.damaged_radius_scale);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixed::FIXED_ONE;
+    use crate::core::vec2::FixedVec2;
+    use crate::game::state::{MatchState, PlayerId, PlayerState};
+
+    fn seven_tiers() -> FormTable {
+        let forms = [
+            Form::Spark, Form::Spark, Form::Glyph, Form::Ward,
+            Form::Arcane, Form::Arcane, Form::Ancient,
+        ];
+        FormTable::new(forms.iter()
+            .enumerate()
+            .map(|(i, form)| FormTier {
+                form: *form,
+                speed: (7 * FIXED_ONE) - (i as Fixed * FIXED_ONE / 2),
+                radius: FIXED_ONE / 2 + i as Fixed * FIXED_ONE / 4,
+                entry_score: i as u32 * 100,
+            })
+            .collect())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_default_table_matches_classic_forms() {
+        let table = FormTable::default();
+        assert_eq!(table.len(), 5);
+        for tier in 0..5u8 {
+            let form = Form::from_index(tier).unwrap();
+            assert_eq!(table.form(tier), form);
+            assert_eq!(table.speed(tier), form.speed());
+            assert_eq!(table.radius(tier), form.radius());
+            assert_eq!(table.next(tier).map(Form::from_index), form.next().map(Some));
+        }
+    }
+
+    #[test]
+    fn test_invalid_tables_rejected() {
+        let tier = FormTable::default().tiers[0];
+        assert!(FormTable::new(vec![]).is_none());
+        assert!(FormTable::new(vec![tier; MAX_FORM_TIERS + 1]).is_none());
+        // Entry scores must strictly increase
+        assert!(FormTable::new(vec![tier, tier]).is_none());
+        assert!(FormTable::new(vec![FormTier { entry_score: 5, ..tier }]).is_none());
+    }
+
+    #[test]
+    fn test_seven_tier_ladder_evolves_through_all_tiers() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let id = PlayerId::new([1; 16]);
+        state.players.insert(id, PlayerState::new(id, FixedVec2::ZERO));
+        state.set_form_table(seven_tiers());
+
+        let table = state.form_table.clone();
+        let mut last_radius = 0;
+        for expected in 0..7u8 {
+            let player = state.players.get_mut(&id).unwrap();
+            assert_eq!(player.tier, expected);
+            assert_eq!(player.form, table.form(expected));
+            assert_eq!(player.speed(&table), table.speed(expected));
+            assert!(player.radius(&table) > last_radius);
+            last_radius = player.radius(&table);
+
+            // Reaching the next threshold evolves exactly one tier
+            let evolved = player.add_score(100, &table);
+            assert_eq!(evolved, expected < 6);
+        }
+
+        let player = &state.players[&id];
+        assert_eq!(player.tier, 6);
+        assert_eq!(player.form, Form::Ancient);
+        assert!(!player.can_evolve(&table));
+        assert!(table.can_eat(6, 5));
+        assert!(!table.can_eat(5, 5));
+    }
+}
@@ -0,0 +1,143 @@
+//! Golden Match Fixture
+//!
+//! Replays a committed 4-player match (`fixtures/golden_match.json`) and
+//! checks the outcome against `fixtures/golden_match.expected.json`. The
+//! same data must produce the same hash on every platform (x86, ARM, WASM),
+//! so any determinism regression fails here.
+//!
+//! The inputs are scripted so the match takes the paths most likely to
+//! drift: a handicapped player hunts the others with its ability, one
+//! player captures a shrine before being eaten and another walks into a
+//! wall. The outcome counts those events, and the test refuses a fixture
+//! that stops producing them.
+//!
+//! After an intentional simulation change, replace the expected file with
+//! the outcome the failing assertion prints.
+
+use std::collections::BTreeMap;
+use serde::{Serialize, Deserialize};
+use crate::core::rng::derive_match_seed;
+use crate::game::events::GameEventData;
+use crate::game::input::InputFrame;
+use crate::game::state::{MatchState, MatchPhase, PlayerId, Placement, PlayerStart};
+use crate::game::tick::{prepare_match, start_playing, MatchConfig};
+use crate::MATCH_DURATION_TICKS;
+
+const FIXTURE: &str = include_str!("../../fixtures/golden_match.json");
+const EXPECTED: &str = include_str!("../../fixtures/golden_match.expected.json");
+
+#[derive(Deserialize)]
+struct Fixture {
+    match_id: String,
+    block_hash: String,
+    /// Per player: `[tier, score]` handicap start
+    #[serde(default)]
+    starts: BTreeMap<String, (u8, u32)>,
+    /// Per player: `[tick, move_x, move_y, flags]`, held until the next entry
+    inputs: BTreeMap<String, Vec<(u32, i8, i8, u8)>>,
+}
+
+/// Outcome in the shape of `MatchEndInfo`, plus counts of the events the
+/// fixture is scripted to produce.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Outcome {
+    rng_seed: String,
+    end_tick: u32,
+    winner_id: Option<String>,
+    /// `[player_id, place, score, eliminations]` in leaderboard order
+    placements: Vec<(String, Placement, u32, u32)>,
+    /// Eliminations with a killer
+    kills: u32,
+    /// Eliminations without one (walls, zone)
+    wall_deaths: u32,
+    shrine_captures: u32,
+    abilities_used: u32,
+    final_state_hash: String,
+}
+
+fn decode<const N: usize>(s: &str) -> [u8; N] {
+    hex::decode(s).unwrap().try_into().unwrap()
+}
+
+fn replay(fixture: &Fixture) -> Outcome {
+    let match_id: [u8; 16] = decode(&fixture.match_id);
+    let block_hash: [u8; 32] = decode(&fixture.block_hash);
+    let player_ids: Vec<[u8; 16]> = fixture.inputs.keys().map(|id| decode(id)).collect();
+    let rng_seed = derive_match_seed(&block_hash, &match_id, &player_ids);
+
+    // Same setup as a live session
+    let config = MatchConfig::default();
+    let mut state = MatchState::new(match_id, rng_seed);
+    prepare_match(&mut state, &config);
+    for id in &player_ids {
+        state.add_player(PlayerId::new(*id));
+    }
+    for (id, &(tier, score)) in &fixture.starts {
+        state.apply_player_start(&PlayerId::new(decode(id)), PlayerStart { tier, score });
+    }
+    state.assign_spawn_positions();
+    start_playing(&mut state, &config);
+
+    let mut current: BTreeMap<PlayerId, InputFrame> = player_ids.iter()
+        .map(|id| (PlayerId::new(*id), InputFrame::new()))
+        .collect();
+
+    let (mut kills, mut wall_deaths, mut shrine_captures, mut abilities_used) = (0, 0, 0, 0);
+    while state.phase != MatchPhase::Ended && state.tick < MATCH_DURATION_TICKS {
+        let next_tick = state.tick + 1;
+        for (id, script) in &fixture.inputs {
+            if let Some(&(_, move_x, move_y, flags)) = script.iter().find(|entry| entry.0 == next_tick) {
+                current.insert(PlayerId::new(decode(id)), InputFrame { move_x, move_y, flags });
+            }
+        }
+        for event in state.tick_once(&current, &config).events {
+            match event.data {
+                GameEventData::PlayerEliminated { killer_id: Some(_), .. } => kills += 1,
+                GameEventData::PlayerEliminated { killer_id: None, .. } => wall_deaths += 1,
+                GameEventData::ShrineActivated { .. } => shrine_captures += 1,
+                GameEventData::AbilityUsed { .. } => abilities_used += 1,
+                _ => {}
+            }
+        }
+    }
+
+    // Placements as the session reports them
+    let placements: Vec<(String, Placement, u32, u32)> = state.leaderboard().into_iter()
+        .enumerate()
+        .map(|(i, (id, score, kills))| (hex::encode(id.as_bytes()), (i + 1) as Placement, score, kills))
+        .collect();
+    let winner_id = placements.first()
+        .filter(|p| p.2 > 0)
+        .map(|p| p.0.clone());
+
+    Outcome {
+        rng_seed: rng_seed.to_string(),
+        end_tick: state.tick,
+        winner_id,
+        placements,
+        kills,
+        wall_deaths,
+        shrine_captures,
+        abilities_used,
+        final_state_hash: hex::encode(state.compute_hash()),
+    }
+}
+
+#[test]
+fn test_golden_match_replays_exactly() {
+    let fixture: Fixture = serde_json::from_str(FIXTURE).unwrap();
+    let outcome = replay(&fixture);
+
+    // A fixture that no longer fights covers none of the risky paths
+    assert!(outcome.kills > 0, "golden fixture has no kills: {:?}", outcome);
+    assert!(outcome.wall_deaths > 0, "golden fixture has no wall deaths: {:?}", outcome);
+    assert!(outcome.shrine_captures > 0, "golden fixture has no shrine captures: {:?}", outcome);
+    assert!(outcome.abilities_used > 0, "golden fixture has no abilities: {:?}", outcome);
+
+    let expected: Outcome = serde_json::from_str(EXPECTED).unwrap();
+    assert!(
+        outcome == expected,
+        "golden match diverged; if intentional, replace fixtures/golden_match.expected.json with:\n{}",
+        serde_json::to_string_pretty(&outcome).unwrap()
+    );
+}
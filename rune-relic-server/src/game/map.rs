@@ -1,10 +1,15 @@
 //! Arcane Circuit Map Geometry
 //!
-//! Deterministic geometry helpers for hubs, corridors, and spawn alcoves.
+//! Deterministic geometry helpers for hubs, corridors, spawn alcoves, and
+//! boost pads.
 
-use crate::core::fixed::{Fixed, FIXED_ONE, fixed_mul, fixed_div, fixed_abs, to_fixed};
+use serde::{Serialize, Deserialize};
+use crate::core::fixed::{
+    Fixed, FIXED_ONE, fixed_mul, fixed_div, fixed_abs, to_fixed, ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT,
+};
 use crate::core::vec2::FixedVec2;
 use crate::core::rng::DeterministicRng;
+use crate::core::hash::StateHasher;
 use crate::game::state::RuneState;
 
 const CORRIDOR_WIDTH: Fixed = to_fixed(7.0);
@@ -34,81 +39,120 @@ pub struct SpawnZone {
     pub corridor: Corridor,
 }
 
+/// Circular pad that launches players who enter it.
+///
+/// Entering adds `impulse` (units per second) to the player's boost
+/// velocity; standing on the pad does not re-trigger it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoostPad {
+    /// Pad identifier (unique per map)
+    pub id: u8,
+    /// Pad center
+    pub center: FixedVec2,
+    /// Trigger radius (player center must be inside)
+    pub radius: Fixed,
+    /// Launch velocity granted on entry
+    pub impulse: FixedVec2,
+}
+
 #[derive(Clone, Debug)]
 pub struct ArcaneCircuitMap {
     hubs: Vec<Hub>,
     corridors: Vec<Corridor>,
     spawn_zones: Vec<SpawnZone>,
+    boost_pads: Vec<BoostPad>,
     hub_weights: Vec<u32>,
     corridor_weights: Vec<u32>,
 }
 
 impl ArcaneCircuitMap {
+    /// Stock layout for the default arena.
     pub fn new() -> Self {
+        Self::for_arena(ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT)
+    }
+
+    /// Stock layout stretched to an arena with the given half extents.
+    ///
+    /// Hub, corridor and spawn positions scale with the arena on each axis
+    /// and hub radii with the smaller axis; corridor widths and spawn
+    /// alcoves keep their size, since they are sized for players. The
+    /// default arena reproduces `new()` exactly.
+    pub fn for_arena(half_width: Fixed, half_height: Fixed) -> Self {
+        let scale_x = fixed_div(half_width, ARENA_HALF_WIDTH);
+        let scale_y = fixed_div(half_height, ARENA_HALF_HEIGHT);
+        let scale_r = scale_x.min(scale_y);
+        let at = |x: i32, y: i32| {
+            FixedVec2::new(fixed_mul(x * FIXED_ONE, scale_x), fixed_mul(y * FIXED_ONE, scale_y))
+        };
+        let hub = |x: i32, y: i32, radius: f64| Hub {
+            center: at(x, y),
+            radius: fixed_mul(to_fixed(radius), scale_r),
+        };
+
         let hubs = vec![
-            Hub { center: FixedVec2::from_ints(0, 0), radius: to_fixed(35.0) },
-            Hub { center: FixedVec2::from_ints(0, 90), radius: to_fixed(35.0) },
-            Hub { center: FixedVec2::from_ints(0, -90), radius: to_fixed(35.0) },
-            Hub { center: FixedVec2::from_ints(140, 0), radius: to_fixed(35.0) },
-            Hub { center: FixedVec2::from_ints(-140, 0), radius: to_fixed(35.0) },
-            Hub { center: FixedVec2::from_ints(70, 45), radius: to_fixed(18.0) },
-            Hub { center: FixedVec2::from_ints(-70, 45), radius: to_fixed(18.0) },
-            Hub { center: FixedVec2::from_ints(70, -45), radius: to_fixed(18.0) },
-            Hub { center: FixedVec2::from_ints(-70, -45), radius: to_fixed(18.0) },
+            hub(0, 0, 35.0),
+            hub(0, 90, 35.0),
+            hub(0, -90, 35.0),
+            hub(140, 0, 35.0),
+            hub(-140, 0, 35.0),
+            hub(70, 45, 18.0),
+            hub(-70, 45, 18.0),
+            hub(70, -45, 18.0),
+            hub(-70, -45, 18.0),
         ];
 
         let mut corridors = vec![
             // Inner spokes
-            Corridor::new(FixedVec2::from_ints(0, 0), FixedVec2::from_ints(70, 45)),
-            Corridor::new(FixedVec2::from_ints(0, 0), FixedVec2::from_ints(-70, 45)),
-            Corridor::new(FixedVec2::from_ints(0, 0), FixedVec2::from_ints(70, -45)),
-            Corridor::new(FixedVec2::from_ints(0, 0), FixedVec2::from_ints(-70, -45)),
+            Corridor::new(at(0, 0), at(70, 45)),
+            Corridor::new(at(0, 0), at(-70, 45)),
+            Corridor::new(at(0, 0), at(70, -45)),
+            Corridor::new(at(0, 0), at(-70, -45)),
 
             // Junction connectors
-            Corridor::new(FixedVec2::from_ints(70, 45), FixedVec2::from_ints(0, 90)),
-            Corridor::new(FixedVec2::from_ints(70, 45), FixedVec2::from_ints(140, 0)),
-            Corridor::new(FixedVec2::from_ints(-70, 45), FixedVec2::from_ints(0, 90)),
-            Corridor::new(FixedVec2::from_ints(-70, 45), FixedVec2::from_ints(-140, 0)),
-            Corridor::new(FixedVec2::from_ints(70, -45), FixedVec2::from_ints(0, -90)),
-            Corridor::new(FixedVec2::from_ints(70, -45), FixedVec2::from_ints(140, 0)),
-            Corridor::new(FixedVec2::from_ints(-70, -45), FixedVec2::from_ints(0, -90)),
-            Corridor::new(FixedVec2::from_ints(-70, -45), FixedVec2::from_ints(-140, 0)),
+            Corridor::new(at(70, 45), at(0, 90)),
+            Corridor::new(at(70, 45), at(140, 0)),
+            Corridor::new(at(-70, 45), at(0, 90)),
+            Corridor::new(at(-70, 45), at(-140, 0)),
+            Corridor::new(at(70, -45), at(0, -90)),
+            Corridor::new(at(70, -45), at(140, 0)),
+            Corridor::new(at(-70, -45), at(0, -90)),
+            Corridor::new(at(-70, -45), at(-140, 0)),
 
             // Outer ring (3-segment polyline per side)
-            Corridor::new(FixedVec2::from_ints(0, 90), FixedVec2::from_ints(0, 140)),
-            Corridor::new(FixedVec2::from_ints(0, 140), FixedVec2::from_ints(140, 140)),
-            Corridor::new(FixedVec2::from_ints(140, 140), FixedVec2::from_ints(140, 0)),
+            Corridor::new(at(0, 90), at(0, 140)),
+            Corridor::new(at(0, 140), at(140, 140)),
+            Corridor::new(at(140, 140), at(140, 0)),
 
-            Corridor::new(FixedVec2::from_ints(140, 0), FixedVec2::from_ints(140, -140)),
-            Corridor::new(FixedVec2::from_ints(140, -140), FixedVec2::from_ints(0, -140)),
-            Corridor::new(FixedVec2::from_ints(0, -140), FixedVec2::from_ints(0, -90)),
+            Corridor::new(at(140, 0), at(140, -140)),
+            Corridor::new(at(140, -140), at(0, -140)),
+            Corridor::new(at(0, -140), at(0, -90)),
 
-            Corridor::new(FixedVec2::from_ints(0, -90), FixedVec2::from_ints(0, -140)),
-            Corridor::new(FixedVec2::from_ints(0, -140), FixedVec2::from_ints(-140, -140)),
-            Corridor::new(FixedVec2::from_ints(-140, -140), FixedVec2::from_ints(-140, 0)),
+            Corridor::new(at(0, -90), at(0, -140)),
+            Corridor::new(at(0, -140), at(-140, -140)),
+            Corridor::new(at(-140, -140), at(-140, 0)),
 
-            Corridor::new(FixedVec2::from_ints(-140, 0), FixedVec2::from_ints(-140, 140)),
-            Corridor::new(FixedVec2::from_ints(-140, 140), FixedVec2::from_ints(0, 140)),
-            Corridor::new(FixedVec2::from_ints(0, 140), FixedVec2::from_ints(0, 90)),
+            Corridor::new(at(-140, 0), at(-140, 140)),
+            Corridor::new(at(-140, 140), at(0, 140)),
+            Corridor::new(at(0, 140), at(0, 90)),
         ];
 
         let spawn_anchors = [
-            FixedVec2::from_ints(-20, 115),
-            FixedVec2::from_ints(20, 115),
-            FixedVec2::from_ints(-20, -115),
-            FixedVec2::from_ints(20, -115),
-            FixedVec2::from_ints(165, 20),
-            FixedVec2::from_ints(165, -20),
-            FixedVec2::from_ints(-165, 20),
-            FixedVec2::from_ints(-165, -20),
-            FixedVec2::from_ints(110, 80),
-            FixedVec2::from_ints(120, 70),
-            FixedVec2::from_ints(-110, 80),
-            FixedVec2::from_ints(-120, 70),
-            FixedVec2::from_ints(110, -80),
-            FixedVec2::from_ints(120, -70),
-            FixedVec2::from_ints(-110, -80),
-            FixedVec2::from_ints(-120, -70),
+            at(-20, 115),
+            at(20, 115),
+            at(-20, -115),
+            at(20, -115),
+            at(165, 20),
+            at(165, -20),
+            at(-165, 20),
+            at(-165, -20),
+            at(110, 80),
+            at(120, 70),
+            at(-110, 80),
+            at(-120, 70),
+            at(110, -80),
+            at(120, -70),
+            at(-110, -80),
+            at(-120, -70),
         ];
 
         let mut spawn_zones = Vec::new();
@@ -141,11 +185,18 @@ impl ArcaneCircuitMap {
             hubs,
             corridors,
             spawn_zones,
+            boost_pads: Vec::new(),
             hub_weights,
             corridor_weights,
         }
     }
 
+    /// Place boost pads on the map (the stock layout has none).
+    pub fn with_boost_pads(mut self, boost_pads: Vec<BoostPad>) -> Self {
+        self.boost_pads = boost_pads;
+        self
+    }
+
     pub fn hubs(&self) -> &[Hub] {
         &self.hubs
     }
@@ -158,6 +209,51 @@ impl ArcaneCircuitMap {
         &self.spawn_zones
     }
 
+    /// Half extents of the smallest origin-centred box around the map.
+    ///
+    /// Covers every hub, corridor and spawn alcove out to their walls; this
+    /// is the walkable area clients draw their boundary around.
+    pub fn half_extents(&self) -> (Fixed, Fixed) {
+        let mut extent = (0, 0);
+        let mut cover = |center: FixedVec2, reach: Fixed| {
+            extent.0 = extent.0.max(fixed_abs(center.x).saturating_add(reach));
+            extent.1 = extent.1.max(fixed_abs(center.y).saturating_add(reach));
+        };
+        for hub in &self.hubs {
+            cover(hub.center, hub.radius);
+        }
+        let zone_corridors = self.spawn_zones.iter().map(|zone| &zone.corridor);
+        for corridor in self.corridors.iter().chain(zone_corridors) {
+            cover(corridor.start, corridor.half_width);
+            cover(corridor.end, corridor.half_width);
+        }
+        for zone in &self.spawn_zones {
+            cover(zone.center, zone.radius);
+        }
+        extent
+    }
+
+    /// Boost pads in map order.
+    pub fn boost_pads(&self) -> &[BoostPad] {
+        &self.boost_pads
+    }
+
+    /// First boost pad (in map order) under `position`.
+    pub fn boost_pad_at(&self, position: FixedVec2) -> Option<&BoostPad> {
+        self.boost_pads.iter().find(|pad| pad.contains(position))
+    }
+
+    /// Hash the boost pad layout (pads change movement, unlike static walls).
+    pub fn hash_boost_pads(&self, hasher: &mut StateHasher) {
+        hasher.update_u8(self.boost_pads.len() as u8);
+        for pad in &self.boost_pads {
+            hasher.update_u8(pad.id);
+            hasher.update_vec2(pad.center);
+            hasher.update_fixed(pad.radius);
+            hasher.update_vec2(pad.impulse);
+        }
+    }
+
     pub fn spawn_zone(&self, id: u8) -> Option<&SpawnZone> {
         self.spawn_zones.iter().find(|zone| zone.id == id)
     }
@@ -308,6 +404,13 @@ impl Corridor {
     }
 }
 
+impl BoostPad {
+    fn contains(&self, position: FixedVec2) -> bool {
+        let radius_sq = (self.radius as i64 * self.radius as i64) >> 16;
+        position.distance_squared_wide(self.center) <= radius_sq
+    }
+}
+
 impl SpawnZone {
     fn contains(&self, position: FixedVec2, radius: Fixed) -> bool {
         if self.radius > radius {
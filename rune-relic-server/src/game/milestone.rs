@@ -0,0 +1,216 @@
+//! Match Milestones
+//!
+//! One-off bonuses for the first kill ("first blood"), the first evolution
+//! and the first shrine capture of a match. Milestones are detected from
+//! the events emitted earlier in the tick, in emission order, so the award
+//! is deterministic. Claimed flags live in `MatchState` and are hashed.
+
+use crate::game::state::{MatchState, PlayerId};
+use crate::game::events::{GameEvent, GameEventData};
+
+/// Score bonus for the first player to eliminate another.
+pub const FIRST_BLOOD_BONUS: u32 = 50;
+
+/// Score bonus for the first player to evolve.
+pub const FIRST_EVOLUTION_BONUS: u32 = 25;
+
+/// Score bonus for the first player to capture a shrine.
+pub const FIRST_SHRINE_BONUS: u32 = 25;
+
+/// Award any milestones reached this tick.
+///
+/// Must run after every step that can eliminate, evolve or capture.
+pub fn process_milestones(state: &mut MatchState) {
+    let mut first_blood: Option<(PlayerId, PlayerId)> = None;
+    let mut first_evolution: Option<PlayerId> = None;
+    let mut first_shrine: Option<(PlayerId, u8)> = None;
+
+    for event in &state.pending_events {
+        match &event.data {
+            GameEventData::PlayerEliminated { victim_id, killer_id: Some(killer_id), .. }
+                if !state.first_blood_claimed && first_blood.is_none() =>
+            {
+                first_blood = Some((*killer_id, *victim_id));
+            }
+            GameEventData::FormEvolved { player_id, .. }
+                if !state.first_evolution_claimed && first_evolution.is_none() =>
+            {
+                first_evolution = Some(*player_id);
+            }
+            GameEventData::ShrineActivated { player_id, shrine_id }
+                if !state.first_shrine_claimed && first_shrine.is_none() =>
+            {
+                first_shrine = Some((*player_id, *shrine_id));
+            }
+            _ => {}
+        }
+    }
+
+    // A bonus can itself evolve a player, which counts for first evolution
+    let mut bonus_evolution: Option<PlayerId> = None;
+
+    if let Some((killer_id, victim_id)) = first_blood {
+        state.first_blood_claimed = true;
+        if award(state, &killer_id, FIRST_BLOOD_BONUS) {
+            bonus_evolution = Some(killer_id);
+        }
+        state.push_event(GameEvent::first_blood(state.tick, killer_id, victim_id, FIRST_BLOOD_BONUS));
+    }
+
+    if let Some(player_id) = first_evolution.or(bonus_evolution) {
+        claim_first_evolution(state, player_id);
+    }
+
+    if let Some((player_id, shrine_id)) = first_shrine {
+        state.first_shrine_claimed = true;
+        let evolved = award(state, &player_id, FIRST_SHRINE_BONUS);
+        state.push_event(GameEvent::first_shrine_capture(
+            state.tick,
+            player_id,
+            shrine_id,
+            FIRST_SHRINE_BONUS,
+        ));
+        if evolved && !state.first_evolution_claimed {
+            claim_first_evolution(state, player_id);
+        }
+    }
+}
+
+/// Award the first-evolution milestone to `player_id`.
+fn claim_first_evolution(state: &mut MatchState, player_id: PlayerId) {
+    state.first_evolution_claimed = true;
+    award(state, &player_id, FIRST_EVOLUTION_BONUS);
+    state.push_event(GameEvent::first_evolution(state.tick, player_id, FIRST_EVOLUTION_BONUS));
+}
+
+/// Add a milestone bonus to a player's score.
+///
+/// Emits `FormEvolved` if the bonus evolves the player, and returns
+/// whether it did.
+fn award(state: &mut MatchState, player_id: &PlayerId, bonus: u32) -> bool {
+    let Some(player) = state.players.get_mut(player_id) else {
+        return false;
+    };
+
+    let old_form = player.form;
+    let evolved = player.add_score(bonus, &state.form_table);
+    let new_form = player.form;
+
+    if evolved {
+        state.push_event(GameEvent::form_evolved(state.tick, *player_id, old_form, new_form));
+    }
+    evolved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::vec2::FixedVec2;
+    use crate::game::state::{MatchPhase, PlayerState};
+
+    fn milestone_events(events: &[GameEvent]) -> usize {
+        events.iter()
+            .filter(|e| matches!(
+                e.data,
+                GameEventData::FirstBlood { .. }
+                    | GameEventData::FirstEvolution { .. }
+                    | GameEventData::FirstShrineCapture { .. }
+            ))
+            .count()
+    }
+
+    fn eliminate(state: &mut MatchState, victim: PlayerId, killer: PlayerId) {
+        let placement = state.eliminate_player(&victim, Some(&killer)).unwrap();
+        state.push_event(GameEvent::player_eliminated(state.tick, victim, Some(killer), placement));
+    }
+
+    #[test]
+    fn test_first_blood_awarded_once() {
+        let mut state = MatchState::new([0; 16], 12345);
+        state.phase = MatchPhase::Playing;
+        let ids: Vec<PlayerId> = (1..=3).map(|i| PlayerId::new([i; 16])).collect();
+        for id in &ids {
+            state.players.insert(*id, PlayerState::new(*id, FixedVec2::ZERO));
+        }
+        state.alive_count = 3;
+        let hunter = ids[0];
+
+        state.tick = 10;
+        eliminate(&mut state, ids[1], hunter);
+        process_milestones(&mut state);
+        let events = state.take_events();
+        assert!(state.first_blood_claimed);
+        assert!(events.iter().any(|e| matches!(
+            e.data,
+            GameEventData::FirstBlood { player_id, victim_id, bonus }
+                if player_id == hunter && victim_id == ids[1] && bonus == FIRST_BLOOD_BONUS
+        )));
+        let after_first = state.players[&hunter].score;
+
+        // Second elimination: regular kill credit only, no milestone
+        state.tick = 20;
+        eliminate(&mut state, ids[2], hunter);
+        process_milestones(&mut state);
+        let events = state.take_events();
+        assert_eq!(milestone_events(&events), 0);
+        assert_eq!(
+            state.players[&hunter].score - after_first,
+            crate::core::fixed::SCORE_PER_KILL,
+        );
+        assert_eq!(
+            state.players[&hunter].score,
+            2 * crate::core::fixed::SCORE_PER_KILL + FIRST_BLOOD_BONUS,
+        );
+    }
+
+    #[test]
+    fn test_shrine_and_evolution_milestones_fire_once() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let a = PlayerId::new([1; 16]);
+        let b = PlayerId::new([2; 16]);
+        state.players.insert(a, PlayerState::new(a, FixedVec2::ZERO));
+        state.players.insert(b, PlayerState::new(b, FixedVec2::ZERO));
+
+        // Both capture on the same tick: first emitted wins
+        state.push_event(GameEvent::shrine_activated(5, b, 0));
+        state.push_event(GameEvent::shrine_activated(5, a, 1));
+        process_milestones(&mut state);
+        assert_eq!(milestone_events(&state.take_events()), 1);
+        assert_eq!(state.players[&b].score, FIRST_SHRINE_BONUS);
+        assert_eq!(state.players[&a].score, 0);
+
+        state.push_event(GameEvent::shrine_activated(6, a, 1));
+        process_milestones(&mut state);
+        assert_eq!(milestone_events(&state.take_events()), 0);
+        assert!(!state.first_evolution_claimed);
+    }
+
+    #[test]
+    fn test_bonus_evolution_emits_event_and_milestone() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let a = PlayerId::new([1; 16]);
+        state.players.insert(a, PlayerState::new(a, FixedVec2::ZERO));
+
+        // Just short of the first evolution threshold
+        let threshold = state.form_table.tier(1).entry_score;
+        state.players.get_mut(&a).unwrap().score = threshold - 1;
+
+        state.push_event(GameEvent::shrine_activated(5, a, 0));
+        process_milestones(&mut state);
+        let events = state.take_events();
+
+        assert!(events.iter().any(|e| matches!(
+            e.data,
+            GameEventData::FormEvolved { player_id, .. } if player_id == a
+        )));
+        assert!(events.iter().any(|e| matches!(
+            e.data,
+            GameEventData::FirstEvolution { player_id, .. } if player_id == a
+        )));
+        assert!(state.first_evolution_claimed);
+        assert_eq!(
+            state.players[&a].score,
+            threshold - 1 + FIRST_SHRINE_BONUS + FIRST_EVOLUTION_BONUS,
+        );
+    }
+}
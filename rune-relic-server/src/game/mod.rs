@@ -6,22 +6,35 @@
 //!
 //! - `input`: Input capture, normalization, buffering
 //! - `state`: Match state, player state, entities
+//! - `form_table`: Configurable evolution ladder
 //! - `tick`: Authoritative simulation loop
 //! - `collision`: Collision detection and resolution
 //! - `rune`: Rune spawning and collection
 //! - `shrine`: Shrine activation mechanics
+//! - `revive`: Team-mode downed state and revives
+//! - `respawn`: Delayed respawns with escalating death penalties
+//! - `survivor`: Survivor-mode enemy waves (PvE)
+//! - `milestone`: First blood / first evolution / first shrine bonuses
 //! - `events`: Game events for replay/verification
 
 pub mod input;
 pub mod state;
+pub mod form_table;
 pub mod tick;
 pub mod collision;
 pub mod map;
 pub mod rune;
 pub mod shrine;
 pub mod ability;
+pub mod revive;
+pub mod respawn;
+pub mod milestone;
+pub mod survivor;
 pub mod events;
 
+#[cfg(test)]
+mod golden;
+
 // Re-export key types
 pub use input::{InputFrame, InputDelta, PlayerInputBuffer, MOVE_LUT};
 pub use state::{MatchState, PlayerState, PlayerId, Form, MatchPhase};
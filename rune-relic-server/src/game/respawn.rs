@@ -0,0 +1,246 @@
+//! Respawn Mechanics
+//!
+//! With respawns enabled an eliminated player is out of play only until
+//! their respawn tick, then returns at a random spawn zone. Every death
+//! makes the next one costlier: the delay grows by `delay_step_ticks` per
+//! earlier death (capped at `max_delay_ticks`) and each respawn docks
+//! `score_penalty` points per death so far, so suicide-rushing back into a
+//! fight does not pay.
+//!
+//! Eliminations from any source are turned into pending respawns at the end
+//! of the tick, so a player awaiting respawn holds no placement: their
+//! `PlayerEliminated` event is rewritten to placement 0 before it leaves the
+//! tick. Docking score on respawn also drops the player back down the form
+//! ladder to the tier that score allows.
+
+use serde::{Serialize, Deserialize};
+use crate::core::rng::DeterministicRng;
+use crate::game::state::{MatchState, PlayerId};
+use crate::game::events::{GameEvent, GameEventData};
+
+/// RNG substream for respawn positions, kept off the main stream.
+const RESPAWN_STREAM: u64 = 0x52455350_41574E00;
+
+/// Configuration for respawns.
+#[derive(Serialize, Deserialize)]
+pub struct RespawnConfig {
+    /// Enable respawns (default off)
+    pub enabled: bool,
+    /// Delay before the first respawn
+    pub base_delay_ticks: u32,
+    /// Extra delay for each earlier death
+    pub delay_step_ticks: u32,
+    /// Longest respawn delay
+    pub max_delay_ticks: u32,
+    /// Score docked per death on each respawn
+    pub score_penalty: u32,
+    /// Invulnerability granted on respawn
+    pub invulnerable_ticks: u32,
+}
+
+impl Default for RespawnConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            base_delay_ticks: 180,   // 3 seconds at 60 Hz
+            delay_step_ticks: 120,   // +2 seconds per earlier death
+            max_delay_ticks: 900,    // 15 seconds
+            score_penalty: 0,
+            invulnerable_ticks: 120, // 2 seconds
+        }
+    }
+}
+
+impl RespawnConfig {
+    /// Respawn delay after a player's `deaths`-th death (1-based).
+    pub fn delay_ticks(&self, deaths: u32) -> u32 {
+        let step = self.delay_step_ticks.saturating_mul(deaths.saturating_sub(1));
+        self.base_delay_ticks.saturating_add(step).min(self.max_delay_ticks)
+    }
+}
+
+/// Number of players out of play awaiting a respawn.
+pub fn pending_respawns(state: &MatchState) -> u32 {
+    state.players.values().filter(|p| p.respawn_tick.is_some()).count() as u32
+}
+
+/// Schedule this tick's eliminations for respawn and bring back due players.
+pub fn process_respawns(state: &mut MatchState, config: &RespawnConfig) {
+    if !config.enabled {
+        return;
+    }
+
+    let tick = state.tick;
+    let mut scheduled: Vec<(PlayerId, u32)> = Vec::new();
+    let mut due: Vec<(usize, PlayerId, u32)> = Vec::new();
+
+    for (ordinal, player) in state.players.values().enumerate() {
+        match player.respawn_tick {
+            Some(respawn_tick) if respawn_tick <= tick => {
+                due.push((ordinal, player.id, player.deaths));
+            }
+            Some(_) => {}
+            None if !player.alive && player.eliminated_tick == Some(tick) => {
+                let delay = config.delay_ticks(player.deaths + 1);
+                scheduled.push((player.id, tick + delay));
+            }
+            None => {}
+        }
+    }
+
+    for (player_id, respawn_tick) in scheduled {
+        if state.schedule_respawn(&player_id, respawn_tick).is_some() {
+            unrank_elimination(state, &player_id);
+        }
+    }
+
+    for (ordinal, player_id, deaths) in due {
+        let index = ((tick as u64) << 16) | ordinal as u64;
+        let mut rng = DeterministicRng::substream(state.rng_seed, RESPAWN_STREAM, index);
+        let position = state.map.random_point_in_spawn_zone(&mut rng);
+        let penalty = config.score_penalty.saturating_mul(deaths);
+        let old_form = state.players[&player_id].form;
+        state.respawn_player(&player_id, position, config.invulnerable_ticks, penalty);
+        state.push_event(GameEvent::player_respawned(tick, player_id, position, deaths));
+        let new_form = state.players[&player_id].form;
+        if new_form != old_form {
+            state.push_event(GameEvent::form_devolved(tick, player_id, old_form, new_form));
+        }
+    }
+}
+
+/// Clear the placement broadcast with this tick's elimination of `player_id`.
+fn unrank_elimination(state: &mut MatchState, player_id: &PlayerId) {
+    let tick = state.tick;
+    for event in state.pending_events.iter_mut().filter(|e| e.tick == tick) {
+        if let GameEventData::PlayerEliminated { victim_id, placement, .. } = &mut event.data {
+            if victim_id == player_id {
+                *placement = 0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::vec2::FixedVec2;
+    use crate::game::events::GameEventData;
+    use crate::game::state::{MatchPhase, PlayerState};
+
+    fn duel() -> (MatchState, PlayerId, PlayerId) {
+        let mut state = MatchState::new([0; 16], 12345);
+        state.phase = MatchPhase::Playing;
+        let rusher = PlayerId::new([1; 16]);
+        let camper = PlayerId::new([2; 16]);
+        for id in [rusher, camper] {
+            state.players.insert(id, PlayerState::new(id, FixedVec2::ZERO));
+        }
+        state.alive_count = 2;
+        (state, rusher, camper)
+    }
+
+    /// Eliminate `victim` and return the ticks until they are back in play.
+    fn die_and_wait(state: &mut MatchState, victim: PlayerId, config: &RespawnConfig) -> u32 {
+        state.tick += 1;
+        let placement = state.eliminate_player(&victim, None).unwrap();
+        state.push_event(GameEvent::player_eliminated(state.tick, victim, None, placement));
+        process_respawns(state, config);
+        assert!(state.players[&victim].placement.is_none());
+        let broadcast = state.pending_events.iter().rev().find_map(|e| match e.data {
+            GameEventData::PlayerEliminated { victim_id, placement, .. } if victim_id == victim => Some(placement),
+            _ => None,
+        });
+        assert_eq!(broadcast, Some(0), "no placement for a pending respawn");
+
+        let died_at = state.tick;
+        while !state.players[&victim].alive {
+            state.tick += 1;
+            process_respawns(state, config);
+        }
+        state.tick - died_at
+    }
+
+    #[test]
+    fn test_repeated_deaths_respawn_slower() {
+        let config = RespawnConfig {
+            enabled: true,
+            score_penalty: 10,
+            ..Default::default()
+        };
+        let (mut state, rusher, _camper) = duel();
+        state.players.get_mut(&rusher).unwrap().score = 100;
+
+        let waits: Vec<u32> = (0..3)
+            .map(|_| die_and_wait(&mut state, rusher, &config))
+            .collect();
+        assert_eq!(waits, vec![180, 300, 420]);
+        assert!(waits[2] > waits[0]);
+
+        let player = &state.players[&rusher];
+        assert_eq!(player.deaths, 3);
+        assert!(player.respawn_tick.is_none());
+        // Docked 10, then 20, then 30
+        assert_eq!(player.score, 40);
+        assert_eq!(state.alive_count, 2);
+        assert_eq!(state.next_placement, 0);
+        let respawns: Vec<u32> = state.take_events().iter().filter_map(|e| match e.data {
+            GameEventData::PlayerRespawned { deaths, .. } => Some(deaths),
+            _ => None,
+        }).collect();
+        assert_eq!(respawns, vec![1, 2, 3]);
+
+        // Same deaths replay to the same counters and positions
+        let (mut replay, rusher2, _) = duel();
+        replay.players.get_mut(&rusher2).unwrap().score = 100;
+        for _ in 0..3 {
+            die_and_wait(&mut replay, rusher2, &config);
+        }
+        assert_eq!(replay.compute_hash(), state.compute_hash());
+    }
+
+    #[test]
+    fn test_respawn_penalty_drops_tier_with_score() {
+        let config = RespawnConfig {
+            enabled: true,
+            score_penalty: 50,
+            ..Default::default()
+        };
+        let (mut state, rusher, _camper) = duel();
+        let entry = state.form_table.tier(1).entry_score;
+        let player = state.players.get_mut(&rusher).unwrap();
+        player.score = entry + 10;
+        assert!(player.try_evolve(&state.form_table));
+
+        die_and_wait(&mut state, rusher, &config);
+
+        let player = &state.players[&rusher];
+        assert_eq!(player.score, entry - 40);
+        assert_eq!(player.tier, 0);
+        assert_eq!(player.form, state.form_table.form(0));
+        assert!(state.take_events().iter().any(|e| matches!(
+            e.data,
+            GameEventData::FormDevolved { player_id, .. } if player_id == rusher
+        )));
+    }
+
+    #[test]
+    fn test_delay_is_capped() {
+        let config = RespawnConfig::default();
+        assert_eq!(config.delay_ticks(1), config.base_delay_ticks);
+        assert_eq!(config.delay_ticks(100), config.max_delay_ticks);
+    }
+
+    #[test]
+    fn test_disabled_elimination_is_permanent() {
+        let (mut state, rusher, _camper) = duel();
+        state.tick += 1;
+        state.eliminate_player(&rusher, None);
+        process_respawns(&mut state, &RespawnConfig::default());
+
+        let player = &state.players[&rusher];
+        assert_eq!(player.deaths, 0);
+        assert!(player.respawn_tick.is_none());
+        assert_eq!(player.placement, Some(2));
+    }
+}
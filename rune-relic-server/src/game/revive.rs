@@ -0,0 +1,204 @@
+//! Team Revive Mechanics
+//!
+//! In team matches a player beaten in a collision is *downed* rather than
+//! eliminated while a teammate is still alive. A downed player is out of
+//! play (`alive = false`, no placement yet) and stays at their death
+//! position. An alive teammate standing within `radius` for
+//! `channel_ticks` consecutive ticks revives them; if `window_ticks` pass
+//! first, or no teammate is left alive, the elimination becomes permanent.
+
+use serde::{Serialize, Deserialize};
+use crate::core::fixed::Fixed;
+use crate::game::state::{MatchState, PlayerId};
+use crate::game::events::GameEvent;
+
+/// Configuration for team revives.
+#[derive(Serialize, Deserialize)]
+pub struct ReviveConfig {
+    /// Enable downed state and revives (default off)
+    pub enabled: bool,
+    /// Ticks a downed player can wait for a revive
+    pub window_ticks: u32,
+    /// Consecutive ticks a teammate must channel nearby
+    pub channel_ticks: u32,
+    /// Maximum distance from the downed player while channeling
+    pub radius: Fixed,
+    /// Invulnerability granted on revive
+    pub invulnerable_ticks: u32,
+}
+
+impl Default for ReviveConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_ticks: 600,       // 10 seconds at 60 Hz
+            channel_ticks: 180,      // 3 seconds
+            radius: 196608,          // 3.0 * 65536
+            invulnerable_ticks: 60,  // 1 second
+        }
+    }
+}
+
+/// Returns true if `player_id` should be downed instead of eliminated.
+pub fn can_be_downed(state: &MatchState, player_id: &PlayerId, config: &ReviveConfig) -> bool {
+    if !config.enabled {
+        return false;
+    }
+    let Some(team) = state.players.get(player_id).and_then(|p| p.team) else {
+        return false;
+    };
+    state.players.values()
+        .any(|p| p.id != *player_id && p.alive && p.team == Some(team))
+}
+
+/// Advance revive channels and expire downed players.
+pub fn process_revives(state: &mut MatchState, config: &ReviveConfig) {
+    if !config.enabled {
+        return;
+    }
+
+    let radius_sq = (config.radius as i64 * config.radius as i64) >> 16;
+
+    // Outcome per downed player, decided in sorted ID order
+    let mut revived: Vec<(PlayerId, PlayerId)> = Vec::new();
+    let mut expired: Vec<PlayerId> = Vec::new();
+    let mut progress: Vec<(PlayerId, u32)> = Vec::new();
+
+    for downed in state.players.values() {
+        let Some(downed_tick) = downed.downed_tick else {
+            continue;
+        };
+
+        let teammates_alive = state.players.values()
+            .any(|p| p.alive && p.team == downed.team);
+        if !teammates_alive || state.tick.saturating_sub(downed_tick) >= config.window_ticks {
+            expired.push(downed.id);
+            continue;
+        }
+
+        // Lowest-ID teammate in range does the channeling
+        let reviver = state.players.values()
+            .filter(|p| p.alive && p.team == downed.team)
+            .find(|p| p.position.distance_squared_wide(downed.position) <= radius_sq)
+            .map(|p| p.id);
+
+        match reviver {
+            Some(reviver) => {
+                let channeled = downed.revive_progress + 1;
+                if channeled >= config.channel_ticks {
+                    revived.push((downed.id, reviver));
+                } else {
+                    progress.push((downed.id, channeled));
+                }
+            }
+            // Channel breaks when nobody is in range
+            None => progress.push((downed.id, 0)),
+        }
+    }
+
+    for (player_id, channeled) in progress {
+        if let Some(player) = state.players.get_mut(&player_id) {
+            player.revive_progress = channeled;
+        }
+    }
+
+    for (player_id, reviver) in revived {
+        state.revive_player(&player_id, config.invulnerable_ticks);
+        state.push_event(GameEvent::player_revived(state.tick, player_id, reviver));
+    }
+
+    for player_id in expired {
+        let killer = state.players.get(&player_id).and_then(|p| p.eliminated_by);
+        if let Some(placement) = state.confirm_downed_elimination(&player_id) {
+            state.push_event(GameEvent::player_eliminated(state.tick, player_id, killer, placement));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixed::FIXED_ONE;
+    use crate::core::vec2::FixedVec2;
+    use crate::game::events::GameEventData;
+    use crate::game::state::{MatchPhase, PlayerState};
+
+    fn team_match() -> (MatchState, PlayerId, PlayerId, PlayerId) {
+        let mut state = MatchState::new([0; 16], 12345);
+        state.phase = MatchPhase::Playing;
+        let downed = PlayerId::new([1; 16]);
+        let medic = PlayerId::new([2; 16]);
+        let enemy = PlayerId::new([3; 16]);
+        for (id, team, x) in [(downed, 0, 0), (medic, 0, 1), (enemy, 1, 50)] {
+            let mut player = PlayerState::new(id, FixedVec2::new(x * FIXED_ONE, 0));
+            player.team = Some(team);
+            state.players.insert(id, player);
+        }
+        state.alive_count = 3;
+        (state, downed, medic, enemy)
+    }
+
+    fn enabled() -> ReviveConfig {
+        ReviveConfig { enabled: true, ..Default::default() }
+    }
+
+    #[test]
+    fn test_channel_revives_downed_teammate() {
+        let config = enabled();
+        let (mut state, downed, _medic, enemy) = team_match();
+
+        assert!(can_be_downed(&state, &downed, &config));
+        state.down_player(&downed, Some(&enemy));
+        assert_eq!(state.alive_count, 2);
+        assert!(state.players[&downed].placement.is_none());
+
+        for _ in 0..config.channel_ticks {
+            state.tick += 1;
+            process_revives(&mut state, &config);
+        }
+
+        let player = &state.players[&downed];
+        assert!(player.alive);
+        assert!(player.downed_tick.is_none());
+        assert_eq!(player.invulnerable_ticks, config.invulnerable_ticks);
+        assert_eq!(state.alive_count, 3);
+        assert_eq!(state.players[&enemy].kills, 0);
+        assert!(state.take_events().iter().any(|e| matches!(
+            e.data,
+            GameEventData::PlayerRevived { player_id, .. } if player_id == downed
+        )));
+    }
+
+    #[test]
+    fn test_window_expiry_is_permanent_elimination() {
+        let config = enabled();
+        let (mut state, downed, medic, enemy) = team_match();
+        // Medic is too far away to channel
+        state.players.get_mut(&medic).unwrap().position = FixedVec2::new(-20 * FIXED_ONE, 0);
+
+        state.down_player(&downed, Some(&enemy));
+        for _ in 0..config.window_ticks {
+            state.tick += 1;
+            process_revives(&mut state, &config);
+        }
+
+        let player = &state.players[&downed];
+        assert!(!player.alive);
+        assert!(player.downed_tick.is_none());
+        assert_eq!(player.placement, Some(3));
+        assert_eq!(state.alive_count, 2);
+        assert_eq!(state.players[&enemy].kills, 1);
+        assert!(state.take_events().iter().any(|e| matches!(
+            e.data,
+            GameEventData::PlayerEliminated { victim_id, killer_id: Some(k), .. }
+                if victim_id == downed && k == enemy
+        )));
+    }
+
+    #[test]
+    fn test_no_downed_state_without_teammate_or_config() {
+        let (state, _downed, _medic, enemy) = team_match();
+        assert!(!can_be_downed(&state, &enemy, &enabled()));
+        assert!(!can_be_downed(&state, &enemy, &ReviveConfig::default()));
+    }
+}
@@ -2,10 +2,21 @@
 //!
 //! Deterministic rune spawning based on RNG.
 
-use crate::game::state::{MatchState, RuneType, PlayerId};
+use serde::{Serialize, Deserialize};
+use crate::core::fixed::{Fixed, FIXED_ONE};
+use crate::core::rng::DeterministicRng;
+use crate::core::vec2::FixedVec2;
+use crate::game::state::{MatchState, RuneType, RuneState, PlayerId, Form};
 use crate::game::events::GameEvent;
 
+/// RNG sub-stream identifier for rune chains.
+const RUNE_CHAIN_STREAM: u64 = 0x52554E45_43484149; // "RUNECHAI"
+
+/// Placement attempts before a chain rune falls back to the collected spot.
+const RUNE_CHAIN_ATTEMPTS: u32 = 4;
+
 /// Configuration for rune spawning.
+#[derive(Serialize, Deserialize)]
 pub struct RuneSpawnConfig {
     /// Initial runes spawned when match begins
     pub initial_spawn_count: u32,
@@ -21,6 +32,41 @@ pub struct RuneSpawnConfig {
     pub weight_corridors: u32,
     /// Spawn weighting for spawn zones
     pub weight_spawns: u32,
+    /// Chance a collection spawns a bonus rune nearby (0 = disabled, FIXED_ONE = always)
+    pub chain_chance: Fixed,
+    /// Maximum distance of a chained rune from the collected one
+    pub chain_radius: Fixed,
+    /// Ticks over which an uncollected rune's value decays (0 = no decay)
+    pub value_decay_ticks: u32,
+    /// Fraction of the value left once fully decayed (FIXED_ONE = no decay)
+    pub value_decay_floor: Fixed,
+    /// Fixed rune layout replacing RNG spawns (balance testing); part of the config
+    pub seeded_schedule: Option<Vec<ScheduledRune>>,
+    /// Max ticks between collections that keep a combo going (0 = combos disabled)
+    pub combo_window_ticks: u32,
+    /// Bonus points per combo step beyond the first rune
+    pub combo_bonus_step: u32,
+    /// Combo step at which the bonus stops growing
+    pub combo_max_step: u32,
+    /// Emit a combo event every this many chained runes (0 = never)
+    pub combo_milestone_interval: u32,
+    /// Match tick (counted from the start) of the scripted rune rain (0 = no rain)
+    pub rain_tick: u32,
+    /// Runes dropped by the rain (still capped by `max_runes`)
+    pub rain_count: u32,
+    /// Rune value multiplier per collector form, Spark to Ancient (all FIXED_ONE = off)
+    pub form_value_scale: [Fixed; 5],
+}
+
+/// One entry of a seeded rune schedule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledRune {
+    /// Tick (counted from the match start) the rune appears on
+    pub tick: u32,
+    /// Rune type
+    pub rune_type: RuneType,
+    /// Spawn position
+    pub position: FixedVec2,
 }
 
 impl Default for RuneSpawnConfig {
@@ -33,6 +79,18 @@ impl Default for RuneSpawnConfig {
             weight_hubs: 60,
             weight_corridors: 30,
             weight_spawns: 10,
+            chain_chance: 0,
+            chain_radius: 196608, // 3.0 * 65536
+            value_decay_ticks: 0,
+            value_decay_floor: 16384, // 0.25 * 65536
+            seeded_schedule: None,
+            combo_window_ticks: 0,
+            combo_bonus_step: 1,
+            combo_max_step: 10,
+            combo_milestone_interval: 5,
+            rain_tick: 0,
+            rain_count: 200,
+            form_value_scale: [FIXED_ONE; 5],
         }
     }
 }
@@ -44,17 +102,38 @@ pub fn maybe_spawn_runes(state: &mut MatchState, config: &RuneSpawnConfig) {
         return;
     }
 
+    if let Some(schedule) = &config.seeded_schedule {
+        spawn_scheduled_runes(state, schedule);
+        return;
+    }
+
     // Initial spawn on first tick
-    if state.tick == 1 && state.runes.is_empty() {
+    let elapsed = state.elapsed_ticks();
+    if elapsed == 1 && state.runes.is_empty() {
         spawn_runes(state, config, config.initial_spawn_count, false, None);
     }
 
     // Check spawn interval
-    if state.tick.is_multiple_of(config.spawn_interval) {
+    if elapsed.is_multiple_of(config.spawn_interval) {
         spawn_runes(state, config, config.spawn_count, true, Some(0));
     }
 }
 
+/// Drop a burst of runes across the arena on the configured rain tick.
+///
+/// Positions and types come from the match RNG with the regular spawn
+/// weights, so the scramble is identical on replay.
+pub fn maybe_rune_rain(state: &mut MatchState, config: &RuneSpawnConfig) {
+    if config.rain_tick == 0 || state.elapsed_ticks() != config.rain_tick {
+        return;
+    }
+    if !matches!(state.phase, crate::game::state::MatchPhase::Playing) {
+        return;
+    }
+
+    spawn_runes(state, config, config.rain_count, true, None);
+}
+
 fn spawn_runes(
     state: &mut MatchState,
     config: &RuneSpawnConfig,
@@ -95,8 +174,87 @@ fn spawn_runes(
     }
 }
 
+/// Spawn this tick's entries of a seeded schedule, in schedule order.
+///
+/// Draws nothing from the match RNG, so the layout is identical for every
+/// seed. Like the RNG path, the tick-1 layout is spawned without events.
+fn spawn_scheduled_runes(state: &mut MatchState, schedule: &[ScheduledRune]) {
+    let tick = state.tick;
+    let elapsed = state.elapsed_ticks();
+    for entry in schedule.iter().filter(|e| e.tick == elapsed) {
+        let rune_id = state.spawn_rune(entry.position, entry.rune_type);
+        if elapsed > 1 {
+            state.push_event(GameEvent::rune_spawned(
+                tick,
+                rune_id,
+                entry.rune_type,
+                entry.position,
+            ));
+        }
+    }
+}
+
+/// Point value of a rune `age` ticks after it spawned.
+///
+/// Falls linearly from the full value to `value_decay_floor` of it over
+/// `value_decay_ticks`, then stays there. Never drops below one point.
+pub fn decayed_value(value: u32, age: u32, config: &RuneSpawnConfig) -> u32 {
+    if config.value_decay_ticks == 0 {
+        return value;
+    }
+    let progress = age.min(config.value_decay_ticks) as i64;
+    let lost = (FIXED_ONE - config.value_decay_floor) as i64 * progress / config.value_decay_ticks as i64;
+    let mult = FIXED_ONE as i64 - lost;
+    ((value as i64 * mult) >> 16).max(1) as u32
+}
+
+/// Scale a rune's points by the collector's form.
+///
+/// Lets larger forms earn less per rune so small players can catch up.
+/// Never drops below one point.
+pub fn form_scaled_value(value: u32, form: Form, config: &RuneSpawnConfig) -> u32 {
+    let mult = config.form_value_scale[form as usize];
+    if mult == FIXED_ONE {
+        return value;
+    }
+    ((value as i64 * mult as i64) >> 16).max(1) as u32
+}
+
+/// Roll for a chained bonus rune after `rune_id` was collected.
+///
+/// Draws from a sub-stream keyed by the collected rune ID, so the main
+/// match RNG is untouched. Returns the spawned rune ID, if any.
+pub fn maybe_chain_rune(state: &mut MatchState, config: &RuneSpawnConfig, rune_id: u32) -> Option<u32> {
+    if config.chain_chance <= 0 {
+        return None;
+    }
+
+    let origin = state.runes.get(&rune_id)?.position;
+
+    let uncollected_count = state.runes.values().filter(|r| !r.collected).count();
+    if uncollected_count >= config.max_runes as usize {
+        return None;
+    }
+
+    let mut rng = DeterministicRng::substream(state.rng_seed, RUNE_CHAIN_STREAM, rune_id as u64);
+    if !rng.next_bool(config.chain_chance) {
+        return None;
+    }
+
+    // Keep the bonus rune on walkable ground
+    let position = (0..RUNE_CHAIN_ATTEMPTS)
+        .map(|_| rng.random_position_in_circle(origin, config.chain_radius))
+        .find(|pos| state.map.contains_player_position(*pos, RuneState::RADIUS, None, false))
+        .unwrap_or(origin);
+    let rune_type = random_rune_type(&mut rng);
+
+    let chained_id = state.spawn_rune(position, rune_type);
+    state.push_event(GameEvent::rune_spawned(state.tick, chained_id, rune_type, position));
+    Some(chained_id)
+}
+
 /// Get a random rune type with weighted distribution.
-fn random_rune_type(rng: &mut crate::core::rng::DeterministicRng) -> RuneType {
+fn random_rune_type(rng: &mut DeterministicRng) -> RuneType {
     // Weighted distribution:
     // Wisdom: 60%, Power: 20%, Speed: 10%, Shield: 5%, Arcane: 4%, Chaos: 1%
     let roll = rng.next_int(100);
@@ -120,7 +278,12 @@ fn random_rune_type(rng: &mut crate::core::rng::DeterministicRng) -> RuneType {
 const RUNE_BUFF_DURATION: u32 = 300;
 
 /// Process rune collection with special effects.
-pub fn collect_rune(state: &mut MatchState, player_id: PlayerId, rune_id: u32) -> Option<GameEvent> {
+pub fn collect_rune(
+    state: &mut MatchState,
+    config: &RuneSpawnConfig,
+    player_id: PlayerId,
+    rune_id: u32,
+) -> Option<GameEvent> {
     // Get rune
     let rune = state.runes.get_mut(&rune_id)?;
     if rune.collected {
@@ -133,19 +296,40 @@ pub fn collect_rune(state: &mut MatchState, player_id: PlayerId, rune_id: u32) -
     rune.collected_by = Some(player_id);
 
     let rune_type = rune.rune_type;
-    let mut points = rune.value();
+    state.runes_collected_by_type[rune_type as usize] += 1;
+    let mut points = decayed_value(rune.value(), state.tick.saturating_sub(rune.spawn_tick), config);
 
     // Update player
     let player = state.players.get_mut(&player_id)?;
     if !player.alive {
         return None;
     }
+    points = form_scaled_value(points, player.form, config);
 
     // Apply wisdom multiplier if player has shrine buff
     if player.has_shrine_buff(crate::game::state::ShrineType::Wisdom) {
         points *= 2;
     }
 
+    // Combo: each rune collected within the window of the previous one
+    // adds another `combo_bonus_step`, up to `combo_max_step` steps
+    let mut combo_milestone = None;
+    if config.combo_window_ticks > 0 {
+        let chained = player.last_collect_tick
+            .is_some_and(|last| state.tick.saturating_sub(last) <= config.combo_window_ticks);
+        player.rune_combo = if chained { player.rune_combo.saturating_add(1) } else { 1 };
+        let bonus = player.rune_combo.saturating_sub(1).min(config.combo_max_step)
+            .saturating_mul(config.combo_bonus_step);
+        points = points.saturating_add(bonus);
+        if config.combo_milestone_interval > 0
+            && player.rune_combo > 1
+            && player.rune_combo % config.combo_milestone_interval == 0
+        {
+            combo_milestone = Some((player.rune_combo, bonus));
+        }
+        player.last_collect_tick = Some(state.tick);
+    }
+
     // Apply rune-specific effects
     match rune_type {
         RuneType::Speed => {
@@ -164,7 +348,7 @@ pub fn collect_rune(state: &mut MatchState, player_id: PlayerId, rune_id: u32) -
 
     let old_form = player.form;
     player.runes_collected += 1;
-    let evolved = player.add_score(points);
+    let evolved = player.add_score(points, &state.form_table);
     let new_score = player.score;
     let new_form = player.form;
 
@@ -178,6 +362,10 @@ pub fn collect_rune(state: &mut MatchState, player_id: PlayerId, rune_id: u32) -
         new_score,
     );
 
+    if let Some((combo, bonus)) = combo_milestone {
+        state.push_event(GameEvent::rune_combo(state.tick, player_id, combo, bonus));
+    }
+
     // If player evolved, also generate evolution event
     if evolved {
         state.push_event(GameEvent::form_evolved(
@@ -216,7 +404,7 @@ fn apply_chaos_effect(state: &mut MatchState, player_id: PlayerId) {
             }
             3 => {
                 // Bonus points (double the chaos base value)
-                player.add_score(50);
+                player.add_score(50, &state.form_table);
             }
             _ => unreachable!(),
         }
@@ -270,6 +458,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_seeded_schedule_ignores_match_seed() {
+        let schedule = vec![
+            ScheduledRune { tick: 1, rune_type: RuneType::Wisdom, position: FixedVec2::from_ints(0, 0) },
+            ScheduledRune { tick: 1, rune_type: RuneType::Power, position: FixedVec2::from_ints(5, -3) },
+            ScheduledRune { tick: 90, rune_type: RuneType::Chaos, position: FixedVec2::from_ints(-8, 12) },
+        ];
+        let config = RuneSpawnConfig { seeded_schedule: Some(schedule.clone()), ..Default::default() };
+
+        let run = |seed: u64| {
+            let mut state = MatchState::new([0; 16], seed);
+            state.phase = crate::game::state::MatchPhase::Playing;
+            for tick in 0..=120 {
+                state.tick = tick;
+                maybe_spawn_runes(&mut state, &config);
+            }
+            state.runes.values()
+                .map(|r| (r.position, r.rune_type))
+                .collect::<Vec<_>>()
+        };
+
+        let layout = run(12345);
+        assert_eq!(layout, run(67890));
+        assert_eq!(layout, schedule.iter().map(|e| (e.position, e.rune_type)).collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_rune_collection() {
         let mut state = MatchState::new([0; 16], 12345);
@@ -286,7 +500,7 @@ mod tests {
         let rune_id = 0;
 
         // Collect it
-        let event = collect_rune(&mut state, player_id, rune_id);
+        let event = collect_rune(&mut state, &RuneSpawnConfig::default(), player_id, rune_id);
         assert!(event.is_some());
 
         // Rune should be collected
@@ -295,4 +509,229 @@ mod tests {
         // Player should have score
         assert_eq!(state.players.get(&player_id).unwrap().score, SCORE_PER_RUNE);
     }
+
+    #[test]
+    fn test_late_collection_yields_fewer_points() {
+        let config = RuneSpawnConfig { value_decay_ticks: 600, ..Default::default() };
+
+        let score_after_wait = |wait: u32| {
+            let mut state = MatchState::new([0; 16], 12345);
+            state.phase = crate::game::state::MatchPhase::Playing;
+            let player_id = PlayerId::new([1; 16]);
+            state.add_player(player_id);
+            let position = state.players[&player_id].position;
+
+            state.tick = 10;
+            let rune_id = state.spawn_rune(position, RuneType::Arcane);
+            state.tick += wait;
+            collect_rune(&mut state, &config, player_id, rune_id).unwrap();
+            state.players[&player_id].score
+        };
+
+        let full = RuneType::Arcane.value();
+        assert_eq!(score_after_wait(0), full);
+        assert!(score_after_wait(300) < full);
+        assert!(score_after_wait(600) < score_after_wait(300));
+        // Fully decayed runes stay at the floor
+        assert_eq!(score_after_wait(600), score_after_wait(6000));
+        assert_eq!(score_after_wait(6000), decayed_value(full, 600, &config));
+    }
+
+    #[test]
+    fn test_large_forms_earn_less_per_rune() {
+        let config = RuneSpawnConfig {
+            form_value_scale: [
+                FIXED_ONE,
+                FIXED_ONE,
+                49152, // 0.75 * 65536
+                49152,
+                32768, // 0.5 * 65536
+            ],
+            ..Default::default()
+        };
+
+        let score_for = |tier: u8| {
+            let mut state = MatchState::new([0; 16], 12345);
+            state.phase = crate::game::state::MatchPhase::Playing;
+            let player_id = PlayerId::new([1; 16]);
+            state.add_player(player_id);
+            let table = state.form_table.clone();
+            let player = state.players.get_mut(&player_id).unwrap();
+            player.set_tier(tier, &table);
+            let (position, before) = (player.position, player.score);
+
+            let rune_id = state.spawn_rune(position, RuneType::Arcane);
+            collect_rune(&mut state, &config, player_id, rune_id).unwrap();
+            state.players[&player_id].score - before
+        };
+
+        let full = RuneType::Arcane.value();
+        assert_eq!(score_for(Form::Spark as u8), full);
+        assert_eq!(score_for(Form::Ancient as u8), full / 2);
+        assert!(score_for(Form::Ancient as u8) < score_for(Form::Spark as u8));
+
+        // Off by default
+        assert_eq!(form_scaled_value(full, Form::Ancient, &RuneSpawnConfig::default()), full);
+    }
+
+    #[test]
+    fn test_quick_collections_build_a_combo() {
+        let config = RuneSpawnConfig {
+            combo_window_ticks: 30,
+            combo_bonus_step: 5,
+            combo_milestone_interval: 2,
+            ..Default::default()
+        };
+
+        // Collect a Wisdom rune at each tick, returning the points each gave
+        let points_at = |ticks: &[u32]| {
+            let mut state = MatchState::new([0; 16], 12345);
+            state.phase = crate::game::state::MatchPhase::Playing;
+            let player_id = PlayerId::new([1; 16]);
+            state.add_player(player_id);
+            let position = state.players[&player_id].position;
+
+            let mut gained = Vec::new();
+            let mut combo_events = 0;
+            for &tick in ticks {
+                state.tick = tick;
+                let rune_id = state.spawn_rune(position, RuneType::Wisdom);
+                let before = state.players[&player_id].score;
+                collect_rune(&mut state, &config, player_id, rune_id).unwrap();
+                gained.push(state.players[&player_id].score - before);
+                combo_events += state.take_events().iter()
+                    .filter(|e| matches!(e.data, crate::game::events::GameEventData::RuneCombo { .. }))
+                    .count();
+            }
+            (gained, combo_events)
+        };
+
+        let base = RuneType::Wisdom.value();
+
+        // Quick succession: escalating bonus and a milestone on the second
+        let (quick, quick_events) = points_at(&[10, 20, 40]);
+        assert_eq!(quick, vec![base, base + 5, base + 10]);
+        assert_eq!(quick_events, 1);
+
+        // Spaced out beyond the window: no bonus, no events
+        let (spaced, spaced_events) = points_at(&[10, 100, 200]);
+        assert_eq!(spaced, vec![base; 3]);
+        assert_eq!(spaced_events, 0);
+
+        // Disabled by default
+        let mut state = MatchState::new([0; 16], 12345);
+        let player_id = PlayerId::new([1; 16]);
+        state.add_player(player_id);
+        let position = state.players[&player_id].position;
+        for tick in [10, 11] {
+            state.tick = tick;
+            let rune_id = state.spawn_rune(position, RuneType::Wisdom);
+            collect_rune(&mut state, &RuneSpawnConfig::default(), player_id, rune_id).unwrap();
+        }
+        assert_eq!(state.players[&player_id].score, base * 2);
+    }
+
+    #[test]
+    fn test_rune_economy_counters() {
+        let run = || {
+            let mut state = MatchState::new([0; 16], 12345);
+            state.phase = crate::game::state::MatchPhase::Playing;
+            let player_id = PlayerId::new([1; 16]);
+            state.add_player(player_id);
+
+            let config = RuneSpawnConfig::default();
+            for tick in 0..300 {
+                state.tick = tick;
+                maybe_spawn_runes(&mut state, &config);
+            }
+
+            let spawned_before = state.rune_economy().spawned;
+            assert_eq!(state.rune_distribution(), spawned_before);
+
+            // Collect the three lowest-ID runes
+            let ids: Vec<u32> = state.runes.keys().take(3).copied().collect();
+            for id in ids {
+                collect_rune(&mut state, &config, player_id, id);
+            }
+            (state.rune_economy(), state.compute_hash())
+        };
+
+        let (economy, hash) = run();
+        let collected: u32 = economy.collected.iter().sum();
+        assert_eq!(collected, 3);
+        for i in 0..economy.active.len() {
+            assert_eq!(economy.active[i], economy.spawned[i] - economy.collected[i]);
+        }
+
+        // Identical runs produce identical telemetry and hashes
+        assert_eq!(run(), (economy, hash));
+    }
+
+    #[test]
+    fn test_rune_chain_always_spawns_one() {
+        use crate::core::fixed::FIXED_ONE;
+        use crate::game::events::GameEventData;
+
+        let mut state = MatchState::new([0; 16], 12345);
+        state.phase = crate::game::state::MatchPhase::Playing;
+        let player_id = PlayerId::new([1; 16]);
+        state.add_player(player_id);
+        let player_pos = state.players.get(&player_id).unwrap().position;
+
+        let config = RuneSpawnConfig {
+            chain_chance: FIXED_ONE,
+            ..Default::default()
+        };
+        let rng_before = state.rng.state();
+
+        for _ in 0..5 {
+            let rune_id = state.spawn_rune(player_pos, RuneType::Wisdom);
+            let before = state.runes.len();
+            collect_rune(&mut state, &config, player_id, rune_id).unwrap();
+            state.take_events();
+
+            let chained = maybe_chain_rune(&mut state, &config, rune_id);
+            assert!(chained.is_some());
+            assert_eq!(state.runes.len(), before + 1);
+
+            let events = state.take_events();
+            assert_eq!(events.len(), 1);
+            assert!(matches!(
+                events[0].data,
+                GameEventData::RuneSpawned { rune_id, .. } if Some(rune_id) == chained
+            ));
+        }
+
+        // Chains draw from a sub-stream, not the match RNG
+        assert_eq!(state.rng.state(), rng_before);
+    }
+
+    #[test]
+    fn test_rune_chain_disabled_by_default() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let rune_id = state.spawn_rune(crate::core::vec2::FixedVec2::ZERO, RuneType::Wisdom);
+        assert!(maybe_chain_rune(&mut state, &RuneSpawnConfig::default(), rune_id).is_none());
+        assert_eq!(state.runes.len(), 1);
+    }
+
+    #[test]
+    fn test_rune_rain_drops_configured_burst() {
+        let config = RuneSpawnConfig { rain_tick: 600, rain_count: 40, ..Default::default() };
+        let rain = |seed: u64| {
+            let mut state = MatchState::new([0; 16], seed);
+            state.phase = crate::game::state::MatchPhase::Playing;
+            state.tick = 599;
+            maybe_rune_rain(&mut state, &config);
+            assert!(state.runes.is_empty(), "no rain before the scheduled tick");
+
+            state.tick = 600;
+            maybe_rune_rain(&mut state, &config);
+            state.runes.values().map(|r| (r.position, r.rune_type)).collect::<Vec<_>>()
+        };
+
+        let drops = rain(12345);
+        assert_eq!(drops.len(), 40);
+        assert_eq!(drops, rain(12345));
+        assert_ne!(drops, rain(54321));
+    }
 }
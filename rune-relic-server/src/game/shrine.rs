@@ -3,6 +3,7 @@
 //! Shrine spawning, channeling, and buff application.
 //! Shrines are fixed positions on the map that grant temporary buffs.
 
+use serde::{Serialize, Deserialize};
 use crate::core::fixed::{Fixed, FIXED_ONE};
 use crate::core::vec2::FixedVec2;
 use crate::game::state::{MatchState, PlayerId, ShrineState, ShrineType};
@@ -10,11 +11,16 @@ use crate::game::events::GameEvent;
 use crate::game::collision::circles_overlap;
 
 /// Configuration for shrine mechanics.
+#[derive(Serialize, Deserialize)]
 pub struct ShrineConfig {
     /// Channel progress per tick (FIXED_ONE / CHANNEL_TICKS)
     pub channel_rate: Fixed,
     /// Buff duration in ticks (30 seconds)
     pub buff_duration: u32,
+    /// Layout slot turned into a Defensive shrine (None = no defensive shrine)
+    pub defensive_shrine: Option<u8>,
+    /// Shield ticks the defensive pulse grants the capturer and teammates
+    pub defensive_shield_ticks: u32,
 }
 
 impl Default for ShrineConfig {
@@ -24,11 +30,13 @@ impl Default for ShrineConfig {
         Self {
             channel_rate: 218,
             buff_duration: 1800, // 30 seconds at 60 Hz
+            defensive_shrine: None,
+            defensive_shield_ticks: 300, // 5 seconds
         }
     }
 }
 
-/// Fixed shrine positions in arena (as Fixed values).
+/// Shrine positions on the stock map (as Fixed values), one per hub.
 /// Arcane Circuit layout with major hubs and minor junctions.
 const SHRINE_POSITIONS: [(FixedVec2, ShrineType); 9] = [
     (FixedVec2::from_ints(0, 0), ShrineType::Wisdom),
@@ -42,14 +50,40 @@ const SHRINE_POSITIONS: [(FixedVec2, ShrineType); 9] = [
     (FixedVec2::from_ints(-70, -45), ShrineType::Wisdom),
 ];
 
-/// Initialize shrines at fixed positions (called at match start).
-pub fn spawn_shrines(state: &mut MatchState) {
-    for (i, (position, shrine_type)) in SHRINE_POSITIONS.iter().enumerate() {
-        let shrine = ShrineState::new(i as u8, *position, *shrine_type);
+/// Initialize shrines on the map's hubs (called at match start).
+///
+/// Each shrine sits on the center of its hub, so shrines follow the map
+/// when it is stretched to a larger arena. The slot named by
+/// `defensive_shrine` spawns as a Defensive shrine.
+pub fn spawn_shrines(state: &mut MatchState, config: &ShrineConfig) {
+    for (i, (stock_position, shrine_type)) in SHRINE_POSITIONS.iter().enumerate() {
+        let position = state.map.hubs().get(i).map_or(stock_position, |hub| &hub.center);
+        let shrine_type = if config.defensive_shrine == Some(i as u8) {
+            ShrineType::Defensive
+        } else {
+            *shrine_type
+        };
+        let shrine = ShrineState::new(i as u8, *position, shrine_type);
         state.shrines.push(shrine);
     }
 }
 
+/// Shield the capturer of a Defensive shrine and their living teammates.
+///
+/// A one-off burst on the rune shield timer rather than a sustained shrine
+/// buff; an existing longer shield is kept.
+fn apply_shield_pulse(state: &mut MatchState, capturer: &PlayerId, shield_ticks: u32) {
+    let Some(team) = state.players.get(capturer).map(|p| p.team) else {
+        return;
+    };
+    for player in state.players.values_mut() {
+        let teammate = team.is_some() && player.team == team && player.alive;
+        if player.id == *capturer || teammate {
+            player.shield_buff_ticks = player.shield_buff_ticks.max(shield_ticks);
+        }
+    }
+}
+
 /// Shrine action to apply after processing.
 #[allow(dead_code)]
 enum ShrineAction {
@@ -72,7 +106,7 @@ pub fn process_shrines(state: &mut MatchState, config: &ShrineConfig) {
     // Step 1: Collect player positions for shrine checks
     let players: Vec<(PlayerId, FixedVec2, Fixed, bool)> = state.players
         .iter()
-        .map(|(id, p)| (*id, p.position, p.radius(), p.alive))
+        .map(|(id, p)| (*id, p.position, p.radius(&state.form_table), p.alive))
         .collect();
 
     // Step 2: Process each shrine
@@ -114,6 +148,14 @@ pub fn process_shrines(state: &mut MatchState, config: &ShrineConfig) {
                         shrine_type: shrine.shrine_type,
                     });
                     events.push(GameEvent::shrine_activated(tick, current, shrine.id));
+
+                    // Everyone else still on the shrine lost the contest; the
+                    // cooldown below wipes any progress they could resume
+                    for (player_id, pos, radius, alive) in &players {
+                        if *alive && *player_id != current && is_player_on_shrine(*pos, *radius, shrine) {
+                            events.push(GameEvent::shrine_contest_lost(tick, *player_id, shrine.id, current));
+                        }
+                    }
                 } else {
                     actions.push(ShrineAction::ContinueChannel {
                         shrine_id: shrine.id,
@@ -176,7 +218,9 @@ pub fn process_shrines(state: &mut MatchState, config: &ShrineConfig) {
                     shrine.channel_progress = 0;
                 }
                 // Apply buff to player
-                if let Some(player) = state.players.get_mut(&player_id) {
+                if shrine_type == ShrineType::Defensive {
+                    apply_shield_pulse(state, &player_id, config.defensive_shield_ticks);
+                } else if let Some(player) = state.players.get_mut(&player_id) {
                     player.add_shrine_buff(shrine_type, buff_duration);
                 }
             }
@@ -243,7 +287,7 @@ mod tests {
     #[test]
     fn test_shrine_spawn_positions() {
         let mut state = MatchState::new([0; 16], 12345);
-        spawn_shrines(&mut state);
+        spawn_shrines(&mut state, &ShrineConfig::default());
 
         assert_eq!(state.shrines.len(), 4);
 
@@ -261,7 +305,7 @@ mod tests {
     fn test_shrine_channel_progress() {
         let mut state = MatchState::new([0; 16], 12345);
         state.phase = crate::game::state::MatchPhase::Playing;
-        spawn_shrines(&mut state);
+        spawn_shrines(&mut state, &ShrineConfig::default());
 
         // Add player at shrine position
         let player_id = PlayerId::new([1; 16]);
@@ -295,4 +339,84 @@ mod tests {
         // Check multiplier
         assert_eq!(get_speed_multiplier(&player), 78643);
     }
+
+    #[test]
+    fn test_shrine_contest_loser_is_cleared() {
+        use crate::game::events::GameEventData;
+        use crate::game::state::PlayerState;
+
+        let mut state = MatchState::new([0; 16], 12345);
+        state.phase = crate::game::state::MatchPhase::Playing;
+        spawn_shrines(&mut state, &ShrineConfig::default());
+
+        let winner = PlayerId::new([1; 16]);
+        let loser = PlayerId::new([2; 16]);
+        state.players.insert(winner, PlayerState::new(winner, FixedVec2::ZERO));
+        state.players.insert(loser, PlayerState::new(loser, FixedVec2::from_ints(1, 0)));
+        state.alive_count = 2;
+
+        // Winner is one tick from capturing while the loser stands by
+        state.shrines[0].channeling_player = Some(winner);
+        state.shrines[0].channel_progress = FIXED_ONE - 1;
+
+        let config = ShrineConfig::default();
+        process_shrines(&mut state, &config);
+
+        let shrine = &state.shrines[0];
+        assert!(!shrine.active);
+        assert!(shrine.cooldown > 0);
+        assert_eq!(shrine.channeling_player, None);
+        assert_eq!(shrine.channel_progress, 0);
+        assert!(state.players[&winner].has_shrine_buff(shrine.shrine_type));
+
+        let events = state.take_events();
+        assert!(events.iter().any(|e| matches!(
+            e.data,
+            GameEventData::ShrineContestLost { player_id, shrine_id: 0, winner_id }
+                if player_id == loser && winner_id == winner
+        )));
+
+        // The loser cannot pick the channel back up during the cooldown
+        process_shrines(&mut state, &config);
+        assert_eq!(state.shrines[0].channeling_player, None);
+        assert_eq!(state.shrines[0].channel_progress, 0);
+    }
+
+    #[test]
+    fn test_defensive_shrine_pulses_shield_once_on_capture() {
+        use crate::game::state::PlayerState;
+
+        let config = ShrineConfig { defensive_shrine: Some(0), ..Default::default() };
+        let mut state = MatchState::new([0; 16], 12345);
+        state.phase = crate::game::state::MatchPhase::Playing;
+        spawn_shrines(&mut state, &config);
+        assert_eq!(state.shrines[0].shrine_type, ShrineType::Defensive);
+
+        let capturer = PlayerId::new([1; 16]);
+        let teammate = PlayerId::new([2; 16]);
+        let rival = PlayerId::new([3; 16]);
+        for (id, team, x) in [(capturer, 0, 0), (teammate, 0, 40), (rival, 1, -40)] {
+            let mut player = PlayerState::new(id, FixedVec2::from_ints(x, 0));
+            player.team = Some(team);
+            state.players.insert(id, player);
+        }
+        state.alive_count = 3;
+
+        while state.shrines[0].active {
+            process_shrines(&mut state, &config);
+        }
+
+        let shield = |state: &MatchState, id: &PlayerId| state.players[id].shield_buff_ticks;
+        assert_eq!(shield(&state, &capturer), config.defensive_shield_ticks);
+        assert_eq!(shield(&state, &teammate), config.defensive_shield_ticks);
+        assert_eq!(shield(&state, &rival), 0);
+        assert!(state.players[&capturer].shrine_buffs.is_empty(), "no sustained buff");
+
+        // Standing on the spent shrine grants nothing more
+        state.players.get_mut(&capturer).unwrap().shield_buff_ticks = 0;
+        for _ in 0..60 {
+            process_shrines(&mut state, &config);
+        }
+        assert_eq!(shield(&state, &capturer), 0);
+    }
 }
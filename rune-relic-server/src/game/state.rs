@@ -3,24 +3,32 @@
 //! All state types for match simulation.
 //! Uses BTreeMap for deterministic iteration order.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use serde::{Serialize, Deserialize};
 
 use crate::core::fixed::{
     Fixed, FIXED_ONE,
-    FORM_SPEEDS, FORM_RADII, SCORE_TO_EVOLVE,
+    FORM_SPEEDS, FORM_RADII,
     ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT,
 };
 use crate::core::vec2::FixedVec2;
 use crate::core::rng::DeterministicRng;
 use crate::core::hash::{StateHash, StateHasher, compute_state_hash};
 use crate::game::events::GameEvent;
+use crate::game::form_table::FormTable;
+use crate::game::input::InputFrame;
 use crate::game::map::ArcaneCircuitMap;
+use crate::game::survivor::{EnemyState, WaveState, hash_enemies};
 
 // =============================================================================
 // PLAYER ID
 // =============================================================================
 
+/// Final standing in a match (1 = winner).
+///
+/// Wide enough for battle-royale lobbies of hundreds of players.
+pub type Placement = u16;
+
 /// Unique player identifier (UUID as bytes).
 ///
 /// Implements Ord for deterministic BTreeMap ordering.
@@ -99,6 +107,17 @@ impl Form {
         }
     }
 
+    /// Get previous form (if not min).
+    pub fn prev(self) -> Option<Form> {
+        match self {
+            Form::Spark => None,
+            Form::Glyph => Some(Form::Spark),
+            Form::Ward => Some(Form::Glyph),
+            Form::Arcane => Some(Form::Ward),
+            Form::Ancient => Some(Form::Arcane),
+        }
+    }
+
     /// Check if this form can eat another form.
     #[inline]
     pub fn can_eat(self, other: Form) -> bool {
@@ -141,9 +160,12 @@ pub struct PlayerState {
     /// Current velocity
     pub velocity: FixedVec2,
 
-    /// Current evolution form
+    /// Current evolution form (presented form of `tier`)
     pub form: Form,
 
+    /// Position on the match's form ladder
+    pub tier: u8,
+
     /// Accumulated score
     pub score: u32,
 
@@ -151,7 +173,7 @@ pub struct PlayerState {
     pub alive: bool,
 
     /// Final placement (1st, 2nd, etc.) - set when eliminated
-    pub placement: Option<u8>,
+    pub placement: Option<Placement>,
 
     /// Tick when player was eliminated
     pub eliminated_tick: Option<u32>,
@@ -171,6 +193,12 @@ pub struct PlayerState {
     /// Number of runes collected
     pub runes_collected: u32,
 
+    /// Tick of the most recent rune collection (tracked only with combos on)
+    pub last_collect_tick: Option<u32>,
+
+    /// Runes collected in the current combo chain (0 = no chain yet)
+    pub rune_combo: u32,
+
     // =========================================================================
     // Health & Buff System (Phase 1 & 2)
     // =========================================================================
@@ -197,6 +225,65 @@ pub struct PlayerState {
     /// Active dash velocity (from Dash ability)
     pub dash_velocity: Option<FixedVec2>,
 
+    /// Ticks the ability button has been held charging a dash
+    pub dash_charge_ticks: u32,
+
+    /// Ability energy (only used when the energy pool is enabled)
+    pub energy: Fixed,
+
+    /// Energy pool size (0 = energy disabled)
+    pub max_energy: Fixed,
+
+    /// Ability overcharge meter (only used when overcharge is enabled)
+    pub overcharge: u32,
+
+    /// Ticks left of an overcharge ability lockout
+    pub overcharge_lockout_ticks: u32,
+
+    /// Inward velocity applied by the shrinking zone (zero inside the zone)
+    pub zone_push: FixedVec2,
+
+    /// Consecutive ticks spent outside the zone while it deals damage
+    pub ticks_outside_zone: u32,
+
+    /// Launch velocity from boost pads, decaying like friction
+    pub boost_velocity: FixedVec2,
+
+    /// Boost pad the player is standing on (re-triggers only after leaving)
+    pub boost_pad: Option<u8>,
+
+    /// Spring velocity from tethers this player is part of
+    pub tether_pull: FixedVec2,
+
+    /// Movement speed multiplier while `slow_ticks` > 0
+    pub slow_mult: Fixed,
+
+    /// Remaining ticks of a movement slow (from mines)
+    pub slow_ticks: u32,
+
+    // =========================================================================
+    // Team Revive
+    // =========================================================================
+
+    /// Team (None = free-for-all)
+    pub team: Option<u8>,
+
+    /// Tick the player was downed (Some = awaiting revive, not yet eliminated)
+    pub downed_tick: Option<u32>,
+
+    /// Consecutive ticks a teammate has channeled a revive
+    pub revive_progress: u32,
+
+    // =========================================================================
+    // Respawn
+    // =========================================================================
+
+    /// Times this player has been eliminated and sent to respawn
+    pub deaths: u32,
+
+    /// Tick the player returns to play (Some = awaiting respawn)
+    pub respawn_tick: Option<u32>,
+
     // =========================================================================
     // Shrine Buffs (Phase 3)
     // =========================================================================
@@ -218,6 +305,7 @@ impl PlayerState {
             spawn_zone_active: false,
             velocity: FixedVec2::ZERO,
             form: Form::Spark,
+            tier: 0,
             score: 0,
             alive: true,
             placement: None,
@@ -227,6 +315,8 @@ impl PlayerState {
             last_jump_tick: 0,
             kills: 0,
             runes_collected: 0,
+            last_collect_tick: None,
+            rune_combo: 0,
             // Health & buffs
             health: FIXED_ONE,
             max_health: FIXED_ONE,
@@ -235,48 +325,91 @@ impl PlayerState {
             // Ability state
             invulnerable_ticks: 0,
             dash_velocity: None,
+            dash_charge_ticks: 0,
+            energy: 0,
+            max_energy: 0,
+            overcharge: 0,
+            overcharge_lockout_ticks: 0,
+            zone_push: FixedVec2::ZERO,
+            ticks_outside_zone: 0,
+            boost_velocity: FixedVec2::ZERO,
+            boost_pad: None,
+            tether_pull: FixedVec2::ZERO,
+            slow_mult: FIXED_ONE,
+            slow_ticks: 0,
+            // Team revive
+            team: None,
+            downed_tick: None,
+            revive_progress: 0,
+            // Respawn
+            deaths: 0,
+            respawn_tick: None,
             // Shrine buffs
             shrine_buffs: Vec::new(),
             shrine_buff_ticks: Vec::new(),
         }
     }
 
-    /// Get current movement speed based on form.
+    /// Get current movement speed from the form ladder.
     #[inline]
-    pub fn speed(&self) -> Fixed {
-        self.form.speed()
+    pub fn speed(&self, table: &FormTable) -> Fixed {
+        table.speed(self.tier)
     }
 
-    /// Get current collision radius based on form.
+    /// Get current collision radius from the form ladder.
+    ///
+    /// This is the effective radius: it scales with health when the ladder
+    /// has a damaged radius scale (see `FormTable::effective_radius`).
     #[inline]
-    pub fn radius(&self) -> Fixed {
-        self.form.radius()
+    pub fn radius(&self, table: &FormTable) -> Fixed {
+        table.effective_radius(self.tier, self.health, self.max_health)
+    }
+
+    /// Move to `tier` on the ladder, taking its form.
+    pub fn set_tier(&mut self, tier: u8, table: &FormTable) {
+        self.tier = tier;
+        self.form = table.form(tier);
     }
 
     /// Check if player can evolve based on score.
-    pub fn can_evolve(&self) -> bool {
-        if self.form == Form::Ancient {
-            return false;
+    pub fn can_evolve(&self, table: &FormTable) -> bool {
+        match table.next(self.tier) {
+            Some(next) => self.score >= table.tier(next).entry_score,
+            None => false,
         }
-        let threshold = SCORE_TO_EVOLVE[self.form as usize];
-        self.score >= threshold
     }
 
-    /// Evolve to next form if possible.
-    pub fn try_evolve(&mut self) -> bool {
-        if let Some(next_form) = self.form.next() {
-            if self.can_evolve() {
-                self.form = next_form;
+    /// Evolve to next tier if possible.
+    pub fn try_evolve(&mut self, table: &FormTable) -> bool {
+        if let Some(next) = table.next(self.tier) {
+            if self.can_evolve(table) {
+                self.set_tier(next, table);
                 return true;
             }
         }
         false
     }
 
-    /// Check if ability is ready.
+    /// De-evolve to previous tier, restoring health.
+    ///
+    /// Score is capped at the entry threshold of the new tier so the
+    /// player has to earn the lost tier back. Returns false at the bottom.
+    pub fn try_devolve(&mut self, table: &FormTable) -> bool {
+        let Some(prev) = table.prev(self.tier) else {
+            return false;
+        };
+        self.set_tier(prev, table);
+        self.score = self.score.min(table.tier(prev).entry_score);
+        self.health = self.max_health;
+        true
+    }
+
+    /// Check if ability is ready and the player can pay `energy_cost`.
     #[inline]
-    pub fn ability_ready(&self) -> bool {
+    pub fn ability_ready(&self, energy_cost: Fixed) -> bool {
         self.ability_cooldown <= 0
+            && self.energy >= energy_cost
+            && self.overcharge_lockout_ticks == 0
     }
 
     /// Check if player can jump.
@@ -286,9 +419,11 @@ impl PlayerState {
     }
 
     /// Add score and check for evolution.
-    pub fn add_score(&mut self, amount: u32) -> bool {
-        self.score = self.score.saturating_add(amount);
-        self.try_evolve()
+    ///
+    /// Saturates at `MAX_SCORE`.
+    pub fn add_score(&mut self, amount: u32, table: &FormTable) -> bool {
+        self.score = self.score.saturating_add(amount).min(crate::core::fixed::MAX_SCORE);
+        self.try_evolve(table)
     }
 
     /// Hash this player's state for verification.
@@ -297,6 +432,7 @@ impl PlayerState {
         hasher.update_vec2(self.position);
         hasher.update_vec2(self.velocity);
         hasher.update_u8(self.form as u8);
+        hasher.update_u8(self.tier);
         hasher.update_u32(self.score);
         hasher.update_bool(self.alive);
         hasher.update_bool(self.spawn_zone_active);
@@ -305,11 +441,48 @@ impl PlayerState {
             hasher.update_u8(zone_id);
         }
         hasher.update_u32(self.kills);
+        hasher.update_u32(self.last_jump_tick);
+        hasher.update_bool(self.last_collect_tick.is_some());
+        if let Some(last_collect_tick) = self.last_collect_tick {
+            hasher.update_u32(last_collect_tick);
+        }
+        hasher.update_u32(self.rune_combo);
         // Health & buff system
         hasher.update_fixed(self.health);
         hasher.update_u32(self.speed_buff_ticks);
         hasher.update_u32(self.shield_buff_ticks);
         hasher.update_u32(self.invulnerable_ticks);
+        hasher.update_u32(self.dash_charge_ticks);
+        hasher.update_fixed(self.energy);
+        hasher.update_fixed(self.max_energy);
+        hasher.update_u32(self.overcharge);
+        hasher.update_u32(self.overcharge_lockout_ticks);
+        hasher.update_vec2(self.zone_push);
+        hasher.update_u32(self.ticks_outside_zone);
+        hasher.update_vec2(self.boost_velocity);
+        hasher.update_bool(self.boost_pad.is_some());
+        if let Some(pad_id) = self.boost_pad {
+            hasher.update_u8(pad_id);
+        }
+        hasher.update_vec2(self.tether_pull);
+        hasher.update_u32(self.slow_ticks);
+        hasher.update_fixed(self.slow_mult);
+        // Team revive
+        hasher.update_bool(self.team.is_some());
+        if let Some(team) = self.team {
+            hasher.update_u8(team);
+        }
+        hasher.update_bool(self.downed_tick.is_some());
+        if let Some(downed_tick) = self.downed_tick {
+            hasher.update_u32(downed_tick);
+        }
+        hasher.update_u32(self.revive_progress);
+        // Respawn
+        hasher.update_u32(self.deaths);
+        hasher.update_bool(self.respawn_tick.is_some());
+        if let Some(respawn_tick) = self.respawn_tick {
+            hasher.update_u32(respawn_tick);
+        }
         // Shrine buffs
         for (i, shrine_type) in self.shrine_buffs.iter().enumerate() {
             hasher.update_u8(*shrine_type as u8);
@@ -380,6 +553,9 @@ pub enum RuneType {
     Chaos = 5,    // Rainbow - random effect
 }
 
+/// Number of rune types (length of per-type counter arrays).
+pub const RUNE_TYPE_COUNT: usize = 6;
+
 impl RuneType {
     /// Get point value for this rune type.
     pub fn value(self) -> u32 {
@@ -427,6 +603,9 @@ pub struct RuneState {
 
     /// Who collected it (if collected)
     pub collected_by: Option<PlayerId>,
+
+    /// Tick the rune spawned on
+    pub spawn_tick: u32,
 }
 
 impl RuneState {
@@ -442,6 +621,7 @@ impl RuneState {
             collected: false,
             collected_tick: None,
             collected_by: None,
+            spawn_tick: 0,
         }
     }
 
@@ -463,6 +643,8 @@ pub enum ShrineType {
     Power = 1,   // Damage boost
     Speed = 2,   // Speed boost
     Shield = 3,  // Damage reduction
+    /// One-time shield pulse on capture
+    Defensive = 4,
 }
 
 /// State of a shrine.
@@ -532,6 +714,20 @@ pub enum AbilityType {
     GravityWell = 3,
     /// Ancient: Consume (extended range)
     Consume = 4,
+    /// Area denial mine (replaces a form's ability when configured)
+    Mine = 5,
+    /// Spring tether to the nearest enemy (replaces a form's ability when configured)
+    Tether = 6,
+}
+
+/// Interaction between two abilities (see `game::ability` combo matrix).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum ComboType {
+    /// Repel dispersed an enemy gravity well
+    RepelDispelsWell = 0,
+    /// Dash launched inside an enemy gravity well was shortened
+    WellDampensDash = 1,
 }
 
 /// Active ability effect on the field (e.g., gravity well).
@@ -545,14 +741,26 @@ pub struct ActiveAbilityEffect {
     pub position: FixedVec2,
     /// Remaining duration in ticks
     pub remaining_ticks: u32,
-    /// Effect radius
+    /// Effect radius (rest length for tethers)
     pub radius: Fixed,
+    /// Players this effect has already triggered on (mines), in contact order
+    #[serde(default)]
+    pub triggered: Vec<PlayerId>,
+    /// Second player bound by the effect (tether target)
+    #[serde(default)]
+    pub target_player: Option<PlayerId>,
 }
 
 // =============================================================================
 // MATCH PHASE
 // =============================================================================
 
+/// Random spawn candidates sampled per player in `assign_spawn_positions`.
+pub const SPAWN_CANDIDATES: u32 = 8;
+
+/// Spawn protection for a player backfilled mid-match (3 seconds at 60 Hz).
+pub const BACKFILL_PROTECTION_TICKS: u32 = 180;
+
 /// Current phase of the match.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[derive(Default)]
@@ -584,6 +792,10 @@ pub struct MatchState {
     /// Current tick (0 to 5400 for 90 seconds)
     pub tick: u32,
 
+    /// Tick the match clock started from (0 unless resumed or continued)
+    #[serde(default)]
+    pub start_tick: u32,
+
     /// Current match phase
     pub phase: MatchPhase,
 
@@ -614,7 +826,7 @@ pub struct MatchState {
     pub alive_count: u32,
 
     /// Next placement number (for elimination order)
-    pub next_placement: u8,
+    pub next_placement: Placement,
 
     /// Events generated this tick (cleared each tick)
     #[serde(skip)]
@@ -623,16 +835,125 @@ pub struct MatchState {
     /// Arena shrink progress (0 = full size, FIXED_ONE = minimum)
     pub arena_shrink: Fixed,
 
+    /// Unshrunk arena half-width (from `MatchConfig` when play starts)
+    pub arena_half_width: Fixed,
+
+    /// Unshrunk arena half-height (from `MatchConfig` when play starts)
+    pub arena_half_height: Fixed,
+
+    /// Ticks spent at full shrink (drives sudden-death damage escalation)
+    pub sudden_death_ticks: u32,
+
     /// Active ability effects on the field (gravity wells, etc.)
     pub active_abilities: Vec<ActiveAbilityEffect>,
+
+    /// Inputs waiting out the configured input delay: `(apply_tick, frame)`
+    /// per player, oldest first
+    pub input_delay_queue: BTreeMap<PlayerId, VecDeque<(u32, InputFrame)>>,
+
+    /// Runes spawned so far, indexed by `RuneType as usize`
+    pub runes_spawned_by_type: [u32; RUNE_TYPE_COUNT],
+
+    /// Runes collected so far, indexed by `RuneType as usize`
+    pub runes_collected_by_type: [u32; RUNE_TYPE_COUNT],
+
+    /// First-blood milestone already awarded
+    pub first_blood_claimed: bool,
+
+    /// First-evolution milestone already awarded
+    pub first_evolution_claimed: bool,
+
+    /// First-shrine-capture milestone already awarded
+    pub first_shrine_claimed: bool,
+
+    /// Evolution ladder in use (classic five forms by default)
+    #[serde(default)]
+    pub form_table: FormTable,
+
+    /// Survivor-mode enemies (BTreeMap for deterministic iteration)
+    #[serde(default)]
+    pub enemies: BTreeMap<u32, EnemyState>,
+
+    /// Survivor-mode wave progression
+    #[serde(default)]
+    pub waves: WaveState,
+}
+
+/// Per-player starting handicap (casual fairness, tournament seeding).
+///
+/// The default is a fresh Spark with no score.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerStart {
+    /// Starting ladder tier
+    pub tier: u8,
+    /// Starting score
+    pub score: u32,
+}
+
+/// Snapshot of the rune economy for balancing spawn weights.
+///
+/// All arrays are indexed by `RuneType as usize`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RuneEconomy {
+    /// Uncollected runes currently on the field
+    pub active: [u32; RUNE_TYPE_COUNT],
+    /// Runes spawned since match start
+    pub spawned: [u32; RUNE_TYPE_COUNT],
+    /// Runes collected since match start
+    pub collected: [u32; RUNE_TYPE_COUNT],
+}
+
+/// Sample `SPAWN_CANDIDATES` points across the spawn zones and return the
+/// one farthest from `placed` as `(zone_id, position)`. Ties keep the
+/// earliest candidate.
+fn best_spawn_candidate(
+    rng: &mut DeterministicRng,
+    map: &ArcaneCircuitMap,
+    player_radius: Fixed,
+    placed: &[FixedVec2],
+) -> Option<(u8, FixedVec2)> {
+    if map.spawn_zones().is_empty() {
+        return None;
+    }
+
+    let mut best: Option<(i64, u8, FixedVec2)> = None;
+
+    for _ in 0..SPAWN_CANDIDATES {
+        let zone_idx = rng.next_int(map.spawn_zones().len() as u32) as usize;
+        let zone = &map.spawn_zones()[zone_idx];
+        let radius = zone.radius.saturating_sub(player_radius).max(0);
+        let candidate = rng.random_position_in_circle(zone.center, radius);
+
+        let separation = placed
+            .iter()
+            .map(|p| p.distance_squared_wide(candidate))
+            .min()
+            .unwrap_or(i64::MAX);
+
+        if best.is_none_or(|(best_sep, _, _)| separation > best_sep) {
+            best = Some((separation, zone.id, candidate));
+        }
+    }
+
+    best.map(|(_, zone_id, position)| (zone_id, position))
 }
 
 impl MatchState {
     /// Create a new match state.
     pub fn new(match_id: [u8; 16], rng_seed: u64) -> Self {
+        Self::with_start_tick(match_id, rng_seed, 0)
+    }
+
+    /// Create a match state whose clock starts at `start_tick`.
+    ///
+    /// The first simulated tick is `start_tick + 1`. Match length, arena
+    /// shrink and rune scheduling all count from `start_tick`, so the match
+    /// plays out exactly as a fresh one would, offset in time.
+    pub fn with_start_tick(match_id: [u8; 16], rng_seed: u64, start_tick: u32) -> Self {
         Self {
             match_id,
-            tick: 0,
+            tick: start_tick,
+            start_tick,
             phase: MatchPhase::Waiting,
             rng_seed,
             rng: DeterministicRng::new(rng_seed),
@@ -645,37 +966,133 @@ impl MatchState {
             next_placement: 0,
             pending_events: Vec::new(),
             arena_shrink: 0,
+            arena_half_width: ARENA_HALF_WIDTH,
+            arena_half_height: ARENA_HALF_HEIGHT,
+            sudden_death_ticks: 0,
             active_abilities: Vec::new(),
+            input_delay_queue: BTreeMap::new(),
+            runes_spawned_by_type: [0; RUNE_TYPE_COUNT],
+            runes_collected_by_type: [0; RUNE_TYPE_COUNT],
+            first_blood_claimed: false,
+            first_evolution_claimed: false,
+            first_shrine_claimed: false,
+            form_table: FormTable::default(),
+            enemies: BTreeMap::new(),
+            waves: WaveState::default(),
         }
     }
 
+    /// Install a custom form ladder and reset every player to its first
+    /// tier. Call before the match starts; matches take their ladder from
+    /// `MatchConfig::form_table` via `prepare_match`.
+    pub fn set_form_table(&mut self, table: FormTable) {
+        for player in self.players.values_mut() {
+            player.set_tier(0, &table);
+        }
+        self.form_table = table;
+    }
+
     /// Add a player to the match.
     pub fn add_player(&mut self, id: PlayerId) {
-        let player = PlayerState::new(id, FixedVec2::ZERO);
+        let mut player = PlayerState::new(id, FixedVec2::ZERO);
+        player.set_tier(0, &self.form_table);
         self.players.insert(id, player);
         self.alive_count += 1;
     }
 
+    /// Apply a handicap start to a player who has not played yet.
+    ///
+    /// The tier is clamped to the ladder and the score to `MAX_SCORE`.
+    /// Call before `assign_spawn_positions` so spawns account for the
+    /// starting radius. Returns false if the player is not in the match.
+    pub fn apply_player_start(&mut self, id: &PlayerId, start: PlayerStart) -> bool {
+        let Some(player) = self.players.get_mut(id) else {
+            return false;
+        };
+        let top = self.form_table.len().saturating_sub(1) as u8;
+        player.set_tier(start.tier.min(top), &self.form_table);
+        player.score = start.score.min(crate::core::fixed::MAX_SCORE);
+        true
+    }
+
+    /// Split the players into `team_count` teams, round-robin in ID order.
+    ///
+    /// A `team_count` of 0 leaves everyone free-for-all.
+    pub fn assign_teams(&mut self, team_count: u8) {
+        if team_count == 0 {
+            return;
+        }
+        for (idx, player) in self.players.values_mut().enumerate() {
+            player.team = Some((idx % team_count as usize) as u8);
+        }
+    }
+
+    /// Team with the fewest living members (lowest ID on ties), or None in
+    /// a free-for-all match.
+    fn smallest_team(&self) -> Option<u8> {
+        let team_count = self.players.values().filter_map(|p| p.team).max()? as usize + 1;
+        let mut living = vec![0u32; team_count];
+        for team in self.players.values().filter(|p| p.alive).filter_map(|p| p.team) {
+            living[team as usize] += 1;
+        }
+        (0..team_count).min_by_key(|&team| living[team]).map(|team| team as u8)
+    }
+
     /// Assign spawn positions to all players (deterministic).
+    ///
+    /// Each player samples `SPAWN_CANDIDATES` random points across the
+    /// spawn zones and takes the one farthest from everyone already placed.
+    /// Players are placed in ID order; ties keep the earliest candidate.
     pub fn assign_spawn_positions(&mut self) {
-        let mut zone_ids: Vec<u8> = self.map.spawn_zones().iter().map(|zone| zone.id).collect();
-        if zone_ids.is_empty() {
+        if self.map.spawn_zones().is_empty() {
             return;
         }
 
-        self.rng.shuffle(&mut zone_ids);
+        let mut placed: Vec<FixedVec2> = Vec::with_capacity(self.players.len());
 
-        for (idx, player) in self.players.values_mut().enumerate() {
-            let zone_id = zone_ids.get(idx % zone_ids.len()).copied().unwrap_or(0);
-            let zone = self.map.spawn_zone(zone_id).unwrap_or(&self.map.spawn_zones()[0]);
-            let radius = zone.radius.saturating_sub(player.radius()).max(0);
-            let position = self.rng.random_position_in_circle(zone.center, radius);
+        for player in self.players.values_mut() {
+            let best = best_spawn_candidate(&mut self.rng, &self.map, player.radius(&self.form_table), &placed);
+
+            if let Some((zone_id, position)) = best {
+                player.position = position;
+                player.velocity = FixedVec2::ZERO;
+                player.spawn_zone_id = Some(zone_id);
+                player.spawn_zone_active = true;
+                placed.push(position);
+            }
+        }
+    }
+
+    /// Add a late joiner to a match in progress (backfill).
+    ///
+    /// The player spawns at the spawn candidate farthest from every living
+    /// player and gets `BACKFILL_PROTECTION_TICKS` of invulnerability.
+    /// Emits `PlayerJoined`. Returns false if the player is already in the
+    /// match.
+    pub fn backfill_player(&mut self, id: PlayerId) -> bool {
+        if self.players.contains_key(&id) {
+            return false;
+        }
 
+        let mut player = PlayerState::new(id, FixedVec2::ZERO);
+        player.set_tier(0, &self.form_table);
+        player.team = self.smallest_team();
+        let alive: Vec<FixedVec2> = self.players.values()
+            .filter(|p| p.alive)
+            .map(|p| p.position)
+            .collect();
+        if let Some((zone_id, position)) = best_spawn_candidate(&mut self.rng, &self.map, player.radius(&self.form_table), &alive) {
             player.position = position;
-            player.velocity = FixedVec2::ZERO;
             player.spawn_zone_id = Some(zone_id);
             player.spawn_zone_active = true;
         }
+        player.invulnerable_ticks = BACKFILL_PROTECTION_TICKS;
+
+        let position = player.position;
+        self.players.insert(id, player);
+        self.alive_count += 1;
+        self.push_event(GameEvent::player_joined(self.tick, id, position));
+        true
     }
 
     /// Get a player by ID.
@@ -692,17 +1109,43 @@ impl MatchState {
     pub fn spawn_rune(&mut self, position: FixedVec2, rune_type: RuneType) -> u32 {
         let id = self.next_rune_id;
         self.next_rune_id += 1;
-        let rune = RuneState::new(id, position, rune_type);
+        let mut rune = RuneState::new(id, position, rune_type);
+        rune.spawn_tick = self.tick;
         self.runes.insert(id, rune);
+        self.runes_spawned_by_type[rune_type as usize] += 1;
         id
     }
 
+    /// Count uncollected runes on the field by type.
+    pub fn rune_distribution(&self) -> [u32; RUNE_TYPE_COUNT] {
+        let mut counts = [0; RUNE_TYPE_COUNT];
+        for rune in self.runes.values().filter(|r| !r.collected) {
+            counts[rune.rune_type as usize] += 1;
+        }
+        counts
+    }
+
+    /// Current rune economy telemetry.
+    pub fn rune_economy(&self) -> RuneEconomy {
+        RuneEconomy {
+            active: self.rune_distribution(),
+            spawned: self.runes_spawned_by_type,
+            collected: self.runes_collected_by_type,
+        }
+    }
+
+    /// Set the unshrunk arena size (half extents).
+    pub fn set_arena_size(&mut self, half_width: Fixed, half_height: Fixed) {
+        self.arena_half_width = half_width;
+        self.arena_half_height = half_height;
+    }
+
     /// Get current arena bounds (accounting for shrink).
     pub fn current_arena_bounds(&self) -> (Fixed, Fixed) {
         // Shrink from full size to 50% over time
         let shrink_factor = FIXED_ONE - (self.arena_shrink >> 1);
-        let half_width = crate::core::fixed::fixed_mul(ARENA_HALF_WIDTH, shrink_factor);
-        let half_height = crate::core::fixed::fixed_mul(ARENA_HALF_HEIGHT, shrink_factor);
+        let half_width = crate::core::fixed::fixed_mul(self.arena_half_width, shrink_factor);
+        let half_height = crate::core::fixed::fixed_mul(self.arena_half_height, shrink_factor);
         (half_width, half_height)
     }
 
@@ -710,23 +1153,24 @@ impl MatchState {
     pub fn is_in_bounds(&self, player: &PlayerState) -> bool {
         self.map.contains_player_position(
             player.position,
-            player.radius(),
+            player.radius(&self.form_table),
             player.spawn_zone_id,
             player.spawn_zone_active,
         )
     }
 
     /// Eliminate a player.
-    pub fn eliminate_player(&mut self, victim_id: &PlayerId, killer_id: Option<&PlayerId>) {
+    ///
+    /// Returns the placement assigned, or None if the player was not alive
+    /// (nothing changes, so callers must not report an elimination).
+    pub fn eliminate_player(&mut self, victim_id: &PlayerId, killer_id: Option<&PlayerId>) -> Option<Placement> {
         // First check if victim is alive
         let victim_alive = self.players.get(victim_id).map(|p| p.alive).unwrap_or(false);
         if !victim_alive {
-            return;
+            return None;
         }
 
-        // Calculate placement before mutating
-        let player_count = self.players.len() as u8;
-        let placement = player_count - self.next_placement;
+        let placement = self.take_placement();
 
         // Update victim
         if let Some(victim) = self.players.get_mut(victim_id) {
@@ -734,23 +1178,178 @@ impl MatchState {
             victim.eliminated_tick = Some(self.tick);
             victim.eliminated_by = killer_id.copied();
             victim.placement = Some(placement);
+            // A dash queued earlier this tick never fires for a dead player
+            victim.dash_velocity = None;
+            victim.dash_charge_ticks = 0;
+            victim.boost_velocity = FixedVec2::ZERO;
+            victim.boost_pad = None;
+            victim.tether_pull = FixedVec2::ZERO;
         }
 
-        // Update alive count and placement counter
         self.alive_count = self.alive_count.saturating_sub(1);
-        self.next_placement += 1;
+        self.clear_effects_of(victim_id);
 
         // Credit kill to killer (separate borrow)
         if let Some(kid) = killer_id {
             if let Some(killer) = self.players.get_mut(kid) {
                 killer.kills += 1;
-                killer.add_score(crate::core::fixed::SCORE_PER_KILL);
+                killer.add_score(crate::core::fixed::SCORE_PER_KILL, &self.form_table);
             }
         }
+
+        Some(placement)
+    }
+
+    /// Hand out the next placement (last place first) and advance the counter.
+    ///
+    /// The only place placements are computed, so every elimination path
+    /// agrees on the numbering.
+    fn take_placement(&mut self) -> Placement {
+        let player_count = Placement::try_from(self.players.len()).unwrap_or(Placement::MAX);
+        let placement = player_count.saturating_sub(self.next_placement);
+        self.next_placement = self.next_placement.saturating_add(1);
+        placement
+    }
+
+    /// Down a player awaiting a team revive.
+    ///
+    /// The player leaves play (`alive = false`) but gets no placement and no
+    /// kill is credited until [`Self::confirm_downed_elimination`].
+    pub fn down_player(&mut self, victim_id: &PlayerId, killer_id: Option<&PlayerId>) {
+        let tick = self.tick;
+        let Some(victim) = self.players.get_mut(victim_id) else {
+            return;
+        };
+        if !victim.alive {
+            return;
+        }
+
+        victim.alive = false;
+        victim.downed_tick = Some(tick);
+        victim.revive_progress = 0;
+        victim.eliminated_by = killer_id.copied();
+        victim.velocity = FixedVec2::ZERO;
+        victim.dash_velocity = None;
+        victim.boost_velocity = FixedVec2::ZERO;
+        victim.boost_pad = None;
+        victim.tether_pull = FixedVec2::ZERO;
+        self.alive_count = self.alive_count.saturating_sub(1);
+        self.clear_effects_of(victim_id);
+    }
+
+    /// Remove the lingering ability effects of a player leaving play.
+    ///
+    /// Tethers stay so `process_tethers` can release them with an event.
+    fn clear_effects_of(&mut self, player_id: &PlayerId) {
+        self.active_abilities.retain(|effect| {
+            effect.source_player != *player_id || effect.ability_type == AbilityType::Tether
+        });
+    }
+
+    /// Bring a downed player back into play at full health.
+    pub fn revive_player(&mut self, player_id: &PlayerId, invulnerable_ticks: u32) {
+        let Some(player) = self.players.get_mut(player_id) else {
+            return;
+        };
+        if player.downed_tick.is_none() {
+            return;
+        }
+
+        player.alive = true;
+        player.downed_tick = None;
+        player.revive_progress = 0;
+        player.eliminated_by = None;
+        player.health = player.max_health;
+        player.invulnerable_ticks = invulnerable_ticks;
+        self.alive_count += 1;
+    }
+
+    /// Send a player eliminated this tick to await a respawn instead.
+    ///
+    /// The placement handed out on elimination is taken back and the death
+    /// is counted. Returns the new death count, or None if the player was
+    /// not eliminated this tick.
+    pub fn schedule_respawn(&mut self, player_id: &PlayerId, respawn_tick: u32) -> Option<u32> {
+        let tick = self.tick;
+        let player = self.players.get_mut(player_id)?;
+        if player.alive || player.eliminated_tick != Some(tick) || player.respawn_tick.is_some() {
+            return None;
+        }
+
+        player.placement = None;
+        player.respawn_tick = Some(respawn_tick);
+        player.deaths += 1;
+        let deaths = player.deaths;
+        self.next_placement = self.next_placement.saturating_sub(1);
+        Some(deaths)
+    }
+
+    /// Bring a player awaiting respawn back into play at `position`.
+    ///
+    /// Health is restored and `score_penalty` points are docked; the player
+    /// drops to the highest tier the remaining score still reaches.
+    pub fn respawn_player(
+        &mut self,
+        player_id: &PlayerId,
+        position: FixedVec2,
+        invulnerable_ticks: u32,
+        score_penalty: u32,
+    ) {
+        let Some(player) = self.players.get_mut(player_id) else {
+            return;
+        };
+        if player.respawn_tick.is_none() {
+            return;
+        }
+
+        player.alive = true;
+        player.respawn_tick = None;
+        player.eliminated_tick = None;
+        player.eliminated_by = None;
+        player.position = position;
+        player.velocity = FixedVec2::ZERO;
+        player.health = player.max_health;
+        player.invulnerable_ticks = invulnerable_ticks;
+        player.ticks_outside_zone = 0;
+        player.score = player.score.saturating_sub(score_penalty);
+        while player.tier > 0 && player.score < self.form_table.tier(player.tier).entry_score {
+            let tier = player.tier - 1;
+            player.set_tier(tier, &self.form_table);
+        }
+        self.alive_count += 1;
+    }
+
+    /// Turn a downed player into a permanent elimination.
+    ///
+    /// Placement and kill credit are assigned now, as if eliminated this tick.
+    /// Returns the placement, or None if the player was not downed.
+    pub fn confirm_downed_elimination(&mut self, player_id: &PlayerId) -> Option<Placement> {
+        // Only downed players can be confirmed
+        self.players.get(player_id)?.downed_tick?;
+
+        let placement = self.take_placement();
+        let tick = self.tick;
+        let killer = {
+            let player = self.players.get_mut(player_id)?;
+            player.downed_tick = None;
+            player.revive_progress = 0;
+            player.eliminated_tick = Some(tick);
+            player.placement = Some(placement);
+            player.eliminated_by
+        };
+
+        if let Some(kid) = killer {
+            if let Some(killer) = self.players.get_mut(&kid) {
+                killer.kills += 1;
+                killer.add_score(crate::core::fixed::SCORE_PER_KILL, &self.form_table);
+            }
+        }
+
+        Some(placement)
     }
 
     /// Get final placements (sorted by placement).
-    pub fn get_placements(&self) -> Vec<(PlayerId, u8, u32)> {
+    pub fn get_placements(&self) -> Vec<(PlayerId, Placement, u32)> {
         let mut results: Vec<_> = self.players.values()
             .map(|p| (p.id, p.placement.unwrap_or(0), p.score))
             .collect();
@@ -784,11 +1383,13 @@ impl MatchState {
                 hasher.update_vec2(rune.position);
                 hasher.update_u8(rune.rune_type as u8);
                 hasher.update_bool(rune.collected);
+                hasher.update_u32(rune.spawn_tick);
             }
 
             // Hash shrine states
             for shrine in &self.shrines {
                 hasher.update_u8(shrine.id);
+                hasher.update_u8(shrine.shrine_type as u8);
                 hasher.update_bool(shrine.active);
                 hasher.update_fixed(shrine.channel_progress);
                 hasher.update_fixed(shrine.cooldown);
@@ -799,14 +1400,118 @@ impl MatchState {
                 hasher.update_u8(ability.ability_type as u8);
                 hasher.update_vec2(ability.position);
                 hasher.update_u32(ability.remaining_ticks);
+                hasher.update_u32(ability.triggered.len() as u32);
+                for player_id in &ability.triggered {
+                    hasher.update_uuid(&player_id.0);
+                }
+                hasher.update_bool(ability.target_player.is_some());
+                if let Some(target) = ability.target_player {
+                    hasher.update_uuid(&target.0);
+                }
+            }
+
+            // Hash delayed inputs still in flight
+            for (player_id, queue) in &self.input_delay_queue {
+                hasher.update_uuid(&player_id.0);
+                for (apply_tick, frame) in queue {
+                    hasher.update_u32(*apply_tick);
+                    hasher.update_u8(frame.move_x as u8);
+                    hasher.update_u8(frame.move_y as u8);
+                    hasher.update_u8(frame.flags);
+                }
             }
 
+            // Hash rune economy counters
+            for (spawned, collected) in self.runes_spawned_by_type.iter().zip(&self.runes_collected_by_type) {
+                hasher.update_u32(*spawned);
+                hasher.update_u32(*collected);
+            }
+
+            // Hash milestone flags
+            hasher.update_bool(self.first_blood_claimed);
+            hasher.update_bool(self.first_evolution_claimed);
+            hasher.update_bool(self.first_shrine_claimed);
+
+            // Hash the form ladder
+            self.form_table.hash_into(hasher);
+
+            // Hash the boost pad layout
+            self.map.hash_boost_pads(hasher);
+
+            // Hash survivor enemies and waves
+            hash_enemies(self, hasher);
+
             // Hash arena state
             hasher.update_fixed(self.arena_shrink);
+            hasher.update_fixed(self.arena_half_width);
+            hasher.update_fixed(self.arena_half_height);
+            hasher.update_u32(self.sudden_death_ticks);
             hasher.update_u32(self.alive_count);
+            hasher.update_u32(self.start_tick);
+
+            // Hash bytes left over from the last fill_bytes draw
+            self.rng.hash_spare_into(hasher);
         })
     }
 
+    /// Ticks simulated since the match clock started.
+    pub fn elapsed_ticks(&self) -> u32 {
+        self.tick.saturating_sub(self.start_tick)
+    }
+
+    /// Current standings as `(player, score, kills)`.
+    ///
+    /// Canonical ordering used for the HUD, spectators and final
+    /// placements: score descending, then kills descending, then lower
+    /// player ID first.
+    pub fn leaderboard(&self) -> Vec<(PlayerId, u32, u32)> {
+        let mut standings: Vec<(PlayerId, u32, u32)> = self
+            .players
+            .iter()
+            .map(|(id, p)| (*id, p.score, p.kills))
+            .collect();
+
+        standings.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then_with(|| b.2.cmp(&a.2))
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        standings
+    }
+
+    /// Winner as reported at match end: the leaderboard leader, if they scored.
+    pub fn result_winner(&self) -> Option<PlayerId> {
+        self.leaderboard().first()
+            .filter(|(_, score, _)| *score > 0)
+            .map(|(id, _, _)| *id)
+    }
+
+    /// Compute a compact commitment to the match outcome.
+    ///
+    /// Covers only the match ID, end tick, winner and leaderboard (player,
+    /// score, kills in placement order), so light clients can check a
+    /// result without the full final state behind `compute_hash`.
+    pub fn compute_result_hash(&self) -> StateHash {
+        let mut hasher = StateHasher::for_match_result();
+        hasher.update_uuid(&self.match_id);
+        hasher.update_u32(self.tick);
+
+        let winner = self.result_winner();
+        hasher.update_bool(winner.is_some());
+        if let Some(winner) = winner {
+            hasher.update_uuid(&winner.0);
+        }
+
+        let standings = self.leaderboard();
+        hasher.update_u32(standings.len() as u32);
+        for (id, score, kills) in standings {
+            hasher.update_uuid(&id.0);
+            hasher.update_u32(score);
+            hasher.update_u32(kills);
+        }
+        hasher.finalize()
+    }
+
     /// Take pending events (consumes them).
     pub fn take_events(&mut self) -> Vec<GameEvent> {
         std::mem::take(&mut self.pending_events)
@@ -844,6 +1549,9 @@ mod tests {
         assert_eq!(Form::Ward.next(), Some(Form::Arcane));
         assert_eq!(Form::Arcane.next(), Some(Form::Ancient));
         assert_eq!(Form::Ancient.next(), None);
+
+        assert_eq!(Form::Spark.prev(), None);
+        assert_eq!(Form::Ancient.prev(), Some(Form::Arcane));
     }
 
     #[test]
@@ -858,18 +1566,19 @@ mod tests {
     fn test_player_evolution() {
         let id = PlayerId::new([0; 16]);
         let mut player = PlayerState::new(id, FixedVec2::ZERO);
+        let table = FormTable::default();
 
         assert_eq!(player.form, Form::Spark);
-        assert!(!player.can_evolve());
+        assert!(!player.can_evolve(&table));
 
         // Add score to trigger evolution
         player.score = 100;
-        assert!(player.can_evolve());
-        assert!(player.try_evolve());
+        assert!(player.can_evolve(&table));
+        assert!(player.try_evolve(&table));
         assert_eq!(player.form, Form::Glyph);
 
         // Can't evolve again without more score
-        assert!(!player.can_evolve());
+        assert!(!player.can_evolve(&table));
     }
 
     #[test]
@@ -900,6 +1609,84 @@ mod tests {
         assert_eq!(state1.compute_hash(), state2.compute_hash());
     }
 
+    #[test]
+    fn test_sequential_eliminations_place_descending() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let ids: Vec<PlayerId> = (1..=5).map(|i| PlayerId::new([i; 16])).collect();
+        for id in &ids {
+            state.add_player(*id);
+        }
+        state.alive_count = 5;
+
+        assert_eq!(state.eliminate_player(&ids[0], None), Some(5));
+        // Already out: no placement consumed
+        assert_eq!(state.eliminate_player(&ids[0], None), None);
+        assert_eq!(state.eliminate_player(&ids[1], Some(&ids[4])), Some(4));
+
+        // A downed player is placed when the elimination is confirmed
+        state.down_player(&ids[2], Some(&ids[4]));
+        assert_eq!(state.eliminate_player(&ids[3], None), Some(3));
+        assert_eq!(state.confirm_downed_elimination(&ids[2]), Some(2));
+        assert_eq!(state.confirm_downed_elimination(&ids[2]), None);
+
+        let placements: Vec<Option<Placement>> = ids.iter().map(|id| state.players[id].placement).collect();
+        assert_eq!(placements, vec![Some(5), Some(4), Some(2), Some(3), None]);
+        assert_eq!(state.alive_count, 1);
+        assert_eq!(state.players[&ids[4]].kills, 2);
+    }
+
+    #[test]
+    fn test_large_lobby_placements_do_not_wrap() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let ids: Vec<PlayerId> = (0..300u32).map(|i| {
+            let mut bytes = [0; 16];
+            bytes[..4].copy_from_slice(&i.to_be_bytes());
+            PlayerId::new(bytes)
+        }).collect();
+        for id in &ids {
+            state.add_player(*id);
+        }
+        state.alive_count = 300;
+
+        // Everyone but the last player falls, last place first
+        for (i, id) in ids.iter().take(299).enumerate() {
+            assert_eq!(state.eliminate_player(id, None), Some(300 - i as Placement));
+        }
+        assert_eq!(state.players[&ids[0]].placement, Some(300));
+        assert_eq!(state.players[&ids[44]].placement, Some(256));
+        assert_eq!(state.players[&ids[298]].placement, Some(2));
+        assert_eq!(state.alive_count, 1);
+
+        let placements = state.get_placements();
+        assert_eq!(placements.last().map(|p| p.1), Some(300));
+    }
+
+    #[test]
+    fn test_result_hash_ignores_positions() {
+        let finished = |x: i32| {
+            let mut state = MatchState::new([7; 16], 12345);
+            for i in 1..=3 {
+                let id = PlayerId::new([i; 16]);
+                let mut player = PlayerState::new(id, FixedVec2::from_ints(x * i as i32, 0));
+                player.score = 100 * i as u32;
+                state.players.insert(id, player);
+            }
+            state.tick = 600;
+            state
+        };
+
+        let a = finished(1);
+        let b = finished(-5);
+        assert_eq!(a.result_winner(), Some(PlayerId::new([3; 16])));
+        assert_eq!(a.compute_result_hash(), b.compute_result_hash());
+        assert_ne!(a.compute_hash(), b.compute_hash());
+
+        // A different outcome changes the result hash
+        let mut c = finished(1);
+        c.players.get_mut(&PlayerId::new([1; 16])).unwrap().score = 1000;
+        assert_ne!(a.compute_result_hash(), c.compute_result_hash());
+    }
+
     #[test]
     fn test_btreemap_iteration_order() {
         let mut state = MatchState::new([0; 16], 12345);
@@ -923,4 +1710,29 @@ mod tests {
 
         assert_eq!(iterated, sorted, "BTreeMap should iterate in sorted order");
     }
+
+    #[test]
+    fn test_spawn_positions_are_spread_out() {
+        // 10 units apart at minimum (well beyond any form's diameter)
+        let min_separation_sq = 100 * FIXED_ONE as i64;
+
+        for seed in 0..20u64 {
+            let mut state = MatchState::new([0; 16], seed);
+            for i in 0..8 {
+                state.add_player(PlayerId::new([i; 16]));
+            }
+            state.assign_spawn_positions();
+
+            let positions: Vec<FixedVec2> = state.players.values().map(|p| p.position).collect();
+            for i in 0..positions.len() {
+                for j in (i + 1)..positions.len() {
+                    let dist_sq = positions[i].distance_squared_wide(positions[j]);
+                    assert!(
+                        dist_sq >= min_separation_sq,
+                        "seed {}: players {} and {} spawned too close", seed, i, j
+                    );
+                }
+            }
+        }
+    }
 }
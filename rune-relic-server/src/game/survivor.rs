@@ -0,0 +1,496 @@
+//! Survivor (PvE) Waves
+//!
+//! Server-side version of the client's wave survivor game. Enemies spawn
+//! in timed waves around living players, chase the nearest one, and are
+//! resolved by size on contact: a player at least as large as the enemy
+//! destroys it for its essence value, anything smaller is eliminated.
+//!
+//! Each enemy draws its type and spawn point from an RNG sub-stream keyed
+//! by its ID, so enabling survivor mode never shifts the main match RNG.
+
+use serde::{Serialize, Deserialize};
+
+use crate::core::fixed::{Fixed, TICK_DURATION, fixed_mul};
+use crate::core::hash::StateHasher;
+use crate::core::rng::DeterministicRng;
+use crate::core::vec2::FixedVec2;
+use crate::game::collision::circles_overlap;
+use crate::game::events::GameEvent;
+use crate::game::state::{MatchState, PlayerId};
+
+/// Sub-stream for enemy rolls ("SURVIVOR" in ASCII)
+const SURVIVOR_STREAM: u64 = 0x53555256_49564F52;
+
+/// Attempts at finding walkable ground for a spawn before falling back
+const SPAWN_ATTEMPTS: u32 = 8;
+
+/// Fraction of the gap to the desired velocity closed per tick.
+///
+/// Slower than player turning so enemies swing wide (4.0/s at 60 Hz).
+const ENEMY_TURN_RATE: Fixed = 4369; // (4.0 / 60) * 65536
+
+/// Enemy kinds, matching the client's `EnemyType`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum EnemyType {
+    /// Fast chaser
+    Shard = 0,
+    /// Basic enemy
+    Orb = 1,
+    /// Slow and large
+    Spike = 2,
+    /// Medium flanker
+    Prism = 3,
+    /// Boss (wave 10)
+    Monolith = 4,
+}
+
+impl EnemyType {
+    /// Chase speed (units per second)
+    pub fn speed(self) -> Fixed {
+        match self {
+            EnemyType::Shard => 314572,    // 4.8 * 65536
+            EnemyType::Orb => 235929,      // 3.6 * 65536
+            EnemyType::Spike => 157286,    // 2.4 * 65536
+            EnemyType::Prism => 262144,    // 4.0 * 65536
+            EnemyType::Monolith => 131072, // 2.0 * 65536
+        }
+    }
+
+    /// Collision radius
+    pub fn radius(self) -> Fixed {
+        match self {
+            EnemyType::Shard => 32768,     // 0.5 * 65536
+            EnemyType::Orb => 45875,       // 0.7 * 65536
+            EnemyType::Spike => 58982,     // 0.9 * 65536
+            EnemyType::Prism => 52428,     // 0.8 * 65536
+            EnemyType::Monolith => 98304,  // 1.5 * 65536
+        }
+    }
+
+    /// Score awarded for destroying the enemy
+    pub fn essence_value(self) -> u32 {
+        match self {
+            EnemyType::Shard => 2,
+            EnemyType::Orb => 4,
+            EnemyType::Spike => 8,
+            EnemyType::Prism => 6,
+            EnemyType::Monolith => 30,
+        }
+    }
+}
+
+/// Enemy composition of a wave as `(type, count)`, in spawn-roll order.
+///
+/// Same table as the client so both sides field the same waves.
+pub fn wave_composition(wave: u32) -> Vec<(EnemyType, u32)> {
+    match wave {
+        0 => Vec::new(),
+        1 => vec![(EnemyType::Shard, 5)],
+        2 => vec![(EnemyType::Shard, 6), (EnemyType::Orb, 2)],
+        3 => vec![(EnemyType::Shard, 8), (EnemyType::Orb, 3)],
+        4 => vec![(EnemyType::Shard, 8), (EnemyType::Orb, 4), (EnemyType::Spike, 1)],
+        5 => vec![
+            (EnemyType::Shard, 10),
+            (EnemyType::Orb, 5),
+            (EnemyType::Spike, 2),
+            (EnemyType::Prism, 1),
+        ],
+        6..=9 => vec![
+            (EnemyType::Shard, 8 + wave / 2),
+            (EnemyType::Orb, 4 + wave / 3),
+            (EnemyType::Spike, 1 + wave / 4),
+            (EnemyType::Prism, wave / 3),
+        ],
+        10 => vec![
+            (EnemyType::Shard, 12),
+            (EnemyType::Orb, 8),
+            (EnemyType::Spike, 4),
+            (EnemyType::Prism, 3),
+            (EnemyType::Monolith, 1),
+        ],
+        _ => vec![
+            (EnemyType::Shard, 10 + wave / 2),
+            (EnemyType::Orb, 6 + wave / 3),
+            (EnemyType::Spike, 3 + wave / 4),
+            (EnemyType::Prism, 2 + wave / 5),
+        ],
+    }
+}
+
+/// Configuration for survivor waves.
+#[derive(Serialize, Deserialize)]
+pub struct SurvivorConfig {
+    /// Run waves this match (set for `MatchMode::Survivor`)
+    pub enabled: bool,
+    /// Ticks between wave starts
+    pub wave_duration_ticks: u32,
+    /// Ticks between spawns in wave 1
+    pub base_spawn_interval_ticks: u32,
+    /// Spawn interval shortening per wave
+    pub spawn_interval_step_ticks: u32,
+    /// Shortest spawn interval
+    pub min_spawn_interval_ticks: u32,
+    /// Cap on living enemies; spawning pauses at the cap
+    pub max_enemies: u32,
+    /// Minimum spawn distance from the targeted player
+    pub spawn_distance: Fixed,
+    /// Extra random spawn distance on top of `spawn_distance`
+    pub spawn_distance_jitter: Fixed,
+}
+
+impl Default for SurvivorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            wave_duration_ticks: 1800,       // 30 seconds at 60 Hz
+            base_spawn_interval_ticks: 120,  // 2 seconds
+            spawn_interval_step_ticks: 6,    // 0.1 seconds
+            min_spawn_interval_ticks: 48,    // 0.8 seconds
+            max_enemies: 30,
+            spawn_distance: 1310720,         // 20.0 * 65536
+            spawn_distance_jitter: 393216,   // 6.0 * 65536
+        }
+    }
+}
+
+impl SurvivorConfig {
+    /// Ticks between spawns during `wave`.
+    pub fn spawn_interval(&self, wave: u32) -> u32 {
+        self.base_spawn_interval_ticks
+            .saturating_sub(self.spawn_interval_step_ticks.saturating_mul(wave))
+            .max(self.min_spawn_interval_ticks)
+            .max(1)
+    }
+}
+
+/// A live enemy.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EnemyState {
+    /// Enemy identifier (monotonic)
+    pub id: u32,
+    /// Enemy kind
+    pub enemy_type: EnemyType,
+    /// Current position
+    pub position: FixedVec2,
+    /// Current velocity (units per second)
+    pub velocity: FixedVec2,
+    /// Wave the enemy spawned in
+    pub wave: u32,
+}
+
+/// Wave progression.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct WaveState {
+    /// Current wave (0 before the first wave starts)
+    pub wave: u32,
+    /// Tick the current wave started
+    pub wave_start_tick: u32,
+    /// Enemies in the current wave's composition
+    pub enemies_this_wave: u32,
+    /// Enemies of the current wave spawned so far
+    pub enemies_spawned: u32,
+    /// Earliest tick of the next spawn
+    pub next_spawn_tick: u32,
+    /// Next enemy ID
+    pub next_enemy_id: u32,
+}
+
+impl WaveState {
+    /// Hash wave progression for verification.
+    pub fn hash_into(&self, hasher: &mut StateHasher) {
+        hasher.update_u32(self.wave);
+        hasher.update_u32(self.wave_start_tick);
+        hasher.update_u32(self.enemies_this_wave);
+        hasher.update_u32(self.enemies_spawned);
+        hasher.update_u32(self.next_spawn_tick);
+        hasher.update_u32(self.next_enemy_id);
+    }
+}
+
+/// Advance waves, spawn, move enemies and resolve contacts.
+pub fn process_survivor(state: &mut MatchState, config: &SurvivorConfig) {
+    if !config.enabled {
+        return;
+    }
+
+    advance_wave(state, config);
+    spawn_enemies(state, config);
+    move_enemies(state);
+    resolve_contacts(state);
+}
+
+/// Start the next wave when the current one has run its course.
+fn advance_wave(state: &mut MatchState, config: &SurvivorConfig) {
+    let waves = &mut state.waves;
+    let due = waves.wave == 0
+        || state.tick.saturating_sub(waves.wave_start_tick) >= config.wave_duration_ticks;
+    if !due {
+        return;
+    }
+
+    waves.wave += 1;
+    waves.wave_start_tick = state.tick;
+    waves.enemies_spawned = 0;
+    waves.enemies_this_wave = wave_composition(waves.wave).iter().map(|(_, count)| count).sum();
+    waves.next_spawn_tick = state.tick;
+
+    let event = GameEvent::wave_started(state.tick, waves.wave, waves.enemies_this_wave);
+    state.push_event(event);
+}
+
+/// Spawn at most one enemy this tick.
+fn spawn_enemies(state: &mut MatchState, config: &SurvivorConfig) {
+    let waves = &state.waves;
+    if state.tick < waves.next_spawn_tick
+        || waves.enemies_spawned >= waves.enemies_this_wave
+        || state.enemies.len() as u32 >= config.max_enemies
+    {
+        return;
+    }
+
+    let living: Vec<FixedVec2> = state.players.values()
+        .filter(|p| p.alive)
+        .map(|p| p.position)
+        .collect();
+    if living.is_empty() {
+        return;
+    }
+
+    let id = waves.next_enemy_id;
+    let wave = waves.wave;
+    let mut rng = DeterministicRng::substream(state.rng_seed, SURVIVOR_STREAM, id as u64);
+
+    let enemy_type = pick_enemy_type(wave, &mut rng);
+    let anchor = living[rng.next_int(living.len() as u32) as usize];
+    let radius = enemy_type.radius();
+    let position = (0..SPAWN_ATTEMPTS)
+        .map(|_| {
+            let distance = config.spawn_distance + rng.next_fixed(config.spawn_distance_jitter);
+            anchor.add(rng.random_direction().scale(distance))
+        })
+        .find(|pos| state.map.contains_player_position(*pos, radius, None, false))
+        .unwrap_or_else(|| state.map.random_point_in_corridor(&mut rng));
+
+    state.enemies.insert(id, EnemyState {
+        id,
+        enemy_type,
+        position,
+        velocity: FixedVec2::ZERO,
+        wave,
+    });
+
+    let waves = &mut state.waves;
+    waves.next_enemy_id += 1;
+    waves.enemies_spawned += 1;
+    waves.next_spawn_tick = state.tick + config.spawn_interval(wave);
+}
+
+/// Roll an enemy type weighted by the wave composition.
+fn pick_enemy_type(wave: u32, rng: &mut DeterministicRng) -> EnemyType {
+    let composition = wave_composition(wave);
+    let total: u32 = composition.iter().map(|(_, count)| count).sum();
+    if total == 0 {
+        return EnemyType::Shard;
+    }
+
+    let mut roll = rng.next_int(total);
+    for (enemy_type, count) in composition {
+        if roll < count {
+            return enemy_type;
+        }
+        roll -= count;
+    }
+    EnemyType::Shard
+}
+
+/// Steer every enemy toward the nearest living player and integrate.
+fn move_enemies(state: &mut MatchState) {
+    let (half_width, half_height) = state.current_arena_bounds();
+
+    for enemy in state.enemies.values_mut() {
+        // Nearest living player; ties go to the lower ID (sorted iteration)
+        let target = state.players.values()
+            .filter(|p| p.alive)
+            .min_by_key(|p| p.position.distance_squared_wide(enemy.position))
+            .map(|p| p.position);
+
+        let desired = match target {
+            Some(target) if target != enemy.position => {
+                target.sub(enemy.position).normalize().scale(enemy.enemy_type.speed())
+            }
+            _ => FixedVec2::ZERO,
+        };
+        enemy.velocity = enemy.velocity.lerp(desired, ENEMY_TURN_RATE);
+
+        let step = FixedVec2::new(
+            fixed_mul(enemy.velocity.x, TICK_DURATION),
+            fixed_mul(enemy.velocity.y, TICK_DURATION),
+        );
+        let moved = enemy.position.add(step);
+        enemy.position = FixedVec2::new(
+            moved.x.clamp(-half_width, half_width),
+            moved.y.clamp(-half_height, half_height),
+        );
+    }
+}
+
+/// Resolve enemy-player contacts in enemy ID order.
+///
+/// Each enemy meets the lowest-ID living player it overlaps. Invulnerable
+/// players pass through enemies; shielded players always win.
+fn resolve_contacts(state: &mut MatchState) {
+    let enemy_ids: Vec<u32> = state.enemies.keys().copied().collect();
+
+    for enemy_id in enemy_ids {
+        let Some(enemy) = state.enemies.get(&enemy_id) else {
+            continue;
+        };
+        let enemy_type = enemy.enemy_type;
+        let enemy_pos = enemy.position;
+
+        let contact = state.players.values()
+            .filter(|p| p.alive && p.invulnerable_ticks == 0)
+            .find(|p| circles_overlap(p.position, p.radius(&state.form_table), enemy_pos, enemy_type.radius()))
+            .map(|p| {
+                let wins = p.radius(&state.form_table) >= enemy_type.radius()
+                    || p.shield_buff_ticks > 0
+                    || p.has_shrine_buff(crate::game::state::ShrineType::Shield);
+                (p.id, wins)
+            });
+
+        let Some((player_id, player_wins)) = contact else {
+            continue;
+        };
+
+        if player_wins {
+            state.enemies.remove(&enemy_id);
+            defeat_enemy(state, player_id, enemy_id, enemy_type);
+        } else if let Some(placement) = state.eliminate_player(&player_id, None) {
+            state.push_event(GameEvent::player_eliminated(state.tick, player_id, None, placement));
+        }
+    }
+}
+
+/// Award essence for a destroyed enemy.
+fn defeat_enemy(state: &mut MatchState, player_id: PlayerId, enemy_id: u32, enemy_type: EnemyType) {
+    let Some(player) = state.players.get_mut(&player_id) else {
+        return;
+    };
+
+    let points = enemy_type.essence_value();
+    let old_form = player.form;
+    let evolved = player.add_score(points, &state.form_table);
+    let new_form = player.form;
+
+    state.push_event(GameEvent::enemy_defeated(state.tick, player_id, enemy_id, enemy_type, points));
+    if evolved {
+        state.push_event(GameEvent::form_evolved(state.tick, player_id, old_form, new_form));
+    }
+}
+
+/// Hash living enemies in ID order.
+pub fn hash_enemies(state: &MatchState, hasher: &mut StateHasher) {
+    for enemy in state.enemies.values() {
+        hasher.update_u32(enemy.id);
+        hasher.update_u8(enemy.enemy_type as u8);
+        hasher.update_vec2(enemy.position);
+        hasher.update_vec2(enemy.velocity);
+        hasher.update_u32(enemy.wave);
+    }
+    state.waves.hash_into(hasher);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixed::FIXED_ONE;
+    use crate::core::hash::StateHash;
+    use crate::game::state::PlayerState;
+
+    fn survivor_match(seed: u64) -> MatchState {
+        let mut state = MatchState::new([7; 16], seed);
+        let id = PlayerId::new([1; 16]);
+        state.players.insert(id, PlayerState::new(id, FixedVec2::ZERO));
+        state.alive_count = 1;
+        state
+    }
+
+    fn run(seed: u64, ticks: u32, config: &SurvivorConfig) -> (MatchState, Vec<StateHash>) {
+        let mut state = survivor_match(seed);
+        let mut hashes = Vec::new();
+        for _ in 0..ticks {
+            state.tick += 1;
+            process_survivor(&mut state, config);
+            state.take_events();
+            hashes.push(state.compute_hash());
+        }
+        (state, hashes)
+    }
+
+    #[test]
+    fn test_seeded_survivor_waves_replay_identically() {
+        let config = SurvivorConfig {
+            enabled: true,
+            wave_duration_ticks: 300,
+            base_spawn_interval_ticks: 20,
+            ..Default::default()
+        };
+
+        let (first, first_hashes) = run(42, 700, &config);
+        let (replay, replay_hashes) = run(42, 700, &config);
+
+        // Three waves in, with enemies on the field
+        assert_eq!(first.waves.wave, 3);
+        assert!(first.waves.next_enemy_id > 5);
+        assert_eq!(first_hashes, replay_hashes);
+        assert_eq!(first.enemies.len(), replay.enemies.len());
+        for (a, b) in first.enemies.values().zip(replay.enemies.values()) {
+            assert_eq!(a.id, b.id);
+            assert_eq!(a.enemy_type, b.enemy_type);
+            assert_eq!(a.position, b.position);
+        }
+
+        // Enemy rolls come from a sub-stream: the main RNG is untouched
+        assert_eq!(first.rng.state(), survivor_match(42).rng.state());
+
+        // A different seed fields a different wave
+        let (other, _) = run(43, 700, &config);
+        let positions = |s: &MatchState| s.enemies.values().map(|e| e.position).collect::<Vec<_>>();
+        assert_ne!(positions(&first), positions(&other));
+    }
+
+    #[test]
+    fn test_enemy_contact_resolves_by_size() {
+        let config = SurvivorConfig { enabled: true, ..Default::default() };
+        let mut state = survivor_match(1);
+        let id = PlayerId::new([1; 16]);
+        state.waves.wave = 1;
+        state.waves.wave_start_tick = 0;
+
+        // A Spark can swallow a Shard...
+        state.enemies.insert(0, EnemyState {
+            id: 0,
+            enemy_type: EnemyType::Shard,
+            position: FixedVec2::new(FIXED_ONE / 4, 0),
+            velocity: FixedVec2::ZERO,
+            wave: 1,
+        });
+        state.tick = 1;
+        resolve_contacts(&mut state);
+        assert!(state.enemies.is_empty());
+        assert_eq!(state.players[&id].score, EnemyType::Shard.essence_value());
+
+        // ...but not an Orb
+        state.enemies.insert(1, EnemyState {
+            id: 1,
+            enemy_type: EnemyType::Orb,
+            position: FixedVec2::new(FIXED_ONE / 4, 0),
+            velocity: FixedVec2::ZERO,
+            wave: 1,
+        });
+        process_survivor(&mut state, &config);
+        assert!(!state.players[&id].alive);
+        assert!(state.enemies.contains_key(&1));
+    }
+}
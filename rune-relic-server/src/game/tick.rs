@@ -4,23 +4,38 @@
 //! This is what BitSage will verify.
 
 use std::collections::BTreeMap;
+use serde::{Serialize, Deserialize};
+use serde_json::{Map, Value};
 
 use crate::core::fixed::{
     Fixed, FIXED_ONE,
-    fixed_mul, JUMP_VELOCITY,
+    fixed_mul, JUMP_VELOCITY, TICK_DURATION,
+    ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT,
 };
+use crate::core::hash::{hash_with_domain, StateHash};
 use crate::core::vec2::FixedVec2;
 use crate::MATCH_DURATION_TICKS;
 use crate::game::input::InputFrame;
-use crate::game::state::{MatchState, MatchPhase, PlayerId};
+use crate::game::state::{MatchState, MatchPhase, PlayerId, AbilityType};
 use crate::game::collision::{
     check_all_player_collisions,
     check_all_rune_collisions,
 };
-use crate::game::rune::{maybe_spawn_runes, collect_rune, RuneSpawnConfig};
-use crate::game::shrine::{ShrineConfig, process_shrines, spawn_shrines};
-use crate::game::ability::{activate_ability, process_active_abilities};
+use crate::game::rune::{
+    maybe_spawn_runes, maybe_rune_rain, maybe_chain_rune, collect_rune, RuneSpawnConfig,
+};
+use crate::game::shrine::{ShrineConfig, process_shrines, spawn_shrines, get_shield_multiplier};
+use crate::game::ability::{
+    AbilityConfig, activate_ability, configured_ability, process_active_abilities, regen_energy,
+    decay_overcharge,
+};
+use crate::game::milestone::process_milestones;
+use crate::game::revive::{ReviveConfig, can_be_downed, process_revives};
+use crate::game::respawn::{RespawnConfig, pending_respawns, process_respawns};
+use crate::game::survivor::{SurvivorConfig, process_survivor};
 use crate::game::events::GameEvent;
+use crate::game::form_table::FormTable;
+use crate::game::map::{ArcaneCircuitMap, BoostPad};
 
 /// Result of a tick.
 #[derive(Debug)]
@@ -32,37 +47,271 @@ pub struct TickResult {
     pub match_ended: bool,
     /// Winner (if match ended with winner)
     pub winner: Option<PlayerId>,
+    /// Main RNG draws per subsystem this tick (debug instrumentation)
+    #[cfg(feature = "rng-audit")]
+    pub rng_audit: Vec<(&'static str, u32)>,
 }
 
 
 /// Configuration for match simulation.
+///
+/// [`MatchConfig::config_bytes`] encodes only what differs from the v1
+/// rules, so adding a field does not change the encoding of any config
+/// that leaves it at its v1 value.
+#[derive(Serialize, Deserialize)]
 pub struct MatchConfig {
     /// Rune spawn configuration
     pub rune_spawn: RuneSpawnConfig,
     /// Shrine configuration
     pub shrine: ShrineConfig,
+    /// Ability configuration
+    pub ability: AbilityConfig,
+    /// Team revive configuration
+    pub revive: ReviveConfig,
+    /// Respawn configuration
+    pub respawn: RespawnConfig,
+    /// Survivor (PvE wave) configuration
+    pub survivor: SurvivorConfig,
+    /// Unshrunk arena half-width (see [`MatchConfig::with_arena_for_players`])
+    pub arena_half_width: Fixed,
+    /// Unshrunk arena half-height
+    pub arena_half_height: Fixed,
+    /// Evolution ladder, installed by [`prepare_match`] before players join
+    pub form_table: FormTable,
+    /// Collision radius multiplier at zero health (FIXED_ONE = flat radius).
+    ///
+    /// Below FIXED_ONE wounded players shrink and are harder to hit; above
+    /// it they swell and are easier to catch.
+    pub damaged_radius_scale: Fixed,
     /// Ticks before arena starts shrinking
     pub shrink_start_tick: u32,
     /// Rate of arena shrink per tick (Fixed)
     pub shrink_rate: Fixed,
     /// Damage per tick when outside zone
     pub zone_damage_rate: Fixed,
+    /// Zone damage multiplier added per consecutive tick outside (0 = flat).
+    ///
+    /// The first tick outside deals `zone_damage_rate`; each further tick
+    /// adds this much to the multiplier, so lingering at the edge gets
+    /// expensive. Re-entering the zone resets the ramp.
+    pub zone_damage_ramp: Fixed,
+    /// Cap on the zone damage multiplier
+    pub zone_damage_ramp_max: Fixed,
+    /// Inward velocity applied to players outside the zone (0 = disabled).
+    ///
+    /// Pushes stragglers back toward safety instead of (or alongside)
+    /// damaging them.
+    pub zone_push_strength: Fixed,
+    /// Sudden-death damage added per tick spent at full shrink.
+    ///
+    /// Once the arena is fully closed every living player takes
+    /// `step * ticks_at_full_shrink` damage per tick, so the match resolves
+    /// instead of waiting out the timer. 0 disables sudden death.
+    pub sudden_death_damage_step: Fixed,
+    /// Ticks between receiving an input and applying it (0 = immediate).
+    ///
+    /// Gives every player the same effective latency regardless of ping.
+    pub input_delay_ticks: u32,
+    /// Lethal damage de-evolves the player one form instead of eliminating.
+    ///
+    /// Only players already at the lowest form (Spark) are eliminated.
+    pub evolve_down_on_damage: bool,
+    /// Award the one-off first blood, first evolution and first shrine
+    /// bonuses (see [`process_milestones`]).
+    pub milestones_enabled: bool,
+    /// Move players by their queued dash after this tick's eliminations
+    /// instead of during physics.
+    ///
+    /// A player eliminated the tick they dash then never moves from it;
+    /// walls and other players see the dashed position next tick.
+    pub dash_after_collisions: bool,
+    /// Allow the jump input (adds `JUMP_VELOCITY` to `velocity.y`).
+    ///
+    /// Off by default: in the top-down arena a jump is just an unexplained
+    /// vertical boost. Jumps share a 30-tick cooldown (`last_jump_tick`).
+    pub jump_enabled: bool,
+    /// Rate input steers velocity toward the input direction (units/s²).
+    ///
+    /// 0 keeps the classic arcade feel: velocity snaps to the input every
+    /// tick and stops dead on release. Above 0 players have momentum: they
+    /// ramp up to form speed, and on release coast to a stop under the
+    /// ~0.95 per-tick friction (about a second to settle). Should be at
+    /// least 5% of form speed per tick so friction cannot cap top speed.
+    pub movement_acceleration: Fixed,
+    /// Launch players who step onto the map's boost pads.
+    ///
+    /// Has no effect without `boost_pads` (the stock layout has none).
+    pub boost_pads_enabled: bool,
+    /// Boost pad layout, placed on the map by [`prepare_match`]
+    pub boost_pads: Vec<BoostPad>,
 }
 
-impl Default for MatchConfig {
-    fn default() -> Self {
+/// Version of the rules encoded by `MatchConfig::default()`.
+///
+/// Bump this whenever a default changes in a way that affects simulation,
+/// and add the previous parameters to [`MatchConfig::for_version`] so older
+/// transcripts keep verifying.
+pub const MATCH_CONFIG_VERSION: u32 = 3;
+
+/// Lobby size the default arena is tuned for.
+pub const ARENA_BASE_PLAYERS: usize = 8;
+
+/// Arena half-extent added per player above `ARENA_BASE_PLAYERS`.
+pub const ARENA_GROWTH_PER_PLAYER: Fixed = 409600; // 6.25 * 65536
+
+impl MatchConfig {
+    /// Scale the arena to the lobby size.
+    ///
+    /// Lobbies up to `ARENA_BASE_PLAYERS` keep the default arena; each
+    /// extra player widens both half extents by `ARENA_GROWTH_PER_PLAYER`
+    /// (16 players: 250 instead of 200).
+    pub fn with_arena_for_players(mut self, player_count: usize) -> Self {
+        let extra = player_count.saturating_sub(ARENA_BASE_PLAYERS) as Fixed;
+        let growth = ARENA_GROWTH_PER_PLAYER.saturating_mul(extra);
+        self.arena_half_width = ARENA_HALF_WIDTH.saturating_add(growth);
+        self.arena_half_height = ARENA_HALF_HEIGHT.saturating_add(growth);
+        self
+    }
+
+    /// Canonical encoding of every simulation parameter.
+    ///
+    /// Written as compact JSON holding only the fields that differ from
+    /// [`MatchConfig::for_version`]`(1)`, keyed by name with nested configs
+    /// as nested objects and keys sorted (the v1 rules encode as `{}`).
+    /// Lists and enum values are written whole. A new field must take its
+    /// v1 behaviour in `for_version(1)`, which keeps every existing
+    /// encoding, and the hash over it, unchanged.
+    pub fn config_bytes(&self) -> Vec<u8> {
+        let delta = config_delta(&Self::v1().to_value(), &self.to_value())
+            .unwrap_or_else(|| Value::Object(Map::new()));
+        serde_json::to_vec(&delta).expect("MatchConfig serialization should not fail")
+    }
+
+    /// Recover a config from [`MatchConfig::config_bytes`].
+    ///
+    /// Fields missing from `data` keep their v1 values, so configs encoded
+    /// before a field existed still decode.
+    pub fn from_config_bytes(data: &[u8]) -> Option<Self> {
+        let delta: Value = serde_json::from_slice(data).ok()?;
+        if !delta.is_object() {
+            return None;
+        }
+        let mut value = Self::v1().to_value();
+        apply_config_delta(&mut value, delta);
+        serde_json::from_value(value).ok()
+    }
+
+    fn to_value(&self) -> Value {
+        serde_json::to_value(self).expect("MatchConfig serialization should not fail")
+    }
+
+    /// Hash committed to in transcripts and match commitments.
+    pub fn config_hash(&self) -> StateHash {
+        hash_with_domain(b"RUNE_RELIC_CONFIG_V1", &self.config_bytes())
+    }
+
+    /// Reconstruct the exact config used for a given rules version.
+    ///
+    /// Returns `None` for versions this build does not know about.
+    pub fn for_version(version: u32) -> Option<Self> {
+        match version {
+            1 => Some(Self::v1()),
+            // v2: first blood / first evolution / first shrine bonuses
+            2 => Some(Self {
+                milestones_enabled: true,
+                ..Self::v1()
+            }),
+            // v3: dashes resolve after eliminations
+            3 => Some(Self {
+                dash_after_collisions: true,
+                ..Self::for_version(2)?
+            }),
+            _ => None,
+        }
+    }
+
+    /// Rules of version 1, the base every encoding is relative to.
+    ///
+    /// Fields added later take the value that reproduces v1 behaviour here;
+    /// later versions override them in [`MatchConfig::for_version`].
+    fn v1() -> Self {
         Self {
             rune_spawn: RuneSpawnConfig::default(),
             shrine: ShrineConfig::default(),
+            ability: AbilityConfig::default(),
+            revive: ReviveConfig::default(),
+            respawn: RespawnConfig::default(),
+            survivor: SurvivorConfig::default(),
+            arena_half_width: ARENA_HALF_WIDTH,
+            arena_half_height: ARENA_HALF_HEIGHT,
+            form_table: FormTable::default(),
+            damaged_radius_scale: FIXED_ONE,
             shrink_start_tick: u32::MAX, // Disable shrink for Arcane Circuit
             shrink_rate: 0,
             zone_damage_rate: 0,
+            zone_damage_ramp: 0,
+            zone_damage_ramp_max: FIXED_ONE * 4,
+            zone_push_strength: 0,
+            sudden_death_damage_step: 0,
+            input_delay_ticks: 0,
+            evolve_down_on_damage: false,
+            milestones_enabled: false,
+            dash_after_collisions: false,
+            jump_enabled: false,
+            movement_acceleration: 0,
+            boost_pads_enabled: false,
+            boost_pads: Vec::new(),
+        }
+    }
+}
+
+impl Default for MatchConfig {
+    fn default() -> Self {
+        Self::for_version(MATCH_CONFIG_VERSION).expect("current rules version is known")
+    }
+}
+
+/// Fields of `value` that differ from `base`, or `None` if they are equal.
+///
+/// Objects with the same keys (structs) recurse; anything else is written
+/// whole.
+fn config_delta(base: &Value, value: &Value) -> Option<Value> {
+    if base == value {
+        return None;
+    }
+    match (base, value) {
+        (Value::Object(base), Value::Object(fields))
+            if base.len() == fields.len() && base.keys().all(|key| fields.contains_key(key)) =>
+        {
+            let delta: Map<String, Value> = fields.iter()
+                .filter_map(|(key, field)| Some((key.clone(), config_delta(&base[key], field)?)))
+                .collect();
+            Some(Value::Object(delta))
         }
+        _ => Some(value.clone()),
+    }
+}
+
+/// Inverse of [`config_delta`]: overwrite `base` with the fields in `delta`.
+fn apply_config_delta(base: &mut Value, delta: Value) {
+    match (base, delta) {
+        (Value::Object(base), Value::Object(fields))
+            if fields.keys().all(|key| base.contains_key(key)) =>
+        {
+            for (key, field) in fields {
+                if let Some(slot) = base.get_mut(&key) {
+                    apply_config_delta(slot, field);
+                }
+            }
+        }
+        (base, delta) => *base = delta,
     }
 }
 
 /// Run one simulation tick.
 ///
+/// Drive matches through [`MatchState::tick_once`]; this is what it wraps.
+///
 /// # Arguments
 ///
 /// * `state` - The match state (will be mutated)
@@ -76,7 +325,7 @@ impl Default for MatchConfig {
 /// - Uses fixed-point math only
 /// - Uses deterministic RNG (state.rng)
 /// - No system calls, no floating point
-pub fn tick(
+pub(crate) fn tick(
     state: &mut MatchState,
     inputs: &BTreeMap<PlayerId, InputFrame>,
     config: &MatchConfig,
@@ -91,9 +340,12 @@ pub fn tick(
         }
         MatchPhase::Countdown { ticks_remaining } => {
             if ticks_remaining == 0 {
-                state.phase = MatchPhase::Playing;
-                // Initialize shrines when match starts
-                spawn_shrines(state);
+                state.rng.audit_subsystem("start");
+                start_playing(state, config);
+                #[cfg(feature = "rng-audit")]
+                {
+                    result.rng_audit = state.rng.take_audit();
+                }
             } else {
                 state.phase = MatchPhase::Countdown {
                     ticks_remaining: ticks_remaining - 1,
@@ -114,44 +366,149 @@ pub fn tick(
     // 0. Advance tick counter
     state.tick += 1;
 
-    // 1. Apply player inputs
-    apply_inputs(state, inputs);
+    // 1. Apply player inputs (after the configured delay)
+    state.rng.audit_subsystem("inputs");
+    if config.input_delay_ticks > 0 {
+        let due = delay_inputs(state, inputs, config.input_delay_ticks);
+        apply_inputs(state, &due, config);
+    } else {
+        apply_inputs(state, inputs, config);
+    }
 
     // 2. Update physics
-    update_physics(state);
+    state.rng.audit_subsystem("physics");
+    update_physics(state, config);
+
+    // 2b. Launch players who just stepped onto a boost pad
+    state.rng.audit_subsystem("boost_pads");
+    process_boost_pads(state, config);
 
     // 3. Update arena shrink (disabled in Arcane Circuit config)
+    state.rng.audit_subsystem("shrink");
     update_arena_shrink(state, config);
 
-    // 4. Check wall collisions (instant elimination)
+    // 4. Apply zone damage to players outside the shrinking arena
+    state.rng.audit_subsystem("zone");
+    process_zone_damage(state, config, &mut result);
+
+    // 5. Check wall collisions (instant elimination)
+    state.rng.audit_subsystem("walls");
     process_wall_collisions(state, &mut result);
 
-    // 5. Check player-vs-player collisions
-    process_player_collisions(state, &mut result);
+    // 6. Check player-vs-player collisions
+    state.rng.audit_subsystem("player_collisions");
+    process_player_collisions(state, config, &mut result);
+
+    // 6a. Move survivors by this tick's dash
+    if config.dash_after_collisions {
+        state.rng.audit_subsystem("dashes");
+        apply_queued_dashes(state);
+    }
+
+    // 6b. Channel revives for downed teammates
+    state.rng.audit_subsystem("revives");
+    process_revives(state, &config.revive);
 
-    // 6. Check player-vs-rune collisions
-    process_rune_collisions(state, &mut result);
+    // 7. Check player-vs-rune collisions
+    state.rng.audit_subsystem("rune_collisions");
+    process_rune_collisions(state, config, &mut result);
 
-    // 7. Spawn new runes
+    // 8. Spawn new runes
+    state.rng.audit_subsystem("rune_spawn");
     maybe_spawn_runes(state, &config.rune_spawn);
 
-    // 8. Process shrine mechanics
+    // 8b. Scripted rune rain
+    state.rng.audit_subsystem("rune_rain");
+    maybe_rune_rain(state, &config.rune_spawn);
+
+    // 9. Process shrine mechanics
+    state.rng.audit_subsystem("shrines");
     process_shrines(state, &config.shrine);
 
-    // 9. Process active ability effects (gravity wells, etc.)
-    process_active_abilities(state);
+    // 10. Process active ability effects (gravity wells, etc.) and energy
+    state.rng.audit_subsystem("abilities");
+    process_active_abilities(state, &config.ability);
+    regen_energy(state, &config.ability);
+    decay_overcharge(state, &config.ability);
 
-    // 10. Check end conditions
-    check_end_conditions(state, &mut result);
+    // 10a. Run survivor waves (PvE mode only)
+    state.rng.audit_subsystem("survivor");
+    process_survivor(state, &config.survivor);
+
+    // 10b. Award first blood / first evolution / first shrine bonuses
+    state.rng.audit_subsystem("milestones");
+    if config.milestones_enabled {
+        process_milestones(state);
+    }
+
+    // 10c. Send this tick's eliminations to respawn and return due players
+    state.rng.audit_subsystem("respawns");
+    process_respawns(state, &config.respawn);
+
+    // 11. Check end conditions
+    state.rng.audit_subsystem("end");
+    check_end_conditions(state, config, &mut result);
 
     // Collect events
     result.events = state.take_events();
 
+    #[cfg(feature = "rng-audit")]
+    {
+        result.rng_audit = state.rng.take_audit();
+    }
+
     result
 }
 
+impl MatchState {
+    /// Advance the match by one tick.
+    ///
+    /// The entry point for anything driving a match (sessions, replay
+    /// viewers, fuzzers, verification): handles every phase, from the
+    /// countdown to the end of the match.
+    pub fn tick_once(
+        &mut self,
+        inputs: &BTreeMap<PlayerId, InputFrame>,
+        config: &MatchConfig,
+    ) -> TickResult {
+        tick(self, inputs, config)
+    }
+}
+
+/// Install a config's pre-start rules on a fresh match.
+///
+/// Sets the form ladder, the arena size and the map stretched to it, and
+/// the boost pad layout. Call before adding players: these rules shape
+/// spawns and starting forms and are part of the initial state hash, so
+/// live sessions and verification must apply them at the same point.
+pub fn prepare_match(state: &mut MatchState, config: &MatchConfig) {
+    state.set_form_table(config.form_table.clone());
+    state.set_arena_size(config.arena_half_width, config.arena_half_height);
+    state.map = ArcaneCircuitMap::for_arena(config.arena_half_width, config.arena_half_height)
+        .with_boost_pads(config.boost_pads.clone());
+}
+
+/// Transition a match into the Playing phase.
+///
+/// This is the single place shrines are spawned and the configured arena
+/// size and damaged radius scale are applied, so live sessions, replays and
+/// verification all reach an identical first Playing tick. Calling it again on a match that is
+/// already set up is a no-op.
+pub fn start_playing(state: &mut MatchState, config: &MatchConfig) {
+    state.phase = MatchPhase::Playing;
+    state.set_arena_size(config.arena_half_width, config.arena_half_height);
+    state.form_table.set_damaged_radius_scale(config.damaged_radius_scale);
+    if state.shrines.is_empty() {
+        spawn_shrines(state, &config.shrine);
+    }
+}
+
 /// Apply player inputs to their states.
-fn apply_inputs(state: &mut MatchState, inputs: &BTreeMap<PlayerId, InputFrame>) {
+fn apply_inputs(
+    state: &mut MatchState,
+    inputs: &BTreeMap<PlayerId, InputFrame>,
+    config: &MatchConfig,
+) {
     // Collect ability activations (to avoid borrow issues)
     let mut ability_activations: Vec<PlayerId> = Vec::new();
 
@@ -164,7 +521,10 @@ fn apply_inputs(state: &mut MatchState, inputs: &BTreeMap<PlayerId, InputFrame>)
 
             // Movement
             let move_dir = input.move_direction();
-            let speed = player.speed();
+            let mut speed = player.speed(&state.form_table);
+            if player.slow_ticks > 0 {
+                speed = fixed_mul(speed, player.slow_mult);
+            }
 
             // Normalize movement if diagonal (prevent faster diagonal movement)
             let move_len_sq = move_dir.length_squared();
@@ -179,31 +539,118 @@ fn apply_inputs(state: &mut MatchState, inputs: &BTreeMap<PlayerId, InputFrame>)
                 FixedVec2::ZERO
             };
 
-            player.velocity = velocity;
+            if config.movement_acceleration > 0 {
+                // Steer toward the input velocity; with no input, coast
+                if velocity != FixedVec2::ZERO {
+                    player.velocity = accelerate_toward(player.velocity, velocity, config.movement_acceleration);
+                }
+            } else {
+                player.velocity = velocity;
+            }
 
             // Jump
-            if input.jump_pressed() && player.can_jump(state.tick) {
+            if config.jump_enabled && input.jump_pressed() && player.can_jump(state.tick) {
                 player.velocity.y = player.velocity.y.wrapping_add(JUMP_VELOCITY);
                 player.last_jump_tick = state.tick;
             }
 
             // Check for ability activation (process after loop to avoid borrow issues)
-            if input.ability_pressed() && player.ability_ready() {
-                ability_activations.push(*player_id);
+            let charges_dash = config.ability.charge_dash_enabled
+                && configured_ability(player.form, &config.ability) == AbilityType::Dash;
+            if charges_dash {
+                // Hold to charge, release to fire
+                if input.ability_pressed() {
+                    if player.ability_ready(config.ability.activation_cost()) {
+                        let max_ticks = config.ability.dash_max_charge_ticks.max(1);
+                        player.dash_charge_ticks = (player.dash_charge_ticks + 1).min(max_ticks);
+                    }
+                } else if player.dash_charge_ticks > 0 {
+                    ability_activations.push(*player_id);
+                }
+            } else {
+                player.dash_charge_ticks = 0;
+                if input.ability_pressed() && player.ability_ready(config.ability.activation_cost()) {
+                    ability_activations.push(*player_id);
+                }
             }
         }
     }
 
     // Process ability activations
     for player_id in ability_activations {
-        if let Some(event) = activate_ability(state, player_id) {
+        if let Some(event) = activate_ability(state, player_id, &config.ability) {
             state.push_event(event);
         }
     }
 }
 
+/// Move `current` toward `target` by at most `acceleration * dt`.
+fn accelerate_toward(current: FixedVec2, target: FixedVec2, acceleration: Fixed) -> FixedVec2 {
+    let max_step = fixed_mul(acceleration, TICK_DURATION).max(1);
+    let max_step_sq = (max_step as i64 * max_step as i64) >> 16;
+    if current.distance_squared_wide(target) <= max_step_sq {
+        return target;
+    }
+    current.add(target.sub(current).normalize().scale(max_step))
+}
+
+/// Queue this tick's inputs and return those due now.
+///
+/// Inputs received at tick T are applied at T + `delay`. Players with
+/// nothing due this tick are absent from the result, just as if they had
+/// sent no input.
+fn delay_inputs(
+    state: &mut MatchState,
+    inputs: &BTreeMap<PlayerId, InputFrame>,
+    delay: u32,
+) -> BTreeMap<PlayerId, InputFrame> {
+    let apply_tick = state.tick.saturating_add(delay);
+    for (player_id, input) in inputs {
+        state
+            .input_delay_queue
+            .entry(*player_id)
+            .or_default()
+            .push_back((apply_tick, *input));
+    }
+
+    let mut due = BTreeMap::new();
+    for (player_id, queue) in state.input_delay_queue.iter_mut() {
+        while let Some(&(tick, frame)) = queue.front() {
+            if tick > state.tick {
+                break;
+            }
+            queue.pop_front();
+            due.insert(*player_id, frame);
+        }
+    }
+    due
+}
+
+/// Move players by their queued dash (`dash_after_collisions`).
+///
+/// Runs after zone, wall and player-collision eliminations, which clear
+/// the dash, so only survivors move.
+fn apply_queued_dashes(state: &mut MatchState) {
+    for player in state.players.values_mut() {
+        let Some(dash_vel) = player.dash_velocity.take() else {
+            continue;
+        };
+        player.position.x = player.position.x.wrapping_add(fixed_mul(dash_vel.x, TICK_DURATION));
+        player.position.y = player.position.y.wrapping_add(fixed_mul(dash_vel.y, TICK_DURATION));
+
+        if player.spawn_zone_active {
+            let inside = player.spawn_zone_id.is_some_and(|zone_id| {
+                state.map.spawn_zone_contains(zone_id, player.position, player.radius(&state.form_table))
+            });
+            if !inside {
+                player.spawn_zone_active = false;
+            }
+        }
+    }
+}
+
 /// Update physics for all players.
-fn update_physics(state: &mut MatchState) {
+fn update_physics(state: &mut MatchState, config: &MatchConfig) {
     // Tick duration: 1/60 second as Fixed
     const TICK_DT: Fixed = 1092; // round(65536 / 60)
 
@@ -230,10 +677,21 @@ fn update_physics(state: &mut MatchState) {
             velocity.y = fixed_mul(velocity.y, SHRINE_SPEED_MULT);
         }
 
+        // Zone push is applied on top of input movement
+        velocity = velocity.add(player.zone_push);
+
+        // Boost pad launches carry on until friction eats them
+        velocity = velocity.add(player.boost_velocity);
+
+        // Tether springs are recomputed every tick
+        velocity = velocity.add(player.tether_pull);
+
         // Apply dash velocity if active
-        if let Some(dash_vel) = player.dash_velocity {
-            velocity = velocity.add(dash_vel);
-            player.dash_velocity = None; // Dash lasts only 1 tick application
+        if !config.dash_after_collisions {
+            if let Some(dash_vel) = player.dash_velocity {
+                velocity = velocity.add(dash_vel);
+                player.dash_velocity = None; // Dash lasts only 1 tick application
+            }
         }
 
         // Integration: position += velocity * dt
@@ -245,7 +703,7 @@ fn update_physics(state: &mut MatchState) {
 
         if player.spawn_zone_active {
             if let Some(zone_id) = player.spawn_zone_id {
-                if !state.map.spawn_zone_contains(zone_id, player.position, player.radius()) {
+                if !state.map.spawn_zone_contains(zone_id, player.position, player.radius(&state.form_table)) {
                     player.spawn_zone_active = false;
                 }
             } else {
@@ -268,57 +726,259 @@ fn update_physics(state: &mut MatchState) {
         if player.invulnerable_ticks > 0 {
             player.invulnerable_ticks -= 1;
         }
+        if player.slow_ticks > 0 {
+            player.slow_ticks -= 1;
+        }
 
         // Update shrine buffs
         player.update_shrine_buffs();
 
-        // Apply friction (slow down when no input)
-        // This creates the "floaty" feel
+        // Apply friction. With instant movement the next input overwrites
+        // velocity, so this only slows players who sent no input; with
+        // `movement_acceleration` it is what bleeds off coasting momentum.
         let friction = FIXED_ONE - 3276; // ~0.95 friction
         player.velocity.x = fixed_mul(player.velocity.x, friction);
         player.velocity.y = fixed_mul(player.velocity.y, friction);
+        // Same flooring problem as boosts: bring a coasting player to rest
+        if config.movement_acceleration > 0 && player.velocity.length_squared() < BOOST_REST_SQ {
+            player.velocity = FixedVec2::ZERO;
+        }
+        player.boost_velocity.x = fixed_mul(player.boost_velocity.x, friction);
+        player.boost_velocity.y = fixed_mul(player.boost_velocity.y, friction);
+        // Flooring keeps small negative components at -1 forever; snap to rest
+        if player.boost_velocity.length_squared() < BOOST_REST_SQ {
+            player.boost_velocity = FixedVec2::ZERO;
+        }
+    }
+}
+
+/// Squared boost speed below which a launch counts as spent (~0.025 units/s).
+const BOOST_REST_SQ: Fixed = 40; // (0.025 * 65536)^2 >> 16
+
+/// Apply boost pad impulses to players entering a pad.
+///
+/// A pad fires once per entry: the player must leave it before it can
+/// launch them again. The impulse replaces any boost still in flight.
+fn process_boost_pads(state: &mut MatchState, config: &MatchConfig) {
+    if !config.boost_pads_enabled || state.map.boost_pads().is_empty() {
+        return;
+    }
+
+    for player in state.players.values_mut() {
+        if !player.alive {
+            continue;
+        }
+        let pad = state.map.boost_pad_at(player.position);
+        let pad_id = pad.map(|pad| pad.id);
+        if let Some(pad) = pad {
+            if player.boost_pad != Some(pad.id) {
+                player.boost_velocity = pad.impulse;
+            }
+        }
+        player.boost_pad = pad_id;
     }
 }
 
 /// Update arena shrink.
 fn update_arena_shrink(state: &mut MatchState, config: &MatchConfig) {
-    if state.tick < config.shrink_start_tick {
+    if state.elapsed_ticks() >= config.shrink_start_tick {
+        // Increase shrink progress
+        state.arena_shrink = (state.arena_shrink + config.shrink_rate).min(FIXED_ONE);
+    }
+
+    // Fully closed: count ticks for sudden-death escalation
+    if state.arena_shrink >= FIXED_ONE {
+        state.sudden_death_ticks = state.sudden_death_ticks.saturating_add(1);
+    }
+}
+
+/// Invulnerability granted after de-evolving from a collision (0.5 seconds).
+const DEVOLVE_INVULNERABLE_TICKS: u32 = 30;
+
+/// Apply zone damage to players outside the current arena bounds.
+///
+/// Also refreshes each player's zone push (see `zone_push_strength`).
+///
+/// At full shrink, sudden-death damage escalating each tick is applied to
+/// every living player. If that would eliminate everyone at once, the
+/// leaderboard leader survives so the match still has a winner.
+fn process_zone_damage(state: &mut MatchState, config: &MatchConfig, _result: &mut TickResult) {
+    let sudden_death_damage = config
+        .sudden_death_damage_step
+        .saturating_mul(state.sudden_death_ticks.min(i32::MAX as u32) as i32);
+    let (half_width, half_height) = state.current_arena_bounds();
+
+    update_zone_push(state, config, half_width, half_height);
+
+    if config.zone_damage_rate <= 0 && sudden_death_damage <= 0 {
         return;
     }
+    let mut lethal: Vec<PlayerId> = Vec::new();
 
-    // Increase shrink progress
-    state.arena_shrink = (state.arena_shrink + config.shrink_rate).min(FIXED_ONE);
+    for (player_id, player) in state.players.iter_mut() {
+        if !player.alive {
+            continue;
+        }
+
+        let outside = player.position.x.abs() > half_width
+            || player.position.y.abs() > half_height;
+        player.ticks_outside_zone = if outside { player.ticks_outside_zone.saturating_add(1) } else { 0 };
+        let mut base_damage = sudden_death_damage.max(0);
+        if outside {
+            let zone_damage = fixed_mul(
+                config.zone_damage_rate.max(0),
+                zone_damage_multiplier(config, player.ticks_outside_zone),
+            );
+            base_damage = base_damage.saturating_add(zone_damage);
+        }
+        if base_damage == 0 {
+            continue;
+        }
+
+        let damage = fixed_mul(base_damage, get_shield_multiplier(player));
+        player.health = player.health.saturating_sub(damage);
+        if player.health <= 0 {
+            lethal.push(*player_id);
+        }
+    }
+
+    // Sudden death must not wipe out the whole field in one tick
+    if sudden_death_damage > 0 && !lethal.is_empty() && lethal.len() as u32 >= state.alive_count {
+        if let Some(survivor) = state
+            .leaderboard()
+            .into_iter()
+            .map(|(id, _, _)| id)
+            .find(|id| lethal.contains(id))
+        {
+            lethal.retain(|id| *id != survivor);
+            if let Some(player) = state.players.get_mut(&survivor) {
+                player.health = 1;
+            }
+        }
+    }
+
+    for player_id in lethal {
+        if config.evolve_down_on_damage && try_devolve_player(state, &player_id) {
+            continue;
+        }
+
+        if let Some(placement) = state.eliminate_player(&player_id, None) {
+            // No killer - zone death
+            state.push_event(GameEvent::player_eliminated(state.tick, player_id, None, placement));
+        }
+    }
+}
+
+/// Zone damage multiplier after `ticks_outside` consecutive ticks out.
+fn zone_damage_multiplier(config: &MatchConfig, ticks_outside: u32) -> Fixed {
+    if config.zone_damage_ramp <= 0 {
+        return FIXED_ONE;
+    }
+    let extra_ticks = ticks_outside.saturating_sub(1).min(i32::MAX as u32) as i32;
+    FIXED_ONE
+        .saturating_add(config.zone_damage_ramp.saturating_mul(extra_ticks))
+        .min(config.zone_damage_ramp_max.max(FIXED_ONE))
+}
+
+/// Set each living player's inward zone push for the next physics step.
+///
+/// Each axis outside the bounds is pushed toward the centre at
+/// `zone_push_strength`; players inside the zone get no push.
+fn update_zone_push(state: &mut MatchState, config: &MatchConfig, half_width: Fixed, half_height: Fixed) {
+    let strength = config.zone_push_strength.max(0);
+
+    for player in state.players.values_mut() {
+        if !player.alive || strength == 0 {
+            player.zone_push = FixedVec2::ZERO;
+            continue;
+        }
+
+        let inward = |coord: Fixed, half: Fixed| {
+            if coord > half {
+                -strength
+            } else if coord < -half {
+                strength
+            } else {
+                0
+            }
+        };
+        player.zone_push = FixedVec2::new(
+            inward(player.position.x, half_width),
+            inward(player.position.y, half_height),
+        );
+    }
+}
+
+/// De-evolve a player one form, emitting an event. Returns false at Spark.
+fn try_devolve_player(state: &mut MatchState, player_id: &PlayerId) -> bool {
+    let Some(player) = state.players.get_mut(player_id) else {
+        return false;
+    };
+
+    let old_form = player.form;
+    if !player.try_devolve(&state.form_table) {
+        return false;
+    }
+    let new_form = player.form;
+
+    let event = GameEvent::form_devolved(state.tick, *player_id, old_form, new_form);
+    state.push_event(event);
+    true
 }
 
 /// Process player-vs-player collisions.
-fn process_player_collisions(state: &mut MatchState, _result: &mut TickResult) {
+fn process_player_collisions(state: &mut MatchState, config: &MatchConfig, _result: &mut TickResult) {
     let collisions = check_all_player_collisions(state);
 
     for collision in collisions {
-        // Get loser's placement before elimination
-        let placement = state.players.len() as u8 - state.next_placement;
-
-        // Eliminate loser
-        state.eliminate_player(&collision.loser, Some(&collision.winner));
-
-        // Generate event
-        let event = GameEvent::player_eliminated(
-            state.tick,
-            collision.loser,
-            Some(collision.winner),
-            placement,
-        );
-        state.push_event(event);
+        // Skip losers already handled this tick (eliminated or just de-evolved)
+        let loser_protected = state
+            .players
+            .get(&collision.loser)
+            .map(|p| !p.alive || p.invulnerable_ticks > 0)
+            .unwrap_or(true);
+        if loser_protected {
+            continue;
+        }
+
+        if config.evolve_down_on_damage && try_devolve_player(state, &collision.loser) {
+            if let Some(loser) = state.players.get_mut(&collision.loser) {
+                loser.invulnerable_ticks = DEVOLVE_INVULNERABLE_TICKS;
+            }
+            continue;
+        }
+
+        // Teammates may still revive the loser
+        if can_be_downed(state, &collision.loser, &config.revive) {
+            state.down_player(&collision.loser, Some(&collision.winner));
+            state.push_event(GameEvent::player_downed(
+                state.tick,
+                collision.loser,
+                Some(collision.winner),
+            ));
+            continue;
+        }
+
+        // Eliminate loser and generate event
+        if let Some(placement) = state.eliminate_player(&collision.loser, Some(&collision.winner)) {
+            state.push_event(GameEvent::player_eliminated(
+                state.tick,
+                collision.loser,
+                Some(collision.winner),
+                placement,
+            ));
+        }
     }
 }
 
 /// Process player-vs-rune collisions.
-fn process_rune_collisions(state: &mut MatchState, _result: &mut TickResult) {
+fn process_rune_collisions(state: &mut MatchState, config: &MatchConfig, _result: &mut TickResult) {
     let collisions = check_all_rune_collisions(state);
 
     for collision in collisions {
-        if let Some(event) = collect_rune(state, collision.player_id, collision.rune_id) {
+        if let Some(event) = collect_rune(state, &config.rune_spawn, collision.player_id, collision.rune_id) {
             state.push_event(event);
+            maybe_chain_rune(state, &config.rune_spawn, collision.rune_id);
         }
     }
 }
@@ -338,27 +998,32 @@ fn process_wall_collisions(state: &mut MatchState, _result: &mut TickResult) {
     }
 
     for player_id in to_eliminate {
-        let event = GameEvent::player_eliminated(
-            state.tick,
-            player_id,
-            None, // No killer - wall death
-            (state.players.len() as u8).saturating_sub(state.next_placement),
-        );
-        state.eliminate_player(&player_id, None);
-        state.push_event(event);
+        if let Some(placement) = state.eliminate_player(&player_id, None) {
+            // No killer - wall death
+            state.push_event(GameEvent::player_eliminated(state.tick, player_id, None, placement));
+        }
     }
 }
 
 /// Check if match should end.
-fn check_end_conditions(state: &mut MatchState, result: &mut TickResult) {
+///
+/// Survivor matches run until everyone has fallen; PvP ends with the last
+/// player standing.
+fn check_end_conditions(state: &mut MatchState, config: &MatchConfig, result: &mut TickResult) {
     // End if time expired
-    if state.tick >= MATCH_DURATION_TICKS {
+    if state.elapsed_ticks() >= MATCH_DURATION_TICKS {
         end_match(state, result);
         return;
     }
 
-    // End if only 1 (or 0) players alive
-    if state.alive_count <= 1 {
+    // Players awaiting a respawn are still in the match
+    let in_play = if config.respawn.enabled {
+        state.alive_count + pending_respawns(state)
+    } else {
+        state.alive_count
+    };
+    let last_standing = if config.survivor.enabled { 0 } else { 1 };
+    if in_play <= last_standing {
         end_match(state, result);
     }
 }
@@ -368,13 +1033,8 @@ fn end_match(state: &mut MatchState, result: &mut TickResult) {
     state.phase = MatchPhase::Ended;
     result.match_ended = true;
 
-    // Find winner (last alive or highest score if tied)
-    let winner = state
-        .players
-        .iter()
-        .filter(|(_, p)| p.alive)
-        .max_by_key(|(id, p)| (p.score, *id))  // Tie-break by ID
-        .map(|(id, _)| *id);
+    // Same winner the session reports and the result hash commits to
+    let winner = state.result_winner();
 
     // Assign 1st place to winner
     if let Some(winner_id) = winner {
@@ -402,7 +1062,7 @@ pub fn replay_match(
     let config = MatchConfig::default();
 
     // Start match
-    state.phase = MatchPhase::Playing;
+    start_playing(&mut state, &config);
 
     for t in 0..tick_count {
         // Get inputs for this tick
@@ -416,7 +1076,7 @@ pub fn replay_match(
         }
 
         // Run tick
-        let result = tick(&mut state, &tick_inputs, &config);
+        let result = state.tick_once(&tick_inputs, &config);
         all_events.extend(result.events);
 
         if result.match_ended {
@@ -458,8 +1118,8 @@ mod tests {
 
         // Run 100 ticks
         for _ in 0..100 {
-            tick(&mut state1, &inputs, &config);
-            tick(&mut state2, &inputs, &config);
+            state1.tick_once(&inputs, &config);
+            state2.tick_once(&inputs, &config);
         }
 
         // States should be identical
@@ -488,7 +1148,7 @@ mod tests {
         inputs.insert(id, InputFrame::with_movement(127, 0)); // Full right
 
         // Run tick
-        tick(&mut state, &inputs, &config);
+        state.tick_once(&inputs, &config);
 
         // Player should have moved right
         let player = state.players.get(&id).unwrap();
@@ -511,12 +1171,44 @@ mod tests {
         state.eliminate_player(&id2, Some(&id1));
 
         let inputs = BTreeMap::new();
-        let result = tick(&mut state, &inputs, &config);
+        let result = state.tick_once(&inputs, &config);
 
         assert!(result.match_ended);
         assert_eq!(result.winner, Some(id1));
     }
 
+    #[test]
+    fn test_end_match_winner_matches_result_winner() {
+        let ended = |scores: [(u32, u32); 3], eliminated: &[u8]| {
+            let mut state = MatchState::new([0; 16], 12345);
+            for (i, (score, kills)) in scores.into_iter().enumerate() {
+                let id = PlayerId::new([i as u8 + 1; 16]);
+                state.add_player(id);
+                let player = state.players.get_mut(&id).unwrap();
+                player.score = score;
+                player.kills = kills;
+            }
+            state.assign_spawn_positions();
+            state.phase = MatchPhase::Playing;
+            for &i in eliminated {
+                state.eliminate_player(&PlayerId::new([i; 16]), None);
+            }
+            // Ties need two survivors, so let the clock end those matches
+            state.tick = MATCH_DURATION_TICKS - 1;
+            let result = state.tick_once(&BTreeMap::new(), &MatchConfig::default());
+            assert!(result.match_ended);
+            assert_eq!(result.winner, state.result_winner());
+            result.winner
+        };
+
+        // An eliminated leader still tops the leaderboard
+        assert_eq!(ended([(500, 0), (100, 0), (200, 0)], &[1, 3]), Some(PlayerId::new([1; 16])));
+
+        // Score tie: kills, then the lower ID, not the higher ID
+        assert_eq!(ended([(300, 0), (300, 0), (0, 0)], &[3]), Some(PlayerId::new([1; 16])));
+        assert_eq!(ended([(300, 0), (300, 1), (0, 0)], &[3]), Some(PlayerId::new([2; 16])));
+    }
+
     #[test]
     fn test_replay_determinism() {
         let state1 = MatchState::new([0; 16], 99999);
@@ -550,4 +1242,767 @@ mod tests {
         assert_eq!(final1.compute_hash(), final2.compute_hash());
         assert_eq!(events1.len(), events2.len());
     }
+
+    #[test]
+    fn test_countdown_and_direct_start_agree() {
+        let mut via_countdown = MatchState::new([0; 16], 4242);
+        let mut direct = MatchState::new([0; 16], 4242);
+        for i in 0..4 {
+            let id = PlayerId::new([i; 16]);
+            via_countdown.add_player(id);
+            direct.add_player(id);
+        }
+
+        via_countdown.phase = MatchPhase::Countdown { ticks_remaining: 3 };
+        let config = MatchConfig::default();
+        let inputs = BTreeMap::new();
+        while via_countdown.phase != MatchPhase::Playing {
+            via_countdown.tick_once(&inputs, &config);
+        }
+
+        start_playing(&mut direct, &config);
+        // Idempotent: a second call must not add shrines
+        start_playing(&mut direct, &config);
+
+        assert_eq!(via_countdown.shrines.len(), direct.shrines.len());
+        assert_eq!(via_countdown.rng.state(), direct.rng.state());
+        assert_eq!(via_countdown.compute_hash(), direct.compute_hash());
+    }
+
+    /// Place a player at a fixed position and start the match.
+    fn setup_zone_player(state: &mut MatchState, id: PlayerId, position: FixedVec2) {
+        state.players.insert(id, crate::game::state::PlayerState::new(id, position));
+        state.alive_count = state.players.len() as u32;
+        state.phase = MatchPhase::Playing;
+    }
+
+    #[test]
+    fn test_zone_damage_ramps_while_lingering_outside() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let straggler = PlayerId::new([1; 16]);
+        let other = PlayerId::new([2; 16]);
+
+        state.arena_shrink = FIXED_ONE;
+        let (half_width, _) = state.current_arena_bounds();
+        let outside = FixedVec2::new(half_width + FIXED_ONE * 2, 0);
+        setup_zone_player(&mut state, straggler, outside);
+        setup_zone_player(&mut state, other, FixedVec2::ZERO);
+
+        let config = MatchConfig {
+            zone_damage_rate: FIXED_ONE >> 8,
+            zone_damage_ramp: FIXED_ONE >> 3,
+            ..Default::default()
+        };
+        let damage_this_tick = |state: &mut MatchState| {
+            let before = state.players[&straggler].health;
+            process_zone_damage(state, &config, &mut TickResult::default());
+            before - state.players[&straggler].health
+        };
+
+        // Each tick outside hurts more than the last, up to the cap
+        let first = damage_this_tick(&mut state);
+        assert_eq!(first, config.zone_damage_rate);
+        let mut last = first;
+        for _ in 0..10 {
+            let damage = damage_this_tick(&mut state);
+            assert!(damage > last);
+            last = damage;
+        }
+        for _ in 0..30 {
+            damage_this_tick(&mut state);
+        }
+        assert_eq!(damage_this_tick(&mut state), fixed_mul(config.zone_damage_rate, config.zone_damage_ramp_max));
+
+        // Stepping back inside resets the ramp
+        state.players.get_mut(&straggler).unwrap().position = FixedVec2::ZERO;
+        assert_eq!(damage_this_tick(&mut state), 0);
+        assert_eq!(state.players[&straggler].ticks_outside_zone, 0);
+        state.players.get_mut(&straggler).unwrap().position = outside;
+        assert_eq!(damage_this_tick(&mut state), first);
+    }
+
+    #[test]
+    fn test_evolve_down_on_zone_damage() {
+        use crate::game::state::Form;
+
+        let mut state = MatchState::new([0; 16], 12345);
+        let ward = PlayerId::new([1; 16]);
+        let other = PlayerId::new([2; 16]);
+
+        // Arena fully shrunk (half size) - ward sits outside, other at center
+        state.arena_shrink = FIXED_ONE;
+        let (half_width, _) = state.current_arena_bounds();
+        setup_zone_player(&mut state, ward, FixedVec2::new(half_width + FIXED_ONE * 2, 0));
+        setup_zone_player(&mut state, other, FixedVec2::ZERO);
+        {
+            let player = state.players.get_mut(&ward).unwrap();
+            player.set_tier(Form::Ward as u8, &state.form_table);
+            player.score = 500;
+            player.health = 1;
+        }
+
+        let config = MatchConfig {
+            zone_damage_rate: FIXED_ONE >> 4,
+            evolve_down_on_damage: true,
+            ..Default::default()
+        };
+
+        let result = state.tick_once(&BTreeMap::new(), &config);
+
+        let player = state.players.get(&ward).unwrap();
+        assert!(player.alive, "Ward should survive by de-evolving");
+        assert_eq!(player.form, Form::Glyph);
+        assert_eq!(player.health, player.max_health);
+        assert!(result.events.iter().any(|e| matches!(
+            e.data,
+            crate::game::events::GameEventData::FormDevolved {
+                old_form: Form::Ward,
+                new_form: Form::Glyph,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_eliminated_player_does_not_apply_queued_dash() {
+        // A Spark dashes while overlapping an Ancient
+        let dash_while_eaten = |config: &MatchConfig| {
+            let mut state = MatchState::new([0; 16], 12345);
+            let spark = PlayerId::new([1; 16]);
+            let ancient = PlayerId::new([2; 16]);
+            let bystander = PlayerId::new([3; 16]);
+            setup_zone_player(&mut state, spark, FixedVec2::ZERO);
+            setup_zone_player(&mut state, ancient, FixedVec2::new(-FIXED_ONE, 0));
+            setup_zone_player(&mut state, bystander, FixedVec2::new(0, 50 * FIXED_ONE));
+            let table = state.form_table.clone();
+            state.players.get_mut(&ancient).unwrap().set_tier(4, &table);
+
+            let mut dash = InputFrame::new();
+            dash.set_ability(true);
+            let result = state.tick_once(&BTreeMap::from([(spark, dash)]), config);
+            let eaten = result.events.iter().any(|e| matches!(
+                e.data,
+                crate::game::events::GameEventData::PlayerEliminated { victim_id, .. } if victim_id == spark
+            ));
+            let player = &state.players[&spark];
+            (eaten, player.position, player.dash_velocity)
+        };
+
+        // Eaten the tick the dash was queued: the dash never moves it
+        let (eaten, position, dash) = dash_while_eaten(&MatchConfig::default());
+        assert!(eaten);
+        assert_eq!(position, FixedVec2::ZERO);
+        assert!(dash.is_none());
+
+        // Rules before v3 moved the player by the dash before the collision
+        let (_, position, _) = dash_while_eaten(&MatchConfig::for_version(2).unwrap());
+        assert_ne!(position, FixedVec2::ZERO);
+    }
+
+    #[test]
+    fn test_survivor_dash_moves_after_collisions() {
+        let dash_distance = |config: &MatchConfig| {
+            let mut state = MatchState::new([0; 16], 12345);
+            let spark = PlayerId::new([1; 16]);
+            setup_zone_player(&mut state, spark, FixedVec2::ZERO);
+            setup_zone_player(&mut state, PlayerId::new([2; 16]), FixedVec2::new(0, 50 * FIXED_ONE));
+
+            let mut dash = InputFrame::new();
+            dash.set_ability(true);
+            state.tick_once(&BTreeMap::from([(spark, dash)]), config);
+            assert!(state.players[&spark].dash_velocity.is_none());
+            state.players[&spark].position
+        };
+
+        // Same displacement whichever step applies it
+        let moved = dash_distance(&MatchConfig::default());
+        assert_ne!(moved, FixedVec2::ZERO);
+        assert_eq!(moved, dash_distance(&MatchConfig::for_version(2).unwrap()));
+    }
+
+    /// An Arcane casts a gravity well over a fleeing Spark while an Ancient
+    /// sits at `killer_x`; returns the state and result of that tick.
+    fn cast_gravity_well(killer_x: Fixed) -> (MatchState, TickResult) {
+        let mut state = MatchState::new([0; 16], 12345);
+        let caster = PlayerId::new([1; 16]);
+        let killer = PlayerId::new([2; 16]);
+        let bystander = PlayerId::new([3; 16]);
+        setup_zone_player(&mut state, caster, FixedVec2::ZERO);
+        setup_zone_player(&mut state, killer, FixedVec2::new(killer_x, 0));
+        setup_zone_player(&mut state, bystander, FixedVec2::new(-5 * FIXED_ONE, 0));
+        let table = state.form_table.clone();
+        state.players.get_mut(&caster).unwrap().set_tier(3, &table);
+        state.players.get_mut(&killer).unwrap().set_tier(4, &table);
+
+        let mut cast = InputFrame::new();
+        cast.set_ability(true);
+        let inputs = BTreeMap::from([
+            (caster, cast),
+            (bystander, InputFrame::with_movement(0, 127)),
+        ]);
+        let result = state.tick_once(&inputs, &MatchConfig::default());
+        (state, result)
+    }
+
+    #[test]
+    fn test_cast_then_eliminated_same_tick_leaves_no_effect() {
+        let caster = PlayerId::new([1; 16]);
+        let bystander = PlayerId::new([3; 16]);
+
+        // Surviving caster: the well stays and slows the bystander
+        let (survived, _) = cast_gravity_well(30 * FIXED_ONE);
+        assert!(survived.active_abilities.iter().any(|e| e.source_player == caster));
+
+        // Caster eaten the same tick: the cast happened, then the well goes
+        let (died, result) = cast_gravity_well(FIXED_ONE);
+        let used = result.events.iter().position(|e| matches!(
+            e.data,
+            crate::game::events::GameEventData::AbilityUsed { player_id, .. } if player_id == caster
+        ));
+        let eliminated = result.events.iter().position(|e| matches!(
+            e.data,
+            crate::game::events::GameEventData::PlayerEliminated { victim_id, .. } if victim_id == caster
+        ));
+        assert!(used.unwrap() < eliminated.unwrap(), "abilities resolve before collisions");
+        assert!(died.players[&caster].ability_cooldown > 0);
+        assert!(died.active_abilities.is_empty());
+        assert!(
+            died.players[&bystander].velocity.y > survived.players[&bystander].velocity.y,
+            "a dead caster's well must not slow anyone"
+        );
+    }
+
+    /// Hold the ability for `hold_ticks`, release, and return how far the
+    /// dash carried the player on the release tick.
+    fn charged_dash_distance(hold_ticks: u32) -> Fixed {
+        let mut state = MatchState::new([0; 16], 12345);
+        let id = PlayerId::new([1; 16]);
+        setup_zone_player(&mut state, id, FixedVec2::ZERO);
+        // Idle opponent far away keeps the match running
+        setup_zone_player(&mut state, PlayerId::new([2; 16]), FixedVec2::new(0, FIXED_ONE * 50));
+        state.players.get_mut(&id).unwrap().invulnerable_ticks = 1000;
+
+        let config = MatchConfig {
+            ability: AbilityConfig { charge_dash_enabled: true, ..Default::default() },
+            ..Default::default()
+        };
+
+        let mut holding = InputFrame::with_movement(127, 0);
+        holding.set_ability(true);
+        let released = InputFrame::with_movement(127, 0);
+
+        for _ in 0..hold_ticks {
+            state.tick_once(&BTreeMap::from([(id, holding)]), &config);
+            assert!(state.players[&id].dash_velocity.is_none(), "dash fires on release only");
+        }
+        assert_eq!(state.players[&id].dash_charge_ticks, hold_ticks.min(60));
+
+        let before = state.players[&id].position.x;
+        state.tick_once(&BTreeMap::from([(id, released)]), &config);
+        let player = &state.players[&id];
+        assert_eq!(player.dash_charge_ticks, 0);
+        assert!(player.ability_cooldown > 0);
+        player.position.x - before
+    }
+
+    #[test]
+    fn test_charged_dash_goes_farther_than_tap() {
+        let tap = charged_dash_distance(1);
+        let held = charged_dash_distance(60);
+        // Held past the cap behaves like a full charge
+        let over_held = charged_dash_distance(120);
+
+        assert!(held > tap, "held {} should exceed tap {}", held, tap);
+        assert_eq!(held, over_held);
+    }
+
+    #[cfg(feature = "rng-audit")]
+    #[test]
+    fn test_rng_audit_counts_rune_spawn_draws() {
+        let mut state = MatchState::new([0; 16], 12345);
+        setup_zone_player(&mut state, PlayerId::new([1; 16]), FixedVec2::new(-FIXED_ONE * 50, 0));
+        setup_zone_player(&mut state, PlayerId::new([2; 16]), FixedVec2::new(FIXED_ONE * 50, 0));
+
+        // Corridor-only spawns draw a fixed 5 values per rune:
+        // region roll, corridor pick, along-axis t, cross offset, rune type
+        const RUNES: u32 = 12;
+        let config = MatchConfig {
+            rune_spawn: RuneSpawnConfig {
+                initial_spawn_count: RUNES,
+                weight_hubs: 0,
+                weight_corridors: 1,
+                weight_spawns: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let result = state.tick_once(&BTreeMap::new(), &config);
+
+        assert_eq!(state.runes.len(), RUNES as usize);
+        let rune_draws = result.rng_audit.iter()
+            .find(|(name, _)| *name == "rune_spawn")
+            .map(|(_, count)| *count);
+        assert_eq!(rune_draws, Some(RUNES * 5));
+    }
+
+    #[test]
+    fn test_jump_gated_by_config_with_cooldown() {
+        let jump_ticks = |config: &MatchConfig| {
+            let mut state = MatchState::new([0; 16], 12345);
+            let jumper = PlayerId::new([1; 16]);
+            let idle = PlayerId::new([2; 16]);
+            setup_zone_player(&mut state, jumper, FixedVec2::new(-FIXED_ONE * 50, 0));
+            setup_zone_player(&mut state, idle, FixedVec2::new(FIXED_ONE * 50, 0));
+
+            let mut jump = InputFrame::new();
+            jump.set_jump(true);
+            let inputs = BTreeMap::from([(jumper, jump), (idle, InputFrame::new())]);
+
+            let mut ticks = Vec::new();
+            while state.tick < 70 {
+                state.tick_once(&inputs, config);
+                let player = &state.players[&jumper];
+                if player.last_jump_tick == state.tick {
+                    ticks.push(state.tick);
+                } else {
+                    assert_eq!(player.velocity.y, 0, "no vertical boost at tick {}", state.tick);
+                }
+            }
+            ticks
+        };
+
+        // Default: holding jump does nothing
+        assert!(jump_ticks(&MatchConfig::default()).is_empty());
+
+        // Enabled: one jump per 30-tick cooldown
+        let enabled = MatchConfig { jump_enabled: true, ..Default::default() };
+        assert_eq!(jump_ticks(&enabled), vec![30, 60]);
+    }
+
+    #[test]
+    fn test_input_delay_applies_after_fixed_ticks() {
+        const DELAY: u32 = 5;
+        const SENT_AT: u32 = 10;
+
+        let mut state = MatchState::new([0; 16], 12345);
+        let mover = PlayerId::new([1; 16]);
+        let idle = PlayerId::new([2; 16]);
+        setup_zone_player(&mut state, mover, FixedVec2::new(-FIXED_ONE * 50, 0));
+        setup_zone_player(&mut state, idle, FixedVec2::new(FIXED_ONE * 50, 0));
+
+        let config = MatchConfig { input_delay_ticks: DELAY, ..Default::default() };
+
+        let neutral = BTreeMap::from([(mover, InputFrame::new()), (idle, InputFrame::new())]);
+        let mut moving = neutral.clone();
+        moving.insert(mover, InputFrame::with_movement(127, 0));
+
+        while state.tick < SENT_AT + DELAY + 1 {
+            let inputs = if state.tick + 1 == SENT_AT { &moving } else { &neutral };
+            state.tick_once(inputs, &config);
+
+            let velocity_x = state.players[&mover].velocity.x;
+            if state.tick == SENT_AT + DELAY {
+                assert!(velocity_x > 0, "input from tick {} applies at tick {}", SENT_AT, state.tick);
+            } else if state.tick < SENT_AT + DELAY {
+                assert_eq!(velocity_x, 0, "input applied early at tick {}", state.tick);
+            }
+        }
+
+        // Only the last DELAY ticks of input remain in flight
+        assert_eq!(state.input_delay_queue[&mover].len(), DELAY as usize);
+    }
+
+    #[test]
+    fn test_zone_push_returns_player_inside() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let straggler = PlayerId::new([1; 16]);
+        let other = PlayerId::new([2; 16]);
+
+        // Arena fully shrunk to 100 units half-width; straggler 10 units outside
+        state.arena_shrink = FIXED_ONE;
+        setup_zone_player(&mut state, straggler, FixedVec2::new(FIXED_ONE * 110, 0));
+        setup_zone_player(&mut state, other, FixedVec2::ZERO);
+
+        let config = MatchConfig {
+            zone_push_strength: FIXED_ONE * 20,
+            sudden_death_damage_step: 0,
+            ..Default::default()
+        };
+        let (half_width, _) = state.current_arena_bounds();
+
+        state.tick_once(&BTreeMap::new(), &config);
+        assert!(state.players[&straggler].zone_push.x < 0);
+        assert_eq!(state.players[&straggler].zone_push.y, 0);
+        assert_eq!(state.players[&other].zone_push, FixedVec2::ZERO);
+
+        let mut ticks = 0;
+        while state.players[&straggler].position.x > half_width {
+            state.tick_once(&BTreeMap::new(), &config);
+            ticks += 1;
+            assert!(ticks < 120, "push should bring the player back within 2 seconds");
+        }
+
+        // Back inside: the push switches off
+        state.tick_once(&BTreeMap::new(), &config);
+        assert_eq!(state.players[&straggler].zone_push, FixedVec2::ZERO);
+        assert!(state.players[&straggler].alive);
+    }
+
+    #[test]
+    fn test_configured_arena_scales_bounds_and_keeps_spawns_inside() {
+        let arena_for = |player_count: usize| {
+            let config = MatchConfig::default().with_arena_for_players(player_count);
+            let mut state = MatchState::new([0; 16], 12345);
+            prepare_match(&mut state, &config);
+            for i in 0..player_count {
+                state.add_player(PlayerId::new([i as u8 + 1; 16]));
+            }
+            state.assign_spawn_positions();
+            start_playing(&mut state, &config);
+            state
+        };
+
+        let small = arena_for(4);
+        let large = arena_for(16);
+        assert_eq!(small.arena_shrink, 0);
+        assert_eq!(small.current_arena_bounds(), (ARENA_HALF_WIDTH, ARENA_HALF_HEIGHT));
+        let (width, height) = large.current_arena_bounds();
+        assert!(width > ARENA_HALF_WIDTH && height > ARENA_HALF_HEIGHT);
+        // Arena size is part of the state hash
+        let mut resized = arena_for(4);
+        resized.set_arena_size(width, height);
+        assert_ne!(small.compute_hash(), resized.compute_hash());
+
+        for player in large.players.values() {
+            assert!(player.position.x.abs() <= width && player.position.y.abs() <= height);
+        }
+
+        // The map stretches with the arena; the default arena keeps the stock map
+        let reach = |state: &MatchState| {
+            state.map.spawn_zones().iter().map(|z| z.center.x.abs()).max().unwrap()
+        };
+        assert_eq!(reach(&small), reach(&MatchState::new([0; 16], 12345)));
+        assert!(reach(&large) > reach(&small));
+        for (stock, stretched) in small.map.hubs().iter().zip(large.map.hubs()) {
+            assert!(stretched.center.length_squared() >= stock.center.length_squared());
+        }
+        // Shrines stay on their hubs
+        for (shrine, hub) in large.shrines.iter().zip(large.map.hubs()) {
+            assert_eq!(shrine.position, hub.center);
+        }
+    }
+
+    #[test]
+    fn test_boost_pad_launches_player_crossing_it() {
+        use crate::game::map::{ArcaneCircuitMap, BoostPad};
+
+        let pad = BoostPad {
+            id: 0,
+            center: FixedVec2::ZERO,
+            radius: FIXED_ONE * 2,
+            impulse: FixedVec2::new(0, FIXED_ONE * 30),
+        };
+        let runner = PlayerId::new([1; 16]);
+        // Bystander keeps the match from ending on one player alive
+        let bystander = PlayerId::new([2; 16]);
+        let config = MatchConfig { boost_pads_enabled: true, ..Default::default() };
+        let mut state = MatchState::new([0; 16], 12345);
+        state.map = ArcaneCircuitMap::new().with_boost_pads(vec![pad]);
+        setup_zone_player(&mut state, runner, FixedVec2::new(-FIXED_ONE * 4, 0));
+        setup_zone_player(&mut state, bystander, FixedVec2::new(0, FIXED_ONE * 20));
+
+        // Run right along y = 0 until the pad is reached
+        let inputs: BTreeMap<PlayerId, InputFrame> =
+            [(runner, InputFrame { move_x: 127, move_y: 0, flags: 0 })].into_iter().collect();
+        let mut ticks = 0;
+        while state.players[&runner].boost_pad.is_none() {
+            assert_eq!(state.players[&runner].boost_velocity, FixedVec2::ZERO);
+            state.tick_once(&inputs, &config);
+            ticks += 1;
+            assert!(ticks < 60, "runner should reach the pad within a second");
+        }
+        assert_eq!(state.players[&runner].boost_velocity, pad.impulse);
+
+        // The launch pushes the runner along the pad's direction
+        let y_before = state.players[&runner].position.y;
+        state.tick_once(&inputs, &config);
+        let player = &state.players[&runner];
+        assert!(player.position.y > y_before);
+        // Still on the pad: no second launch, the boost just decays
+        assert_eq!(player.boost_pad, Some(0));
+        assert!(player.boost_velocity.y < pad.impulse.y);
+        assert_eq!(player.boost_velocity.x, 0);
+
+        // Pads do nothing unless the config enables them
+        let mut state = MatchState::new([0; 16], 12345);
+        state.map = ArcaneCircuitMap::new().with_boost_pads(vec![pad]);
+        setup_zone_player(&mut state, runner, FixedVec2::ZERO);
+        setup_zone_player(&mut state, bystander, FixedVec2::new(0, FIXED_ONE * 20));
+        state.tick_once(&BTreeMap::new(), &MatchConfig::default());
+        assert_eq!(state.players[&runner].boost_velocity, FixedVec2::ZERO);
+    }
+
+    #[test]
+    fn test_full_shrink_sudden_death_resolves_match() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let a = PlayerId::new([1; 16]);
+        let b = PlayerId::new([2; 16]);
+
+        // Both players safely inside the fully closed zone
+        state.arena_shrink = FIXED_ONE;
+        setup_zone_player(&mut state, a, FixedVec2::new(-FIXED_ONE * 5, 0));
+        setup_zone_player(&mut state, b, FixedVec2::new(FIXED_ONE * 5, 0));
+        state.players.get_mut(&a).unwrap().score = 50;
+        let inputs = BTreeMap::new();
+
+        // Off by default: a closed zone alone does not end the match
+        let mut idle = state.clone();
+        for _ in 0..300 {
+            idle.tick_once(&inputs, &MatchConfig::default());
+        }
+        assert_eq!(idle.alive_count, 2);
+
+        let config = MatchConfig {
+            sudden_death_damage_step: 8, // ~0.0001 * 65536, lethal after ~2s
+            ..Default::default()
+        };
+
+        let mut ticks = 0;
+        while state.phase == MatchPhase::Playing {
+            state.tick_once(&inputs, &config);
+            ticks += 1;
+            assert!(ticks <= 300, "sudden death should resolve the match quickly");
+        }
+
+        // Equal health means both hit zero together; the leader survives
+        assert_eq!(state.alive_count, 1);
+        assert!(state.players[&a].alive);
+        assert!(!state.players[&b].alive);
+        assert!(state.tick < MATCH_DURATION_TICKS);
+    }
+
+    #[test]
+    fn test_spark_eliminated_at_zero_health() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let spark = PlayerId::new([1; 16]);
+        let other = PlayerId::new([2; 16]);
+        let third = PlayerId::new([3; 16]);
+
+        state.arena_shrink = FIXED_ONE;
+        let (half_width, _) = state.current_arena_bounds();
+        setup_zone_player(&mut state, spark, FixedVec2::new(half_width + FIXED_ONE * 2, 0));
+        setup_zone_player(&mut state, other, FixedVec2::new(-FIXED_ONE * 5, 0));
+        setup_zone_player(&mut state, third, FixedVec2::new(FIXED_ONE * 5, 0));
+        state.players.get_mut(&spark).unwrap().health = 1;
+
+        let config = MatchConfig {
+            zone_damage_rate: FIXED_ONE >> 4,
+            evolve_down_on_damage: true,
+            ..Default::default()
+        };
+
+        state.tick_once(&BTreeMap::new(), &config);
+
+        let player = state.players.get(&spark).unwrap();
+        assert!(!player.alive, "Spark has no lower form and should be eliminated");
+        assert_eq!(state.alive_count, 2);
+    }
+
+    #[test]
+    fn test_solo_survivor_match_runs_until_player_falls() {
+        use crate::game::survivor::SurvivorConfig;
+
+        let solo = PlayerId::new([1; 16]);
+        let config = MatchConfig {
+            survivor: SurvivorConfig { enabled: true, ..Default::default() },
+            ..Default::default()
+        };
+        let mut state = MatchState::new([0; 16], 12345);
+        setup_zone_player(&mut state, solo, FixedVec2::ZERO);
+
+        // One player alive does not end a survivor match
+        let result = state.tick_once(&BTreeMap::new(), &config);
+        assert!(!result.match_ended);
+        assert_eq!(state.waves.wave, 1);
+        assert_eq!(state.enemies.len(), 1);
+
+        // Standing still, the wave eventually catches the player
+        let mut ticks = 1;
+        while state.phase != MatchPhase::Ended {
+            state.tick_once(&BTreeMap::new(), &config);
+            ticks += 1;
+            assert!(ticks < MATCH_DURATION_TICKS, "match should end");
+        }
+        assert!(!state.players[&solo].alive);
+    }
+
+    #[test]
+    fn test_momentum_drifts_after_input_stops() {
+        let runner = PlayerId::new([1; 16]);
+        // Bystander keeps the match from ending on one player alive
+        let bystander = PlayerId::new([2; 16]);
+        let config = MatchConfig {
+            movement_acceleration: FIXED_ONE * 60,
+            ..Default::default()
+        };
+        let mut state = MatchState::new([0; 16], 12345);
+        setup_zone_player(&mut state, runner, FixedVec2::ZERO);
+        setup_zone_player(&mut state, bystander, FixedVec2::new(0, FIXED_ONE * 20));
+
+        // Speed ramps up instead of snapping to form speed
+        let held = BTreeMap::from([(runner, InputFrame::with_movement(127, 0))]);
+        state.tick_once(&held, &config);
+        let top_speed = state.players[&runner].speed(&state.form_table);
+        assert!(state.players[&runner].velocity.x < top_speed / 2);
+        for _ in 0..30 {
+            state.tick_once(&held, &config);
+        }
+
+        // Release: the player keeps sliding, slower every tick
+        let released = BTreeMap::from([(runner, InputFrame::default())]);
+        let mut last_x = state.players[&runner].position.x;
+        let mut last_speed = state.players[&runner].velocity.x;
+        for _ in 0..10 {
+            state.tick_once(&released, &config);
+            let player = &state.players[&runner];
+            assert!(player.position.x > last_x, "player should still be drifting");
+            assert!(player.velocity.x < last_speed, "drift should decay");
+            last_x = player.position.x;
+            last_speed = player.velocity.x;
+        }
+
+        // ...and eventually comes fully to rest
+        for _ in 0..300 {
+            state.tick_once(&released, &config);
+        }
+        assert_eq!(state.players[&runner].velocity, FixedVec2::ZERO);
+
+        // Without acceleration, release stops the player dead
+        let mut state = MatchState::new([0; 16], 12345);
+        setup_zone_player(&mut state, runner, FixedVec2::ZERO);
+        setup_zone_player(&mut state, bystander, FixedVec2::new(0, FIXED_ONE * 20));
+        state.tick_once(&held, &MatchConfig::default());
+        state.tick_once(&released, &MatchConfig::default());
+        let stopped_x = state.players[&runner].position.x;
+        state.tick_once(&released, &MatchConfig::default());
+        assert_eq!(state.players[&runner].position.x, stopped_x);
+    }
+
+    #[test]
+    fn test_config_version_1_predates_milestones() {
+        let current = MatchConfig::for_version(MATCH_CONFIG_VERSION).unwrap();
+        assert_eq!(current.config_hash(), MatchConfig::default().config_hash());
+        assert!(MatchConfig::for_version(MATCH_CONFIG_VERSION + 1).is_none());
+
+        let hunter_score = |config: &MatchConfig| {
+            let mut state = MatchState::new([0; 16], 12345);
+            let ids: Vec<PlayerId> = (1..=3).map(|i| PlayerId::new([i; 16])).collect();
+            for (i, id) in ids.iter().enumerate() {
+                setup_zone_player(&mut state, *id, FixedVec2::new(FIXED_ONE * 20 * i as Fixed, 0));
+            }
+            let placement = state.eliminate_player(&ids[1], Some(&ids[0])).unwrap();
+            state.push_event(GameEvent::player_eliminated(state.tick, ids[1], Some(ids[0]), placement));
+            state.tick_once(&BTreeMap::new(), config);
+            state.players[&ids[0]].score
+        };
+
+        // Same kill, but only v2 pays the first blood bonus
+        let v1 = hunter_score(&MatchConfig::for_version(1).unwrap());
+        let v2 = hunter_score(&MatchConfig::for_version(2).unwrap());
+        assert_eq!(v2 - v1, crate::game::milestone::FIRST_BLOOD_BONUS);
+    }
+
+    #[test]
+    fn test_config_versions_have_pinned_encodings() {
+        // Transcripts commit to these; a change here breaks old proofs
+        let pinned = [
+            (1, "{}", "b4aa7e53892db801d5adf62578cdf89315a9e13778c62452dbded63c3e65d087"),
+            (
+                2,
+                r#"{"milestones_enabled":true}"#,
+                "4cdeb99da3c9a8d6944a8edcdbb0206c77e165d54560272f00f30278a32da8c1",
+            ),
+            (
+                3,
+                r#"{"dash_after_collisions":true,"milestones_enabled":true}"#,
+                "fe8a83cf9419f071ed91b89b93970b49db629bf05d9f679b6de60fa56c705a02",
+            ),
+        ];
+        for (version, bytes, hash) in pinned {
+            let config = MatchConfig::for_version(version).unwrap();
+            assert_eq!(String::from_utf8(config.config_bytes()).unwrap(), bytes);
+            assert_eq!(hex::encode(config.config_hash()), hash, "v{}", version);
+        }
+
+        // Nested fields write only what changed; unknown fields are rejected
+        let mut config = MatchConfig::default();
+        config.rune_spawn.initial_spawn_count += 1;
+        let bytes = String::from_utf8(config.config_bytes()).unwrap();
+        assert_eq!(
+            bytes,
+            format!(
+                concat!(
+                    r#"{{"dash_after_collisions":true,"milestones_enabled":true,"#,
+                    r#""rune_spawn":{{"initial_spawn_count":{}}}}}"#,
+                ),
+                config.rune_spawn.initial_spawn_count
+            )
+        );
+        assert!(MatchConfig::from_config_bytes(br#"{"no_such_field":1}"#).is_none());
+        assert!(MatchConfig::from_config_bytes(b"[]").is_none());
+    }
+
+    #[test]
+    fn test_config_hash_is_stable_and_covers_nested_fields() {
+        let base = MatchConfig::default();
+        assert_eq!(base.config_bytes(), MatchConfig::default().config_bytes());
+
+        // Round trip recovers the same config
+        let recovered = MatchConfig::from_config_bytes(&base.config_bytes()).unwrap();
+        assert_eq!(recovered.config_hash(), base.config_hash());
+
+        let tweaks: Vec<fn(&mut MatchConfig)> = vec![
+            |c| c.rune_spawn.combo_window_ticks += 1,
+            |c| c.rune_spawn.seeded_schedule = Some(vec![]),
+            |c| c.rune_spawn.form_value_scale[4] -= 1,
+            |c| c.shrine.buff_duration += 1,
+            |c| c.ability.mine_form = Some(crate::game::state::Form::Spark),
+            |c| c.revive.channel_ticks += 1,
+            |c| c.respawn.score_penalty += 1,
+            |c| c.survivor.max_enemies += 1,
+            |c| c.arena_half_width += 1,
+            |c| c.boost_pads_enabled = true,
+            |c| c.ability.overcharge_lockout_ticks += 1,
+        ];
+        for tweak in tweaks {
+            let mut config = MatchConfig::default();
+            tweak(&mut config);
+            assert_ne!(config.config_hash(), base.config_hash());
+        }
+    }
+
+    #[test]
+    fn test_tick_once_matches_free_tick() {
+        let config = MatchConfig::default();
+        let mut via_fn = MatchState::new([0; 16], 12345);
+        let mut via_method = MatchState::new([0; 16], 12345);
+        for state in [&mut via_fn, &mut via_method] {
+            setup_zone_player(state, PlayerId::new([1; 16]), FixedVec2::ZERO);
+            setup_zone_player(state, PlayerId::new([2; 16]), FixedVec2::new(20 * FIXED_ONE, 0));
+            state.phase = MatchPhase::Countdown { ticks_remaining: 2 };
+        }
+
+        let mut inputs = BTreeMap::new();
+        inputs.insert(PlayerId::new([1; 16]), InputFrame::with_movement(100, 0));
+        for _ in 0..120 {
+            let a = tick(&mut via_fn, &inputs, &config);
+            let b = via_method.tick_once(&inputs, &config);
+            assert_eq!(a.match_ended, b.match_ended);
+            assert_eq!(a.winner, b.winner);
+            assert_eq!(format!("{:?}", a.events), format!("{:?}", b.events));
+            assert_eq!(via_fn.compute_hash(), via_method.compute_hash());
+        }
+        assert_eq!(via_method.phase, MatchPhase::Playing);
+    }
 }
@@ -39,6 +39,20 @@ pub enum ClientMessage {
 
     /// Player is leaving the match.
     Leave,
+
+    /// Pause the match (private matches only).
+    Pause,
+
+    /// Resume a paused match.
+    Resume,
+
+    /// Rate a finished match and optionally vote for an MVP.
+    RateMatch {
+        /// Player voted most valuable, if any.
+        mvp: Option<[u8; 16]>,
+        /// Rating from 1 to 5 stars.
+        stars: u8,
+    },
 }
 
 /// Authentication request.
@@ -88,6 +102,8 @@ pub enum MatchMode {
     Private,
     /// Practice mode (solo).
     Practice,
+    /// Survivor - cooperative PvE against enemy waves.
+    Survivor,
 }
 
 /// Player input for a game tick.
@@ -139,6 +155,9 @@ pub enum ServerMessage {
     /// Game state update (every tick).
     State(GameStateUpdate),
 
+    /// Present state plus what was missed, sent to a reconnecting player.
+    CatchUp(CatchUpInfo),
+
     /// Game event notification.
     Event(MatchEvent),
 
@@ -228,6 +247,20 @@ pub struct MatchStartInfo {
     pub config_hash: [u8; 32],
     /// Block hash used for seed derivation.
     pub block_hash: [u8; 32],
+    /// Half-width of the walkable map (Q16.16).
+    ///
+    /// Players are confined by the map's hubs, corridors and spawn alcoves
+    /// (see `ArcaneCircuitMap::half_extents`), all of which lie inside the
+    /// box `[-map_half_width, map_half_width] x [-map_half_height,
+    /// map_half_height]` centred on the origin. The map grows with the
+    /// lobby, so clients draw their boundary from these values rather than
+    /// built-in defaults: one server unit is one Unity world unit, and 4.5
+    /// pixels in the Bevy client (the stock map fits its old 900 px ring).
+    #[serde(default)]
+    pub map_half_width: i32,
+    /// Half-height of the walkable map (Q16.16), see `map_half_width`.
+    #[serde(default)]
+    pub map_half_height: i32,
 }
 
 /// Initial player information.
@@ -263,10 +296,76 @@ pub struct GameStateUpdate {
     /// Active shrines (only changed ones).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shrines: Option<Vec<ShrineUpdate>>,
+    /// Boost pads on the map (omitted when the map has none).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boost_pads: Option<Vec<BoostPadUpdate>>,
+    /// Current survivor wave (survivor mode only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wave: Option<u32>,
+    /// Living survivor enemies (survivor mode only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enemies: Option<Vec<EnemyUpdate>>,
+    /// Current standings in canonical placement order (HUD/spectators).
+    #[serde(default)]
+    pub leaderboard: Vec<LeaderboardEntry>,
     /// State hash for verification.
     pub state_hash: [u8; 32],
 }
 
+/// Reconnection catch-up.
+///
+/// Lets a returning client rebuild the present from one message instead of
+/// replaying every event it missed: the authoritative state, plus the
+/// eliminations and form changes since it dropped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatchUpInfo {
+    /// Tick the player disconnected at.
+    pub since_tick: u32,
+    /// Eliminations after `since_tick`, oldest first.
+    pub eliminations: Vec<EliminationSummary>,
+    /// Evolutions and de-evolutions after `since_tick`, oldest first.
+    pub form_changes: Vec<FormChangeSummary>,
+    /// Current authoritative state.
+    pub state: GameStateUpdate,
+}
+
+/// Elimination missed while disconnected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EliminationSummary {
+    /// Tick of the elimination.
+    pub tick: u32,
+    /// Eliminated player.
+    pub victim_id: [u8; 16],
+    /// Credited killer, if any.
+    pub killer_id: Option<[u8; 16]>,
+    /// Final placement.
+    pub placement: u16,
+}
+
+/// Form change missed while disconnected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormChangeSummary {
+    /// Tick of the change.
+    pub tick: u32,
+    /// Player whose form changed.
+    pub player_id: [u8; 16],
+    /// Form before the change.
+    pub old_form: u8,
+    /// Form after the change.
+    pub new_form: u8,
+}
+
+/// Leaderboard row in a state update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    /// Player identifier.
+    pub player_id: [u8; 16],
+    /// Current score.
+    pub score: u32,
+    /// Eliminations credited.
+    pub kills: u32,
+}
+
 /// Player state in update.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerStateUpdate {
@@ -278,6 +377,8 @@ pub struct PlayerStateUpdate {
     pub velocity: [i32; 2],
     /// Player form (evolution level).
     pub form: u8,
+    /// Tier on the match's form ladder (equals `form` on the classic ladder).
+    pub tier: u8,
     /// Current score.
     pub score: u32,
     /// Is player alive.
@@ -305,6 +406,10 @@ pub struct PlayerBuffs {
     pub invulnerable: u32,
     /// Active shrine buffs.
     pub shrine_buffs: Vec<u8>,
+    /// Ability energy (fixed-point).
+    pub energy: i32,
+    /// Energy pool size (fixed-point, 0 when energy is disabled).
+    pub max_energy: i32,
 }
 
 /// Rune update.
@@ -335,6 +440,34 @@ pub struct ShrineUpdate {
     pub controller: Option<[u8; 16]>,
 }
 
+/// Boost pad in update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoostPadUpdate {
+    /// Pad identifier.
+    pub id: u8,
+    /// Center (Fixed as i32).
+    pub position: [i32; 2],
+    /// Trigger radius (Fixed as i32).
+    pub radius: i32,
+    /// Launch velocity granted on entry (Fixed as i32).
+    pub impulse: [i32; 2],
+}
+
+/// Survivor enemy in update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemyUpdate {
+    /// Enemy identifier.
+    pub id: u32,
+    /// Enemy kind (see `EnemyType`).
+    pub enemy_type: u8,
+    /// Position (Fixed as i32).
+    pub position: [i32; 2],
+    /// Velocity (Fixed as i32).
+    pub velocity: [i32; 2],
+    /// Collision radius (Fixed as i32).
+    pub radius: i32,
+}
+
 /// Game events.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "event", rename_all = "snake_case")]
@@ -370,6 +503,50 @@ pub enum MatchEvent {
         victim_id: [u8; 16],
         killer_id: Option<[u8; 16]>,
         victim_form: u8,
+        /// Final placement (0 = the player will respawn).
+        placement: u16,
+    },
+
+    /// Player joined the match in progress (backfill).
+    PlayerJoined {
+        /// Tick the player joined after.
+        tick: u32,
+        /// Joining player.
+        player_id: [u8; 16],
+        /// Spawn position.
+        position: [i32; 2],
+    },
+
+    /// Player was downed and awaits a team revive.
+    PlayerDowned {
+        /// Tick the player went down.
+        tick: u32,
+        /// Downed player.
+        player_id: [u8; 16],
+        /// Player who downed them.
+        killer_id: Option<[u8; 16]>,
+    },
+
+    /// Downed player was revived by a teammate.
+    PlayerRevived {
+        /// Tick of the revive.
+        tick: u32,
+        /// Revived player.
+        player_id: [u8; 16],
+        /// Teammate who channeled the revive.
+        reviver_id: [u8; 16],
+    },
+
+    /// Eliminated player returned to play.
+    PlayerRespawned {
+        /// Tick of the respawn.
+        tick: u32,
+        /// Respawned player.
+        player_id: [u8; 16],
+        /// Respawn position.
+        position: [i32; 2],
+        /// Deaths so far.
+        deaths: u32,
     },
 
     /// Player used ability.
@@ -379,6 +556,130 @@ pub enum MatchEvent {
         ability_type: u8,
     },
 
+    /// Another player captured the shrine this player was contesting.
+    ShrineContestLost {
+        /// Tick of the capture.
+        tick: u32,
+        /// Near-miss player.
+        player_id: [u8; 16],
+        /// Contested shrine.
+        shrine_id: u32,
+        /// Player who captured it.
+        winner_id: [u8; 16],
+    },
+
+    /// Enemy stepped into a mine.
+    MineTriggered {
+        /// Tick of first contact.
+        tick: u32,
+        /// Player caught in the mine.
+        player_id: [u8; 16],
+        /// Owner of the mine.
+        owner_id: [u8; 16],
+    },
+
+    /// Tether bound an enemy to its caster.
+    TetherAttached {
+        /// Tick the tether attached.
+        tick: u32,
+        /// Caster.
+        player_id: [u8; 16],
+        /// Enemy bound by the tether.
+        target_id: [u8; 16],
+    },
+
+    /// Tether expired or broke.
+    TetherReleased {
+        /// Tick the tether released.
+        tick: u32,
+        /// Caster.
+        player_id: [u8; 16],
+        /// Enemy that was bound.
+        target_id: [u8; 16],
+    },
+
+    /// Two abilities interacted.
+    AbilityCombo {
+        /// Tick of the interaction.
+        tick: u32,
+        /// Player whose ability triggered it.
+        player_id: [u8; 16],
+        /// Owner of the other ability involved.
+        other_player: [u8; 16],
+        /// Combo type (see `ComboType`).
+        combo: u8,
+    },
+
+    /// First elimination of the match.
+    FirstBlood {
+        /// Tick of the elimination.
+        tick: u32,
+        /// Player who scored first blood.
+        player_id: [u8; 16],
+        /// Player eliminated.
+        victim_id: [u8; 16],
+        /// Score bonus awarded.
+        bonus: u32,
+    },
+
+    /// First evolution of the match.
+    FirstEvolution {
+        /// Tick of the evolution.
+        tick: u32,
+        /// Player who evolved.
+        player_id: [u8; 16],
+        /// Score bonus awarded.
+        bonus: u32,
+    },
+
+    /// First shrine capture of the match.
+    FirstShrineCapture {
+        /// Tick of the capture.
+        tick: u32,
+        /// Player who captured the shrine.
+        player_id: [u8; 16],
+        /// Shrine captured.
+        shrine_id: u32,
+        /// Score bonus awarded.
+        bonus: u32,
+    },
+
+    /// Rune combo chain reached a milestone.
+    RuneCombo {
+        /// Tick of the milestone collection.
+        tick: u32,
+        /// Player on the combo.
+        player_id: [u8; 16],
+        /// Runes chained so far.
+        combo: u32,
+        /// Bonus points on the milestone rune.
+        bonus: u32,
+    },
+
+    /// Survivor wave began.
+    WaveStarted {
+        /// Tick the wave started.
+        tick: u32,
+        /// Wave number (first wave is 1).
+        wave: u32,
+        /// Enemies the wave will spawn.
+        enemy_count: u32,
+    },
+
+    /// Player destroyed a survivor enemy.
+    EnemyDefeated {
+        /// Tick of the kill.
+        tick: u32,
+        /// Player who destroyed it.
+        player_id: [u8; 16],
+        /// Enemy destroyed.
+        enemy_id: u32,
+        /// Enemy kind (see `EnemyType`).
+        enemy_type: u8,
+        /// Essence (score) awarded.
+        points: u32,
+    },
+
     /// Player captured shrine.
     ShrineCaptured {
         tick: u32,
@@ -401,6 +702,22 @@ pub enum MatchEvent {
 
     /// Match has started (game is now running).
     MatchStarted,
+
+    /// Match was paused by a player.
+    MatchPaused {
+        /// Tick the match is frozen at.
+        tick: u32,
+        /// Player who paused.
+        player_id: [u8; 16],
+    },
+
+    /// Match was resumed.
+    MatchResumed {
+        /// Tick the match resumes from.
+        tick: u32,
+        /// Player who resumed.
+        player_id: [u8; 16],
+    },
 }
 
 /// Match end information.
@@ -416,6 +733,8 @@ pub struct MatchEndInfo {
     pub placements: Vec<PlayerPlacement>,
     /// Final state hash.
     pub final_state_hash: [u8; 32],
+    /// Outcome-only commitment (winner, placements, scores).
+    pub result_hash: [u8; 32],
     /// Proof transcript (for ranked matches).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transcript: Option<Vec<u8>>,
@@ -427,7 +746,7 @@ pub struct PlayerPlacement {
     /// Player identifier.
     pub player_id: [u8; 16],
     /// Final place (1-based).
-    pub place: u8,
+    pub place: u16,
     /// Final score.
     pub score: u32,
     /// Eliminations.
@@ -613,6 +932,7 @@ mod tests {
             MatchMode::Ranked,
             MatchMode::Private,
             MatchMode::Practice,
+            MatchMode::Survivor,
         ];
 
         for mode in modes {
@@ -679,6 +999,7 @@ mod tests {
                 victim_id: [3; 16],
                 killer_id: Some([4; 16]),
                 victim_form: 2,
+                placement: 3,
             },
             MatchEvent::AbilityUsed {
                 tick: 400,
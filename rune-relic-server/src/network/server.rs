@@ -5,6 +5,7 @@
 
 use std::collections::BTreeMap;
 use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::net::{TcpListener, TcpStream};
@@ -22,10 +23,33 @@ use crate::network::protocol::{
     MatchmakingResponse, MatchmakingStatus, GameInput, MatchFoundInfo,
     MatchStartInfo, InitialPlayerInfo, MatchEvent, ErrorCode, MatchMode,
 };
+use crate::proof::transcript::MatchTranscript;
+use crate::proof::verify::verify_transcript_with_artifacts;
 use crate::network::session::{
     SessionId, SessionState, SessionConfig, SessionManager, SessionError, MatchSession,
+    MatchStartData, RATING_WINDOW,
 };
 
+/// Build the `MatchStart` payload sent to clients.
+fn match_start_info(start_data: &MatchStartData) -> MatchStartInfo {
+    MatchStartInfo {
+        match_id: start_data.match_id,
+        rng_seed: start_data.rng_seed,
+        start_tick: start_data.start_tick,
+        players: start_data.players.iter().map(|(id, pos, color)| {
+            InitialPlayerInfo {
+                player_id: *id,
+                position: *pos,
+                color_index: *color,
+            }
+        }).collect(),
+        config_hash: start_data.config_hash,
+        block_hash: start_data.block_hash,
+        map_half_width: start_data.map_half_width,
+        map_half_height: start_data.map_half_height,
+    }
+}
+
 /// Convert a game event to a match event for client broadcasting.
 fn convert_game_event_to_match_event(event: &GameEvent) -> MatchEvent {
     match &event.data {
@@ -34,7 +58,38 @@ fn convert_game_event_to_match_event(event: &GameEvent) -> MatchEvent {
                 tick: event.tick,
                 victim_id: *victim_id.as_bytes(),
                 killer_id: killer_id.map(|id| *id.as_bytes()),
-                victim_form: *placement, // Using placement as form for now
+                // Using placement as form for now (saturates in huge lobbies)
+                victim_form: u8::try_from(*placement).unwrap_or(u8::MAX),
+                placement: *placement,
+            }
+        }
+        GameEventData::PlayerJoined { player_id, position } => {
+            MatchEvent::PlayerJoined {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                position: [position.x, position.y],
+            }
+        }
+        GameEventData::PlayerDowned { player_id, killer_id } => {
+            MatchEvent::PlayerDowned {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                killer_id: killer_id.map(|id| *id.as_bytes()),
+            }
+        }
+        GameEventData::PlayerRevived { player_id, reviver_id } => {
+            MatchEvent::PlayerRevived {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                reviver_id: *reviver_id.as_bytes(),
+            }
+        }
+        GameEventData::PlayerRespawned { player_id, position, deaths } => {
+            MatchEvent::PlayerRespawned {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                position: [position.x, position.y],
+                deaths: *deaths,
             }
         }
         GameEventData::RuneCollected { player_id, rune_id, rune_type, points, .. } => {
@@ -62,6 +117,14 @@ fn convert_game_event_to_match_event(event: &GameEvent) -> MatchEvent {
                 new_form: *new_form as u8,
             }
         }
+        GameEventData::FormDevolved { player_id, old_form, new_form } => {
+            MatchEvent::PlayerEvolved {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                old_form: *old_form as u8,
+                new_form: *new_form as u8,
+            }
+        }
         GameEventData::ShrineActivated { player_id, shrine_id } => {
             MatchEvent::ShrineCaptured {
                 tick: event.tick,
@@ -70,6 +133,14 @@ fn convert_game_event_to_match_event(event: &GameEvent) -> MatchEvent {
                 shrine_type: 0, // Generic shrine
             }
         }
+        GameEventData::ShrineContestLost { player_id, shrine_id, winner_id } => {
+            MatchEvent::ShrineContestLost {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                shrine_id: *shrine_id as u32,
+                winner_id: *winner_id.as_bytes(),
+            }
+        }
         GameEventData::AbilityUsed { player_id, ability_type } => {
             MatchEvent::AbilityUsed {
                 tick: event.tick,
@@ -77,6 +148,82 @@ fn convert_game_event_to_match_event(event: &GameEvent) -> MatchEvent {
                 ability_type: *ability_type,
             }
         }
+        GameEventData::MineTriggered { player_id, owner_id } => {
+            MatchEvent::MineTriggered {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                owner_id: *owner_id.as_bytes(),
+            }
+        }
+        GameEventData::TetherAttached { player_id, target_id } => {
+            MatchEvent::TetherAttached {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                target_id: *target_id.as_bytes(),
+            }
+        }
+        GameEventData::TetherReleased { player_id, target_id } => {
+            MatchEvent::TetherReleased {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                target_id: *target_id.as_bytes(),
+            }
+        }
+        GameEventData::AbilityCombo { player_id, other_player, combo } => {
+            MatchEvent::AbilityCombo {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                other_player: *other_player.as_bytes(),
+                combo: *combo as u8,
+            }
+        }
+        GameEventData::FirstBlood { player_id, victim_id, bonus } => {
+            MatchEvent::FirstBlood {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                victim_id: *victim_id.as_bytes(),
+                bonus: *bonus,
+            }
+        }
+        GameEventData::FirstEvolution { player_id, bonus } => {
+            MatchEvent::FirstEvolution {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                bonus: *bonus,
+            }
+        }
+        GameEventData::FirstShrineCapture { player_id, shrine_id, bonus } => {
+            MatchEvent::FirstShrineCapture {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                shrine_id: *shrine_id as u32,
+                bonus: *bonus,
+            }
+        }
+        GameEventData::RuneCombo { player_id, combo, bonus } => {
+            MatchEvent::RuneCombo {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                combo: *combo,
+                bonus: *bonus,
+            }
+        }
+        GameEventData::WaveStarted { wave, enemy_count } => {
+            MatchEvent::WaveStarted {
+                tick: event.tick,
+                wave: *wave,
+                enemy_count: *enemy_count,
+            }
+        }
+        GameEventData::EnemyDefeated { player_id, enemy_id, enemy_type, points } => {
+            MatchEvent::EnemyDefeated {
+                tick: event.tick,
+                player_id: *player_id.as_bytes(),
+                enemy_id: *enemy_id,
+                enemy_type: *enemy_type as u8,
+                points: *points,
+            }
+        }
         // Events not sent to clients (internal)
         GameEventData::ShrineChannelStarted { .. } => MatchEvent::MatchStarted,
         GameEventData::ShrineChannelInterrupted { .. } => MatchEvent::MatchStarted,
@@ -102,6 +249,8 @@ pub struct ServerConfig {
     pub version: String,
     /// Authentication configuration.
     pub auth: AuthConfig,
+    /// Directory for transcripts that fail post-match verification (None = disabled).
+    pub verification_artifact_dir: Option<PathBuf>,
 }
 
 impl Default for ServerConfig {
@@ -114,6 +263,7 @@ impl Default for ServerConfig {
             enable_ranked: true,
             version: env!("CARGO_PKG_VERSION").to_string(),
             auth: AuthConfig::from_env(),
+            verification_artifact_dir: std::env::var("VERIFICATION_ARTIFACT_DIR").ok().map(PathBuf::from),
         }
     }
 }
@@ -458,6 +608,15 @@ impl GameServer {
             ClientMessage::Leave => {
                 Self::handle_leave(addr, clients, sessions, matchmaking_queue).await;
             }
+            ClientMessage::Pause => {
+                Self::handle_pause(addr, true, clients, sessions, sender).await;
+            }
+            ClientMessage::Resume => {
+                Self::handle_pause(addr, false, clients, sessions, sender).await;
+            }
+            ClientMessage::RateMatch { mvp, stars } => {
+                Self::handle_rate_match(addr, mvp, stars, clients, sessions, sender).await;
+            }
             _ => {
                 debug!("Unhandled message type from {}", addr);
             }
@@ -759,21 +918,7 @@ impl GameServer {
 
                 match start_result {
                     Ok(start_data) => {
-                        // Build MatchStartInfo
-                        let match_start = MatchStartInfo {
-                            match_id: start_data.match_id,
-                            rng_seed: start_data.rng_seed,
-                            start_tick: 0,
-                            players: start_data.players.iter().map(|(id, pos, color)| {
-                                InitialPlayerInfo {
-                                    player_id: *id,
-                                    position: *pos,
-                                    color_index: *color,
-                                }
-                            }).collect(),
-                            config_hash: [0; 32],
-                            block_hash: start_data.block_hash,
-                        };
+                        let match_start = match_start_info(&start_data);
 
                         // Broadcast match start to all players
                         {
@@ -788,9 +933,10 @@ impl GameServer {
                         let session_clone = session.clone();
                         let sessions_clone = sessions.clone();
                         let tick_rate = config.tick_rate;
+                        let artifact_dir = config.verification_artifact_dir.clone();
 
                         tokio::spawn(async move {
-                            Self::run_session_game_loop(session_clone, sessions_clone, tick_rate).await;
+                            Self::run_session_game_loop(session_clone, sessions_clone, tick_rate, artifact_dir).await;
                         });
                     }
                     Err(e) => {
@@ -846,41 +992,118 @@ impl GameServer {
         }
     }
 
+    /// Handle pause/resume request.
+    async fn handle_pause(
+        addr: SocketAddr,
+        pause: bool,
+        clients: &Arc<RwLock<BTreeMap<SocketAddr, ConnectedClient>>>,
+        sessions: &Arc<SessionManager>,
+        sender: &mpsc::Sender<ServerMessage>,
+    ) {
+        let (player_id, session_id) = {
+            let clients = clients.read().await;
+            match clients.get(&addr) {
+                Some(c) => (c.player_id, c.session_id),
+                None => return,
+            }
+        };
+
+        let (Some(player_id), Some(session_id)) = (player_id, session_id) else {
+            let _ = sender.send(ServerMessage::Error(crate::network::protocol::ServerError {
+                code: ErrorCode::NotInMatch,
+                message: "Not in a match".to_string(),
+            })).await;
+            return;
+        };
+
+        let Some(session) = sessions.get_session(&session_id).await else {
+            return;
+        };
+
+        let mut session = session.write().await;
+        let result = if pause {
+            session.pause(&player_id)
+        } else {
+            session.resume(&player_id)
+        };
+
+        match result {
+            Ok(tick) => {
+                let event = if pause {
+                    MatchEvent::MatchPaused { tick, player_id: *player_id.as_bytes() }
+                } else {
+                    MatchEvent::MatchResumed { tick, player_id: *player_id.as_bytes() }
+                };
+                info!("Match {:?} {} at tick {}", &session_id[..4], if pause { "paused" } else { "resumed" }, tick);
+                session.broadcast(ServerMessage::Event(event)).await;
+            }
+            Err(e) => {
+                let _ = sender.send(ServerMessage::Error(crate::network::protocol::ServerError {
+                    code: ErrorCode::InvalidInput,
+                    message: e.to_string(),
+                })).await;
+            }
+        }
+    }
+
+    /// Handle a post-match rating / MVP vote.
+    async fn handle_rate_match(
+        addr: SocketAddr,
+        mvp: Option<[u8; 16]>,
+        stars: u8,
+        clients: &Arc<RwLock<BTreeMap<SocketAddr, ConnectedClient>>>,
+        sessions: &Arc<SessionManager>,
+        sender: &mpsc::Sender<ServerMessage>,
+    ) {
+        let (player_id, session_id) = {
+            let clients = clients.read().await;
+            match clients.get(&addr) {
+                Some(c) => (c.player_id, c.session_id),
+                None => return,
+            }
+        };
+
+        let (Some(player_id), Some(session_id)) = (player_id, session_id) else {
+            let _ = sender.send(ServerMessage::Error(crate::network::protocol::ServerError {
+                code: ErrorCode::NotInMatch,
+                message: "Not in a match".to_string(),
+            })).await;
+            return;
+        };
+
+        let Some(session) = sessions.get_session(&session_id).await else {
+            let _ = sender.send(ServerMessage::Error(crate::network::protocol::ServerError {
+                code: ErrorCode::MatchNotFound,
+                message: "Rating window has closed".to_string(),
+            })).await;
+            return;
+        };
+
+        let mut session = session.write().await;
+        match session.rate_match(&player_id, mvp.map(PlayerId::new), stars) {
+            Ok(true) => debug!("Rating from {} recorded", addr),
+            Ok(false) => debug!("Duplicate rating from {} ignored", addr),
+            Err(e) => {
+                let _ = sender.send(ServerMessage::Error(crate::network::protocol::ServerError {
+                    code: ErrorCode::InvalidInput,
+                    message: e.to_string(),
+                })).await;
+            }
+        }
+    }
+
     /// Run the game loop for a session.
     /// Handles countdown, tick execution at 60Hz, state broadcasting, and match end.
     async fn run_session_game_loop(
         session: Arc<RwLock<MatchSession>>,
         sessions: Arc<SessionManager>,
         tick_rate: u32,
+        artifact_dir: Option<PathBuf>,
     ) {
         let session_id = session.read().await.id;
-        let countdown_duration = session.read().await.config.countdown_duration;
-
-        // Phase 1: Countdown
-        let countdown_secs = countdown_duration.as_secs() as u32;
-        for remaining in (1..=countdown_secs).rev() {
-            // Broadcast countdown event
-            {
-                let s = session.read().await;
-                s.broadcast(ServerMessage::Event(MatchEvent::Countdown { seconds: remaining })).await;
-            }
-            tokio::time::sleep(Duration::from_secs(1)).await;
-        }
-
-        // Transition to playing
-        {
-            let mut s = session.write().await;
-            s.begin_playing();
-            info!("Match {:?} started playing", &session_id[..4]);
-        }
-
-        // Broadcast match start (game is now running)
-        {
-            let s = session.read().await;
-            s.broadcast(ServerMessage::Event(MatchEvent::MatchStarted)).await;
-        }
 
-        // Phase 2: Game tick loop at 60Hz
+        // Game tick loop at 60Hz. The countdown runs as the first simulation
+        // ticks, so play starts on exactly the same tick for every client.
         let tick_duration = Duration::from_micros(1_000_000 / tick_rate as u64);
         let mut tick_interval = interval(tick_duration);
         tick_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
@@ -888,14 +1111,28 @@ impl GameServer {
         // State update throttling: send every 3rd tick (60Hz -> 20Hz)
         const STATE_UPDATE_INTERVAL: u32 = 3;
 
+        let mut last_countdown: Option<u32> = None;
+
         loop {
             tick_interval.tick().await;
 
-            let (match_ended, current_tick, state_update, events) = {
+            let (match_ended, current_tick, state_update, events, countdown, started) = {
                 let mut s = session.write().await;
 
-                // Check if session is still in playing state
-                if s.get_state() != SessionState::Playing {
+                // Hold the simulation while paused (tick does not advance),
+                // but never longer than the pause limit
+                if s.get_state() == SessionState::Paused {
+                    if let Some((tick, host)) = s.resume_expired_pause() {
+                        info!("Match {:?} pause expired, resuming at tick {}", &session_id[..4], tick);
+                        let event = MatchEvent::MatchResumed { tick, player_id: *host.as_bytes() };
+                        s.broadcast(ServerMessage::Event(event)).await;
+                    }
+                    continue;
+                }
+
+                // Check if session is still counting down or playing
+                let counting_down = s.get_state() == SessionState::Countdown;
+                if !counting_down && s.get_state() != SessionState::Playing {
                     break;
                 }
 
@@ -908,17 +1145,32 @@ impl GameServer {
                 let match_ended = tick_result.match_ended;
                 let events = tick_result.events.clone();
                 let current_tick = s.current_tick();
+                let playing = s.get_state() == SessionState::Playing;
 
                 // Generate state update (only on throttled interval)
-                let state_update = if current_tick % STATE_UPDATE_INTERVAL == 0 {
+                let state_update = if playing && current_tick % STATE_UPDATE_INTERVAL == 0 {
                     s.generate_state_update()
                 } else {
                     None
                 };
 
-                (match_ended, current_tick, state_update, events)
+                (match_ended, current_tick, state_update, events, s.countdown_seconds(), counting_down && playing)
             };
 
+            // Broadcast each countdown second once
+            if let Some(seconds) = countdown.filter(|s| Some(*s) != last_countdown) {
+                last_countdown = countdown;
+                let s = session.read().await;
+                s.broadcast(ServerMessage::Event(MatchEvent::Countdown { seconds })).await;
+            }
+
+            // Broadcast match start (game is now running)
+            if started {
+                info!("Match {:?} started playing", &session_id[..4]);
+                let s = session.read().await;
+                s.broadcast(ServerMessage::Event(MatchEvent::MatchStarted)).await;
+            }
+
             // Broadcast events (always, regardless of throttling)
             {
                 let s = session.read().await;
@@ -941,20 +1193,45 @@ impl GameServer {
             }
         }
 
-        // Phase 3: Match end
+        // Match end
         let end_info = {
             let mut s = session.write().await;
             s.finalize()
         };
 
         if let Some(end_info) = end_info {
+            // Re-verify proof transcripts and keep failures for debugging
+            if let (Some(dir), Some(bytes)) = (artifact_dir, end_info.transcript.clone()) {
+                tokio::task::spawn_blocking(move || {
+                    match MatchTranscript::from_bytes(&bytes) {
+                        Ok(transcript) => {
+                            verify_transcript_with_artifacts(&transcript, Some(&dir));
+                        }
+                        Err(e) => warn!("Could not decode transcript for verification: {}", e),
+                    }
+                });
+            }
+
             let s = session.read().await;
             s.broadcast(ServerMessage::MatchEnd(end_info)).await;
             info!("Match {:?} ended", &session_id[..4]);
         }
 
-        // Cleanup session after a delay
-        tokio::time::sleep(Duration::from_secs(5)).await;
+        // Cleanup session after a delay (the window for post-match ratings)
+        tokio::time::sleep(RATING_WINDOW).await;
+        {
+            let s = session.read().await;
+            let ratings = s.ratings();
+            if ratings.votes > 0 {
+                info!(
+                    "Match {:?} rated {} times, average {:.2} stars, top MVP {:?}",
+                    &session_id[..4],
+                    ratings.votes,
+                    ratings.average_stars(),
+                    ratings.top_mvp().map(|(id, votes)| (hex::encode(&id.as_bytes()[..4]), votes)),
+                );
+            }
+        }
         sessions.remove_session(&session_id).await;
     }
 
@@ -971,6 +1248,37 @@ impl GameServer {
 
             let mut queue_guard = queue.write().await;
 
+            // Top up running casual matches before opening new ones
+            let mut not_joined = Vec::new();
+            while let Some(idx) = queue_guard.iter().position(|e| e.mode == MatchMode::Casual) {
+                let Some((session_id, session)) = sessions.find_backfill_session(MatchMode::Casual).await else {
+                    break;
+                };
+                let entry = queue_guard.remove(idx);
+                let joined = {
+                    let mut session = session.write().await;
+                    session.add_player(entry.player_id, entry.sender.clone())
+                        .map(|()| session.match_start_data())
+                };
+
+                match joined {
+                    Ok(Some(start_data)) => {
+                        sessions.register_player(entry.player_id, session_id).await;
+                        Self::assign_client_session(&clients, entry.player_id, session_id).await;
+                        let _ = entry.sender.send(ServerMessage::MatchStart(match_start_info(&start_data))).await;
+                        info!("Player joined running match {:?}", &session_id[..4]);
+                    }
+                    Ok(None) => not_joined.push(entry),
+                    Err(e) => {
+                        debug!("Could not join running match {:?}: {}", &session_id[..4], e);
+                        not_joined.push(entry);
+                    }
+                }
+            }
+            for entry in not_joined.into_iter().rev() {
+                queue_guard.insert(0, entry);
+            }
+
             // Get indices of casual players
             let casual_indices: Vec<usize> = queue_guard.iter()
                 .enumerate()
@@ -999,6 +1307,7 @@ impl GameServer {
                     min_players: 1,  // Allow single-player for testing
                     mode: MatchMode::Casual,
                     generate_proof: false,
+                    allow_backfill: true,
                     ..Default::default()
                 };
 
@@ -1011,14 +1320,7 @@ impl GameServer {
                     for entry in &matched_entries {
                         let _ = session.add_player(entry.player_id, entry.sender.clone());
                         sessions.register_player(entry.player_id, session_id).await;
-
-                        // Update client state
-                        let mut clients_guard = clients.write().await;
-                        for (_, client) in clients_guard.iter_mut() {
-                            if client.player_id == Some(entry.player_id) {
-                                client.session_id = Some(session_id);
-                            }
-                        }
+                        Self::assign_client_session(&clients, entry.player_id, session_id).await;
                     }
 
                     // Notify players
@@ -1057,6 +1359,20 @@ impl GameServer {
         }
     }
 
+    /// Point the client connection of `player_id` at `session_id`.
+    async fn assign_client_session(
+        clients: &RwLock<BTreeMap<SocketAddr, ConnectedClient>>,
+        player_id: PlayerId,
+        session_id: SessionId,
+    ) {
+        let mut clients_guard = clients.write().await;
+        for (_, client) in clients_guard.iter_mut() {
+            if client.player_id == Some(player_id) {
+                client.session_id = Some(session_id);
+            }
+        }
+    }
+
     /// Run cleanup loop.
     async fn run_cleanup_loop(
         clients: Arc<RwLock<BTreeMap<SocketAddr, ConnectedClient>>>,
@@ -9,19 +9,31 @@ use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, RwLock, broadcast};
 
 use crate::core::rng::derive_match_seed;
-use crate::game::input::InputFrame;
-use crate::game::state::{MatchState, PlayerId, MatchPhase};
-use crate::game::tick::{tick, TickResult, MatchConfig};
-use crate::proof::transcript::{MatchTranscript, MatchMetadata, MatchResult};
+use crate::game::input::{InputFrame, PlayerInputBuffer};
+use crate::game::state::{MatchState, MatchPhase, PlayerId, RuneEconomy, PlayerStart};
+use crate::game::tick::{prepare_match, start_playing, TickResult, MatchConfig};
+use crate::game::events::GameEventData;
+use crate::proof::state_diff::StateSnapshot;
+use crate::proof::transcript::{
+    MatchTranscript, MatchMetadata, MatchResult, InitialMatchState, InitialPlayerState,
+    PlayerInputRecord,
+};
 use crate::network::protocol::{
     ServerMessage, GameStateUpdate, PlayerStateUpdate, PlayerBuffs,
     MatchEvent, MatchEndInfo, PlayerPlacement, MatchMode,
-    RuneUpdate, ShrineUpdate,
+    RuneUpdate, ShrineUpdate, BoostPadUpdate, EnemyUpdate, LeaderboardEntry,
+    CatchUpInfo, EliminationSummary, FormChangeSummary,
 };
 
 /// Unique session identifier.
 pub type SessionId = [u8; 16];
 
+/// How long a finished session accepts post-match ratings.
+pub const RATING_WINDOW: Duration = Duration::from_secs(5);
+
+/// Longest a private match stays paused before resuming on its own.
+pub const MAX_PAUSE_DURATION: Duration = Duration::from_secs(300);
+
 /// Session state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SessionState {
@@ -31,6 +43,8 @@ pub enum SessionState {
     Countdown,
     /// Match in progress.
     Playing,
+    /// Match frozen by a player (private matches only).
+    Paused,
     /// Match ended, processing results.
     Ended,
     /// Session closed.
@@ -58,7 +72,7 @@ pub struct SessionConfig {
     pub min_players: usize,
     /// Time to ready up after match found (seconds).
     pub ready_timeout: Duration,
-    /// Countdown duration (seconds).
+    /// Countdown duration, run as simulation ticks before play starts.
     pub countdown_duration: Duration,
     /// Match duration (ticks).
     pub match_duration_ticks: u32,
@@ -68,6 +82,17 @@ pub struct SessionConfig {
     pub generate_proof: bool,
     /// Reconnect timeout in ticks (30 seconds at 60Hz = 1800 ticks).
     pub reconnect_timeout_ticks: u32,
+    /// Let players join a match in progress (never in ranked mode).
+    pub allow_backfill: bool,
+    /// Add buff and form changes found by diffing each tick's state to the
+    /// transcript (see `proof::state_diff`).
+    pub derive_state_events: bool,
+    /// Tick the match clock starts from, for resumed matches and series
+    /// continuations (0 for a fresh match).
+    pub start_tick: u32,
+    /// Split players into this many teams and enable team revives (0 for
+    /// free-for-all).
+    pub team_count: u8,
 }
 
 impl Default for SessionConfig {
@@ -81,6 +106,10 @@ impl Default for SessionConfig {
             mode: MatchMode::Casual,
             generate_proof: false,
             reconnect_timeout_ticks: 1800, // 30 seconds @ 60Hz
+            allow_backfill: false,
+            derive_state_events: false,
+            start_tick: 0,
+            team_count: 0,
         }
     }
 }
@@ -121,12 +150,16 @@ pub struct MatchSession {
     pub config: SessionConfig,
     /// Connected players.
     players: BTreeMap<PlayerId, SessionPlayer>,
+    /// Lobby owner, the only player allowed to pause (first to join).
+    host: Option<PlayerId>,
     /// Game state (when playing).
     game_state: Option<MatchState>,
     /// Match configuration.
     match_config: MatchConfig,
     /// Proof transcript (if generating proofs).
     transcript: Option<MatchTranscript>,
+    /// Per-player input recordings for the transcript (if generating proofs).
+    input_buffers: BTreeMap<PlayerId, PlayerInputBuffer>,
     /// Block hash for seed derivation.
     block_hash: [u8; 32],
     /// When session was created.
@@ -135,8 +168,18 @@ pub struct MatchSession {
     /// When match started (if started).
     #[allow(dead_code)]
     started_at: Option<Instant>,
+    /// When the match was paused (if paused).
+    paused_at: Option<Instant>,
+    /// When the match ended (opens the rating window).
+    ended_at: Option<Instant>,
     /// Event broadcast channel.
     event_tx: broadcast::Sender<MatchEvent>,
+    /// Post-match votes, one per player (telemetry only, not in the transcript).
+    ratings: BTreeMap<PlayerId, (u8, Option<PlayerId>)>,
+    /// Eliminations so far, for reconnect catch-up.
+    eliminations: Vec<EliminationSummary>,
+    /// Form changes so far, for reconnect catch-up.
+    form_changes: Vec<FormChangeSummary>,
 }
 
 impl MatchSession {
@@ -144,28 +187,43 @@ impl MatchSession {
     pub fn new(id: SessionId, config: SessionConfig) -> Self {
         let (event_tx, _) = broadcast::channel(256);
 
+        let mut match_config = MatchConfig::default();
+        match_config.survivor.enabled = config.mode == MatchMode::Survivor;
+        match_config.revive.enabled = config.team_count > 0;
+
         Self {
             id,
             state: SessionState::Lobby,
             config,
             players: BTreeMap::new(),
+            host: None,
             game_state: None,
-            match_config: MatchConfig::default(),
+            match_config,
             transcript: None,
+            input_buffers: BTreeMap::new(),
             block_hash: [0; 32],
             created_at: Instant::now(),
             started_at: None,
+            paused_at: None,
+            ended_at: None,
             event_tx,
+            ratings: BTreeMap::new(),
+            eliminations: Vec::new(),
+            form_changes: Vec::new(),
         }
     }
 
     /// Add a player to the session.
+    ///
+    /// During play this backfills the player into the simulation when
+    /// `allow_backfill` is set and the match is not ranked.
     pub fn add_player(
         &mut self,
         player_id: PlayerId,
         sender: mpsc::Sender<ServerMessage>,
     ) -> Result<(), SessionError> {
-        if self.state != SessionState::Lobby {
+        let backfill = self.backfill_allowed();
+        if self.state != SessionState::Lobby && !backfill {
             return Err(SessionError::MatchInProgress);
         }
 
@@ -177,6 +235,21 @@ impl MatchSession {
             return Err(SessionError::AlreadyInSession);
         }
 
+        if backfill {
+            // Joins between ticks; the PlayerJoined event goes out (and into
+            // the transcript) with the next tick's events
+            let state = self.game_state.as_mut().ok_or(SessionError::InvalidState)?;
+            if !state.backfill_player(player_id) {
+                return Err(SessionError::AlreadyPlayed);
+            }
+            if self.config.generate_proof {
+                self.input_buffers.insert(
+                    player_id,
+                    PlayerInputBuffer::new(player_id, self.id, state.rng_seed),
+                );
+            }
+        }
+
         self.players.insert(player_id, SessionPlayer {
             player_id,
             ready: false,
@@ -186,13 +259,31 @@ impl MatchSession {
             rtt_ms: 0,
             sender,
         });
+        self.host.get_or_insert(player_id);
 
         Ok(())
     }
 
+    /// True if players may join the match in progress.
+    fn backfill_allowed(&self) -> bool {
+        self.state == SessionState::Playing
+            && self.config.allow_backfill
+            && self.config.mode != MatchMode::Ranked
+    }
+
+    /// True if a new player could join the match in progress right now.
+    pub fn has_backfill_slot(&self) -> bool {
+        self.backfill_allowed() && self.players.len() < self.config.max_players
+    }
+
     /// Remove a player from the session.
+    ///
+    /// If the host leaves, the remaining player with the lowest ID takes over.
     pub fn remove_player(&mut self, player_id: &PlayerId) -> bool {
         if self.players.remove(player_id).is_some() {
+            if self.host == Some(*player_id) {
+                self.host = self.players.keys().next().copied();
+            }
             // If in lobby and no players left, close session
             if self.state == SessionState::Lobby && self.players.is_empty() {
                 self.state = SessionState::Closed;
@@ -219,6 +310,9 @@ impl MatchSession {
 
     /// Reconnect a player with a new sender channel.
     /// Returns Some(current_tick) if reconnected, None if player not found or timed out.
+    ///
+    /// A player returning to a running match is sent a `CatchUp` with
+    /// everything missed since the disconnect.
     pub fn reconnect_player(
         &mut self,
         player_id: &PlayerId,
@@ -226,21 +320,69 @@ impl MatchSession {
     ) -> Option<u32> {
         let current_tick = self.game_state.as_ref().map(|s| s.tick).unwrap_or(0);
 
-        if let Some(player) = self.players.get_mut(player_id) {
-            // Check if reconnect is within timeout
-            if let ConnectionState::Disconnected { since_tick } = player.connection_state {
-                let elapsed = current_tick.saturating_sub(since_tick);
-                if elapsed > self.config.reconnect_timeout_ticks {
-                    // Too late to reconnect
-                    return None;
-                }
+        let player = self.players.get_mut(player_id)?;
+        let mut missed_since = None;
+        // Check if reconnect is within timeout
+        if let ConnectionState::Disconnected { since_tick } = player.connection_state {
+            let elapsed = current_tick.saturating_sub(since_tick);
+            if elapsed > self.config.reconnect_timeout_ticks {
+                // Too late to reconnect
+                return None;
             }
+            missed_since = Some(since_tick);
+        }
 
-            player.connection_state = ConnectionState::Connected;
-            player.sender = sender;
-            Some(current_tick)
-        } else {
-            None
+        player.connection_state = ConnectionState::Connected;
+        player.sender = sender;
+
+        if let Some(catch_up) = missed_since.and_then(|since_tick| self.catch_up(since_tick)) {
+            let _ = self.players[player_id].sender.try_send(ServerMessage::CatchUp(catch_up));
+        }
+        Some(current_tick)
+    }
+
+    /// Build a catch-up for a client that has seen nothing after `since_tick`.
+    ///
+    /// Returns None before the match has started.
+    pub fn catch_up(&self, since_tick: u32) -> Option<CatchUpInfo> {
+        let state = self.generate_state_update()?;
+        Some(CatchUpInfo {
+            since_tick,
+            eliminations: self.eliminations.iter()
+                .filter(|e| e.tick > since_tick)
+                .cloned()
+                .collect(),
+            form_changes: self.form_changes.iter()
+                .filter(|c| c.tick > since_tick)
+                .cloned()
+                .collect(),
+            state,
+        })
+    }
+
+    /// Remember the events a reconnecting client needs to catch up on.
+    fn record_catch_up_events(&mut self, result: &TickResult) {
+        for event in &result.events {
+            match &event.data {
+                GameEventData::PlayerEliminated { victim_id, killer_id, placement } => {
+                    self.eliminations.push(EliminationSummary {
+                        tick: event.tick,
+                        victim_id: *victim_id.as_bytes(),
+                        killer_id: killer_id.map(|k| *k.as_bytes()),
+                        placement: *placement,
+                    });
+                }
+                GameEventData::FormEvolved { player_id, old_form, new_form }
+                | GameEventData::FormDevolved { player_id, old_form, new_form } => {
+                    self.form_changes.push(FormChangeSummary {
+                        tick: event.tick,
+                        player_id: *player_id.as_bytes(),
+                        old_form: *old_form as u8,
+                        new_form: *new_form as u8,
+                    });
+                }
+                _ => {}
+            }
         }
     }
 
@@ -315,6 +457,17 @@ impl MatchSession {
 
     /// Start the match.
     pub fn start_match(&mut self) -> Result<MatchStartData, SessionError> {
+        self.start_match_with(&BTreeMap::new())
+    }
+
+    /// Start the match with per-player handicap starts.
+    ///
+    /// Players absent from `starts` begin as a fresh Spark. Starts are part
+    /// of the initial state hash and the transcript's initial snapshot.
+    pub fn start_match_with(
+        &mut self,
+        starts: &BTreeMap<PlayerId, PlayerStart>,
+    ) -> Result<MatchStartData, SessionError> {
         if self.state != SessionState::Lobby {
             return Err(SessionError::InvalidState);
         }
@@ -329,15 +482,32 @@ impl MatchSession {
             .collect();
         let rng_seed = derive_match_seed(&self.block_hash, &self.id, &player_ids);
 
+        // Size the arena to the lobby before anything is committed to it
+        self.match_config = std::mem::take(&mut self.match_config)
+            .with_arena_for_players(self.players.len());
+
         // Initialize game state
-        let mut game_state = MatchState::new(self.id, rng_seed);
+        let mut game_state = MatchState::with_start_tick(self.id, rng_seed, self.config.start_tick);
+        prepare_match(&mut game_state, &self.match_config);
 
         // Add players to game state
         for player_id in self.players.keys() {
             game_state.add_player(*player_id);
+            if let Some(start) = starts.get(player_id) {
+                game_state.apply_player_start(player_id, *start);
+            }
         }
+        game_state.assign_teams(self.config.team_count);
         game_state.assign_spawn_positions();
 
+        // The countdown runs inside the simulation so inputs are ignored
+        // until the exact tick play starts
+        let countdown_ticks = (self.config.countdown_duration.as_millis() as u64
+            * crate::TICK_RATE as u64 / 1000) as u32;
+        game_state.phase = MatchPhase::Countdown {
+            ticks_remaining: countdown_ticks.saturating_sub(1),
+        };
+
         // Create transcript if generating proofs
         if self.config.generate_proof {
             let metadata = MatchMetadata {
@@ -349,13 +519,45 @@ impl MatchSession {
                     .duration_since(std::time::UNIX_EPOCH)
                     .unwrap_or_default()
                     .as_secs(),
-                config_hash: [0; 32], // TODO: hash config
+                config_hash: self.match_config.config_hash(),
             };
-            self.transcript = Some(MatchTranscript::new(metadata));
+            let mut transcript = MatchTranscript::new(metadata);
+            transcript.set_config(&self.match_config);
+            transcript.set_initial_state(InitialMatchState {
+                start_tick: game_state.start_tick,
+                players: game_state.players.values()
+                    .map(|p| InitialPlayerState {
+                        player_id: *p.id.as_bytes(),
+                        position: p.position,
+                        form: p.tier,
+                        score: p.score,
+                        spawn_zone: p.spawn_zone_id,
+                        team: p.team,
+                    })
+                    .collect(),
+                rng_state: game_state.rng.state(),
+                state_hash: game_state.compute_hash(),
+            });
+            self.transcript = Some(transcript);
+            self.input_buffers = game_state.players.keys()
+                .map(|id| (*id, PlayerInputBuffer::new(*id, self.id, rng_seed)))
+                .collect();
         }
 
-        // Collect initial positions
-        let initial_players: Vec<_> = game_state.players.iter()
+        self.game_state = Some(game_state);
+        self.state = SessionState::Countdown;
+        self.started_at = Some(Instant::now());
+
+        self.match_start_data().ok_or(SessionError::InvalidState)
+    }
+
+    /// Start data for the match with players where they stand now.
+    ///
+    /// At the start these are the spawn positions; players joining a match
+    /// in progress get the same message with everyone's current position.
+    pub fn match_start_data(&self) -> Option<MatchStartData> {
+        let state = self.game_state.as_ref()?;
+        let players = state.players.iter()
             .enumerate()
             .map(|(idx, (id, p))| {
                 (
@@ -365,37 +567,104 @@ impl MatchSession {
                 )
             })
             .collect();
+        let (map_half_width, map_half_height) = state.map.half_extents();
 
-        self.game_state = Some(game_state);
-        self.state = SessionState::Countdown;
-        self.started_at = Some(Instant::now());
-
-        Ok(MatchStartData {
+        Some(MatchStartData {
             match_id: self.id,
-            rng_seed,
+            rng_seed: state.rng_seed,
             block_hash: self.block_hash,
-            players: initial_players,
+            players,
+            config_hash: self.match_config.config_hash(),
+            start_tick: state.start_tick,
+            map_half_width,
+            map_half_height,
         })
     }
 
-    /// Transition from countdown to playing.
+    /// Skip the rest of the countdown and start playing immediately.
     pub fn begin_playing(&mut self) {
         if self.state == SessionState::Countdown {
             self.state = SessionState::Playing;
             if let Some(ref mut state) = self.game_state {
-                state.phase = MatchPhase::Playing;
+                start_playing(state, &self.match_config);
             }
         }
     }
 
+    /// Pause the match.
+    ///
+    /// Only the host of a private match may pause; the simulation tick is
+    /// frozen until `resume` is called or `MAX_PAUSE_DURATION` passes.
+    pub fn pause(&mut self, player_id: &PlayerId) -> Result<u32, SessionError> {
+        if self.config.mode != MatchMode::Private {
+            return Err(SessionError::PauseNotAllowed);
+        }
+        self.check_host(player_id)?;
+        if self.state != SessionState::Playing {
+            return Err(SessionError::MatchNotInProgress);
+        }
+
+        self.state = SessionState::Paused;
+        self.paused_at = Some(Instant::now());
+        Ok(self.current_tick())
+    }
+
+    /// Resume a paused match from the tick it was paused at (host only).
+    pub fn resume(&mut self, player_id: &PlayerId) -> Result<u32, SessionError> {
+        self.check_host(player_id)?;
+        if self.state != SessionState::Paused {
+            return Err(SessionError::InvalidState);
+        }
+
+        self.state = SessionState::Playing;
+        self.paused_at = None;
+        Ok(self.current_tick())
+    }
+
+    /// Resume a pause that has lasted `MAX_PAUSE_DURATION`.
+    ///
+    /// Returns the tick and the host the resume is attributed to, or None
+    /// if the match is not paused or the pause has not expired yet.
+    pub fn resume_expired_pause(&mut self) -> Option<(u32, PlayerId)> {
+        let expired = self.state == SessionState::Paused
+            && self.paused_at.is_none_or(|paused| paused.elapsed() >= MAX_PAUSE_DURATION);
+        if !expired {
+            return None;
+        }
+
+        self.state = SessionState::Playing;
+        self.paused_at = None;
+        let host = self.host.or_else(|| self.players.keys().next().copied())?;
+        Some((self.current_tick(), host))
+    }
+
+    /// Current host (lobby owner).
+    pub fn host(&self) -> Option<PlayerId> {
+        self.host
+    }
+
+    /// Reject players other than the host.
+    fn check_host(&self, player_id: &PlayerId) -> Result<(), SessionError> {
+        if !self.players.contains_key(player_id) {
+            return Err(SessionError::PlayerNotFound);
+        }
+        if self.host != Some(*player_id) {
+            return Err(SessionError::NotHost);
+        }
+        Ok(())
+    }
+
     /// Process a game input from a player.
+    ///
+    /// Inputs sent during the countdown are held but have no effect until
+    /// play starts.
     pub fn process_input(
         &mut self,
         player_id: &PlayerId,
         tick: u32,
         input: InputFrame,
     ) -> Result<(), SessionError> {
-        if self.state != SessionState::Playing {
+        if !matches!(self.state, SessionState::Countdown | SessionState::Playing) {
             return Err(SessionError::MatchNotInProgress);
         }
 
@@ -409,7 +678,18 @@ impl MatchSession {
     }
 
     /// Run a single game tick.
+    ///
+    /// During the countdown this advances the simulation's countdown and
+    /// moves the session to `Playing` on the tick play starts.
     pub fn run_tick(&mut self) -> Option<TickResult> {
+        if self.state == SessionState::Countdown {
+            let state = self.game_state.as_mut()?;
+            let result = state.tick_once(&BTreeMap::new(), &self.match_config);
+            if state.phase == MatchPhase::Playing {
+                self.state = SessionState::Playing;
+            }
+            return Some(result);
+        }
         if self.state != SessionState::Playing {
             return None;
         }
@@ -426,8 +706,18 @@ impl MatchSession {
             inputs.insert(*player_id, player.last_input);
         }
 
+        // Record every player the transcript tracks; players who left get
+        // neutral input, as the verifier would replay for them
+        for (player_id, buffer) in &mut self.input_buffers {
+            let input = *inputs.entry(*player_id).or_insert_with(InputFrame::new);
+            buffer.record(state.tick + 1, input);
+        }
+
+        let snapshot = (self.config.generate_proof && self.config.derive_state_events)
+            .then(|| StateSnapshot::capture(state));
+
         // Run the tick
-        let result = tick(state, &inputs, &self.match_config);
+        let result = state.tick_once(&inputs, &self.match_config);
 
         // Record checkpoint in transcript
         if self.config.generate_proof {
@@ -445,9 +735,14 @@ impl MatchSession {
                 for event in &result.events {
                     transcript.record_event(event);
                 }
+                if let Some(snapshot) = snapshot {
+                    transcript.events.extend(snapshot.derive_events(state));
+                }
             }
         }
 
+        self.record_catch_up_events(&result);
+
         // Check if match ended
         if result.match_ended {
             self.state = SessionState::Ended;
@@ -466,11 +761,12 @@ impl MatchSession {
                 position: [p.position.x, p.position.y],
                 velocity: [p.velocity.x, p.velocity.y],
                 form: p.form as u8,
+                tier: p.tier,
                 score: p.score,
                 alive: p.alive,
                 spawn_zone_id: p.spawn_zone_id.map(|id| id as i32).unwrap_or(-1),
                 spawn_zone_active: p.spawn_zone_active,
-                radius: p.radius(),
+                radius: p.radius(&state.form_table),
                 ability_cooldown: p.ability_cooldown,
                 buffs: PlayerBuffs {
                     speed: p.speed_buff_ticks,
@@ -479,11 +775,13 @@ impl MatchSession {
                     shrine_buffs: p.shrine_buffs.iter()
                         .map(|shrine_type| *shrine_type as u8)
                         .collect(),
+                    energy: p.energy,
+                    max_energy: p.max_energy,
                 },
             })
             .collect();
 
-        let include_runes = state.tick <= 3;
+        let include_runes = state.elapsed_ticks() <= 3;
         let runes: Vec<RuneUpdate> = if include_runes {
             state.runes.iter()
                 .filter(|(_, r)| !r.collected)
@@ -509,12 +807,41 @@ impl MatchSession {
             })
             .collect();
 
+        let boost_pads: Vec<BoostPadUpdate> = state.map.boost_pads().iter()
+            .map(|pad| BoostPadUpdate {
+                id: pad.id,
+                position: [pad.center.x, pad.center.y],
+                radius: pad.radius,
+                impulse: [pad.impulse.x, pad.impulse.y],
+            })
+            .collect();
+
+        let enemies: Vec<EnemyUpdate> = state.enemies.values()
+            .map(|e| EnemyUpdate {
+                id: e.id,
+                enemy_type: e.enemy_type as u8,
+                position: [e.position.x, e.position.y],
+                velocity: [e.velocity.x, e.velocity.y],
+                radius: e.enemy_type.radius(),
+            })
+            .collect();
+
         Some(GameStateUpdate {
             tick: state.tick,
-            time_remaining: self.config.match_duration_ticks.saturating_sub(state.tick),
+            time_remaining: self.config.match_duration_ticks.saturating_sub(state.elapsed_ticks()),
             players,
             runes: if include_runes && !runes.is_empty() { Some(runes) } else { None },
             shrines: if shrines.is_empty() { None } else { Some(shrines) },
+            boost_pads: if boost_pads.is_empty() { None } else { Some(boost_pads) },
+            wave: if self.match_config.survivor.enabled { Some(state.waves.wave) } else { None },
+            enemies: if self.match_config.survivor.enabled { Some(enemies) } else { None },
+            leaderboard: state.leaderboard().into_iter()
+                .map(|(id, score, kills)| LeaderboardEntry {
+                    player_id: *id.as_bytes(),
+                    score,
+                    kills,
+                })
+                .collect(),
             state_hash: state.compute_hash(),
         })
     }
@@ -528,28 +855,23 @@ impl MatchSession {
         let state = self.game_state.as_ref()?;
         let final_hash = state.compute_hash();
 
-        // Build placements sorted by score
-        let mut placements: Vec<_> = state.players.iter()
-            .map(|(id, p)| PlayerPlacement {
+        // Build placements in canonical leaderboard order
+        let mut placements: Vec<_> = state.leaderboard().into_iter()
+            .map(|(id, score, kills)| PlayerPlacement {
                 player_id: *id.as_bytes(),
                 place: 0, // Set below
-                score: p.score,
-                eliminations: p.kills,
-                runes_collected: p.runes_collected,
+                score,
+                eliminations: kills,
+                runes_collected: state.players.get(&id).map(|p| p.runes_collected).unwrap_or(0),
             })
             .collect();
 
-        // Sort by score descending
-        placements.sort_by(|a, b| b.score.cmp(&a.score));
-
         // Assign places
         for (i, p) in placements.iter_mut().enumerate() {
-            p.place = (i + 1) as u8;
+            p.place = (i + 1) as u16;
         }
 
-        let winner_id = placements.first()
-            .filter(|p| p.score > 0)
-            .map(|p| p.player_id);
+        let winner_id = state.result_winner().map(|id| *id.as_bytes());
 
         // Finalize transcript
         let transcript_bytes = if self.config.generate_proof {
@@ -562,6 +884,14 @@ impl MatchSession {
                         .collect(),
                     final_state_hash: final_hash,
                 };
+                for (player_id, buffer) in &mut self.input_buffers {
+                    buffer.finalize(state.tick);
+                    transcript.add_player_inputs(PlayerInputRecord {
+                        player_id: *player_id.as_bytes(),
+                        deltas: buffer.deltas().to_vec(),
+                        input_count: buffer.delta_count() as u32,
+                    });
+                }
                 transcript.finalize(result);
                 Some(transcript.to_bytes())
             } else {
@@ -572,6 +902,7 @@ impl MatchSession {
         };
 
         self.state = SessionState::Closed;
+        self.ended_at = Some(Instant::now());
 
         Some(MatchEndInfo {
             match_id: self.id,
@@ -579,6 +910,7 @@ impl MatchSession {
             winner_id,
             placements,
             final_state_hash: final_hash,
+            result_hash: state.compute_result_hash(),
             transcript: transcript_bytes,
         })
     }
@@ -602,10 +934,77 @@ impl MatchSession {
         self.state
     }
 
+    /// Whole seconds left in the countdown (None once playing).
+    pub fn countdown_seconds(&self) -> Option<u32> {
+        match self.game_state.as_ref()?.phase {
+            MatchPhase::Countdown { ticks_remaining } => {
+                Some((ticks_remaining + 1).div_ceil(crate::TICK_RATE))
+            }
+            _ => None,
+        }
+    }
+
     /// Get current tick.
     pub fn current_tick(&self) -> u32 {
         self.game_state.as_ref().map(|s| s.tick).unwrap_or(0)
     }
+
+    /// Whether the match ended less than `RATING_WINDOW` ago.
+    pub fn rating_window_open(&self) -> bool {
+        self.ended_at.is_some_and(|ended| ended.elapsed() < RATING_WINDOW)
+    }
+
+    /// Record a post-match rating and optional MVP vote.
+    ///
+    /// Only players of the finished match may vote, and only their first
+    /// vote counts. Returns false if the player had already voted.
+    pub fn rate_match(
+        &mut self,
+        player_id: &PlayerId,
+        mvp: Option<PlayerId>,
+        stars: u8,
+    ) -> Result<bool, SessionError> {
+        if self.state != SessionState::Closed || self.game_state.is_none() {
+            return Err(SessionError::InvalidState);
+        }
+        if !self.players.contains_key(player_id) {
+            return Err(SessionError::PlayerNotFound);
+        }
+        if !(1..=5).contains(&stars) {
+            return Err(SessionError::InvalidRating);
+        }
+        if let Some(mvp) = mvp {
+            let in_match = self.game_state.as_ref()
+                .is_some_and(|s| s.players.contains_key(&mvp));
+            if !in_match {
+                return Err(SessionError::InvalidRating);
+            }
+        }
+
+        if self.ratings.contains_key(player_id) {
+            return Ok(false);
+        }
+        self.ratings.insert(*player_id, (stars, mvp));
+        Ok(true)
+    }
+
+    /// Aggregate post-match votes for operator telemetry.
+    pub fn ratings(&self) -> MatchRatings {
+        let mut ratings = MatchRatings::default();
+        for (stars, mvp) in self.ratings.values() {
+            ratings.votes += 1;
+            ratings.total_stars += *stars as u32;
+            if let Some(mvp) = mvp {
+                *ratings.mvp_votes.entry(*mvp).or_insert(0) += 1;
+            }
+        }
+        ratings
+    }
+
+    /// Rune economy telemetry for operators (None before the match starts).
+    pub fn rune_economy(&self) -> Option<RuneEconomy> {
+        self.game_state.as_ref().map(|s| s.rune_economy())
+    }
 }
 
 /// Data returned when match starts.
@@ -619,6 +1018,44 @@ pub struct MatchStartData {
     pub block_hash: [u8; 32],
     /// Initial player data: (player_id, position, color_index).
     pub players: Vec<([u8; 16], [i32; 2], u8)>,
+    /// Hash of the simulation config (see `MatchConfig::config_hash`).
+    pub config_hash: [u8; 32],
+    /// Tick the match clock starts from.
+    pub start_tick: u32,
+    /// Half extents of the walkable map (see `MatchStartInfo::map_half_width`).
+    pub map_half_width: i32,
+    /// See `map_half_width`.
+    pub map_half_height: i32,
+}
+
+/// Aggregated post-match ratings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MatchRatings {
+    /// Number of players who voted.
+    pub votes: u32,
+    /// Sum of all star ratings.
+    pub total_stars: u32,
+    /// MVP votes received per player.
+    pub mvp_votes: BTreeMap<PlayerId, u32>,
+}
+
+impl MatchRatings {
+    /// Mean star rating (0.0 with no votes).
+    pub fn average_stars(&self) -> f64 {
+        if self.votes == 0 {
+            return 0.0;
+        }
+        self.total_stars as f64 / self.votes as f64
+    }
+
+    /// Player with the most MVP votes (lowest ID wins ties).
+    pub fn top_mvp(&self) -> Option<(PlayerId, u32)> {
+        self.mvp_votes.iter()
+            .fold(None, |best, (id, votes)| match best {
+                Some((_, top)) if top >= *votes => best,
+                _ => Some((*id, *votes)),
+            })
+    }
 }
 
 /// Session errors.
@@ -651,6 +1088,22 @@ pub enum SessionError {
     /// Player not found.
     #[error("Player not found")]
     PlayerNotFound,
+
+    /// Pausing is not allowed in this match mode.
+    #[error("Pause not allowed in this mode")]
+    PauseNotAllowed,
+
+    /// Only the host may do this.
+    #[error("Only the host can do this")]
+    NotHost,
+
+    /// Rating out of range or MVP not in the match.
+    #[error("Invalid match rating")]
+    InvalidRating,
+
+    /// Player already took part in this match and cannot join it again.
+    #[error("Already played in this match")]
+    AlreadyPlayed,
 }
 
 // =============================================================================
@@ -725,14 +1178,30 @@ impl SessionManager {
         sessions.len()
     }
 
+    /// Find a running match in `mode` that takes late joiners and has room.
+    ///
+    /// Sessions are searched in ID order.
+    pub async fn find_backfill_session(&self, mode: MatchMode) -> Option<(SessionId, Arc<RwLock<MatchSession>>)> {
+        let sessions = self.sessions.read().await;
+        for (id, session) in sessions.iter() {
+            let s = session.read().await;
+            if s.config.mode == mode && s.has_backfill_slot() {
+                return Some((*id, session.clone()));
+            }
+        }
+        None
+    }
+
     /// Cleanup closed sessions.
+    ///
+    /// Sessions still inside their post-match rating window are kept.
     pub async fn cleanup(&self) {
         let mut sessions = self.sessions.write().await;
         let mut to_remove = Vec::new();
 
         for (id, session) in sessions.iter() {
             let s = session.read().await;
-            if s.state == SessionState::Closed {
+            if s.state == SessionState::Closed && !s.rating_window_open() {
                 to_remove.push(*id);
             }
         }
@@ -830,6 +1299,114 @@ mod tests {
         assert_eq!(session.state, SessionState::Countdown);
     }
 
+    #[tokio::test]
+    async fn test_start_sends_configured_arena_bounds() {
+        use crate::core::fixed::FIXED_ONE;
+
+        let mut session = create_test_session();
+        for i in 1..=16 {
+            let id = PlayerId::new([i; 16]);
+            let (tx, _) = mpsc::channel(10);
+            session.add_player(id, tx).unwrap();
+            session.set_player_ready(&id, true);
+        }
+
+        // The lobby size picks the arena, and clients get the map it holds
+        let start_data = session.start_match().unwrap();
+        assert_eq!(session.match_config.arena_half_width, 250 * FIXED_ONE);
+        let state = session.game_state.as_ref().unwrap();
+        assert_eq!(
+            (start_data.map_half_width, start_data.map_half_height),
+            state.map.half_extents()
+        );
+        let (stock_width, stock_height) = crate::game::map::ArcaneCircuitMap::new().half_extents();
+        assert!(start_data.map_half_width > stock_width && start_data.map_half_height > stock_height);
+
+        // Spawns use the stretched map, reaching past the stock alcoves
+        assert!(state.players.values().any(|p| p.position.x.abs() > stock_width));
+        for player in state.players.values() {
+            assert!(player.position.x.abs() <= start_data.map_half_width);
+            assert!(player.position.y.abs() <= start_data.map_half_height);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handicap_start_as_ward_is_in_initial_hash() {
+        use crate::game::state::Form;
+
+        let start = |starts: BTreeMap<PlayerId, PlayerStart>| {
+            let config = SessionConfig { generate_proof: true, ..Default::default() };
+            let mut session = MatchSession::new([0; 16], config);
+            for id in [PlayerId::new([1; 16]), PlayerId::new([2; 16])] {
+                let (tx, _) = mpsc::channel(10);
+                session.add_player(id, tx).unwrap();
+                session.set_player_ready(&id, true);
+            }
+            session.set_block_hash([42; 32]);
+            session.start_match_with(&starts).unwrap();
+            session
+        };
+
+        let ward = PlayerId::new([1; 16]);
+        let handicapped = start(BTreeMap::from([(ward, PlayerStart { tier: 2, score: 0 })]));
+        let even = start(BTreeMap::new());
+
+        // The configured player begins at Ward, the other as a Spark
+        let state = handicapped.game_state.as_ref().unwrap();
+        assert_eq!(state.players[&ward].form, Form::Ward);
+        assert_eq!(state.players[&PlayerId::new([2; 16])].form, Form::Spark);
+
+        // Both the live initial hash and the transcript snapshot reflect it
+        let handicapped_initial = &handicapped.transcript.as_ref().unwrap().initial_state;
+        let even_initial = &even.transcript.as_ref().unwrap().initial_state;
+        assert_eq!(handicapped_initial.state_hash, state.compute_hash());
+        assert_ne!(handicapped_initial.state_hash, even_initial.state_hash);
+        assert_eq!(handicapped_initial.players[0].form, 2);
+        assert_eq!(even_initial.players[0].form, 0);
+    }
+
+    #[tokio::test]
+    async fn test_countdown_ignores_inputs_until_playing() {
+        let config = SessionConfig {
+            countdown_duration: Duration::from_secs(1),
+            ..Default::default()
+        };
+        let mut session = MatchSession::new([0; 16], config);
+        let player1 = PlayerId::new([1; 16]);
+        let player2 = PlayerId::new([2; 16]);
+        let (tx1, _) = mpsc::channel(10);
+        let (tx2, _) = mpsc::channel(10);
+
+        session.add_player(player1, tx1).unwrap();
+        session.add_player(player2, tx2).unwrap();
+        session.set_player_ready(&player1, true);
+        session.set_player_ready(&player2, true);
+        session.start_match().unwrap();
+        let spawn = session.game_state.as_ref().unwrap().players[&player1].position;
+
+        // Held input during the countdown is accepted but moves nobody
+        session.process_input(&player1, 0, InputFrame::with_movement(127, 0)).unwrap();
+        assert_eq!(session.countdown_seconds(), Some(1));
+        for _ in 0..crate::TICK_RATE - 1 {
+            session.run_tick().unwrap();
+            assert_eq!(session.state, SessionState::Countdown);
+        }
+
+        // Play starts on the last countdown tick, still without movement
+        session.run_tick().unwrap();
+        assert_eq!(session.state, SessionState::Playing);
+        assert_eq!(session.countdown_seconds(), None);
+        let state = session.game_state.as_ref().unwrap();
+        assert_eq!(state.tick, 0);
+        assert_eq!(state.players[&player1].position, spawn);
+
+        // First playing tick applies the held input
+        session.run_tick().unwrap();
+        let state = session.game_state.as_ref().unwrap();
+        assert_eq!(state.tick, 1);
+        assert_ne!(state.players[&player1].position, spawn);
+    }
+
     #[tokio::test]
     async fn test_cannot_start_without_ready() {
         let mut session = create_test_session();
@@ -895,6 +1472,475 @@ mod tests {
         assert_eq!(session.current_tick(), 1);
     }
 
+    #[tokio::test]
+    async fn test_backfill_joins_running_match() {
+        use crate::game::events::GameEventData;
+
+        let run = |backfill: bool| {
+            let config = SessionConfig { allow_backfill: true, ..Default::default() };
+            let mut session = MatchSession::new([0; 16], config);
+            for i in 1..=2 {
+                let (tx, _) = mpsc::channel(10);
+                session.add_player(PlayerId::new([i; 16]), tx).unwrap();
+                session.set_player_ready(&PlayerId::new([i; 16]), true);
+            }
+            session.start_match().unwrap();
+            session.begin_playing();
+            for _ in 0..30 {
+                session.run_tick();
+            }
+
+            let late = PlayerId::new([3; 16]);
+            let mut joined_event = false;
+            if backfill {
+                let (tx, _) = mpsc::channel(10);
+                session.add_player(late, tx).unwrap();
+            }
+            for _ in 0..30 {
+                let result = session.run_tick().unwrap();
+                joined_event |= result.events.iter().any(|e| matches!(
+                    e.data,
+                    GameEventData::PlayerJoined { player_id, .. } if player_id == late
+                ));
+            }
+
+            let state = session.game_state.as_ref().unwrap();
+            assert_eq!(state.players.contains_key(&late), backfill);
+            assert_eq!(joined_event, backfill);
+            (state.alive_count, state.compute_hash())
+        };
+
+        let (alive_without, hash_without) = run(false);
+        let (alive_with, hash_with) = run(true);
+        assert_eq!(alive_with, alive_without + 1);
+        assert_ne!(hash_with, hash_without);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_through_session_manager() {
+        let manager = SessionManager::new();
+        let config = SessionConfig { allow_backfill: true, max_players: 3, ..Default::default() };
+        let session_id = manager.create_session(config).await;
+        assert!(manager.find_backfill_session(MatchMode::Casual).await.is_none());
+
+        let handle = manager.get_session(&session_id).await.unwrap();
+        {
+            let mut session = handle.write().await;
+            for i in 1..=2 {
+                let (tx, _) = mpsc::channel(10);
+                session.add_player(PlayerId::new([i; 16]), tx).unwrap();
+                session.set_player_ready(&PlayerId::new([i; 16]), true);
+            }
+            session.start_match().unwrap();
+            session.begin_playing();
+        }
+        let (found, _) = manager.find_backfill_session(MatchMode::Casual).await.unwrap();
+        assert_eq!(found, session_id);
+        assert!(manager.find_backfill_session(MatchMode::Ranked).await.is_none());
+
+        // The joiner gets start data that includes them
+        let mut session = handle.write().await;
+        let late = PlayerId::new([3; 16]);
+        let (tx, _) = mpsc::channel(10);
+        session.add_player(late, tx).unwrap();
+        let start_data = session.match_start_data().unwrap();
+        assert!(start_data.players.iter().any(|(id, _, _)| *id == *late.as_bytes()));
+        drop(session);
+        assert!(manager.find_backfill_session(MatchMode::Casual).await.is_none());
+
+        // A player who left cannot take a second slot in the same match
+        let mut session = handle.write().await;
+        session.remove_player(&late);
+        let (tx, _) = mpsc::channel(10);
+        assert!(matches!(session.add_player(late, tx), Err(SessionError::AlreadyPlayed)));
+        assert_eq!(session.player_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_rejected_in_ranked() {
+        let config = SessionConfig {
+            allow_backfill: true,
+            mode: MatchMode::Ranked,
+            ..Default::default()
+        };
+        let mut session = MatchSession::new([0; 16], config);
+        for i in 1..=2 {
+            let (tx, _) = mpsc::channel(10);
+            session.add_player(PlayerId::new([i; 16]), tx).unwrap();
+            session.set_player_ready(&PlayerId::new([i; 16]), true);
+        }
+        session.set_block_hash([42; 32]);
+        session.start_match().unwrap();
+        session.begin_playing();
+
+        let (tx, _) = mpsc::channel(10);
+        let result = session.add_player(PlayerId::new([3; 16]), tx);
+        assert!(matches!(result, Err(SessionError::MatchInProgress)));
+    }
+
+    #[tokio::test]
+    async fn test_live_session_matches_replay() {
+        use crate::game::tick::replay_match;
+
+        let mut session = create_test_session();
+        let player1 = PlayerId::new([1; 16]);
+        let player2 = PlayerId::new([2; 16]);
+        let (tx1, _) = mpsc::channel(10);
+        let (tx2, _) = mpsc::channel(10);
+
+        session.add_player(player1, tx1).unwrap();
+        session.add_player(player2, tx2).unwrap();
+        session.set_player_ready(&player1, true);
+        session.set_player_ready(&player2, true);
+        session.start_match().unwrap();
+
+        // Snapshot the pre-play state as a verifier would reconstruct it
+        let initial = session.game_state.clone().unwrap();
+
+        session.begin_playing();
+        for _ in 0..30 {
+            session.run_tick();
+        }
+
+        let (replayed, _) = replay_match(initial, &BTreeMap::new(), 30);
+        let live = session.game_state.as_ref().unwrap();
+
+        assert!(!live.shrines.is_empty());
+        assert_eq!(live.shrines.len(), replayed.shrines.len());
+        for (a, b) in live.shrines.iter().zip(&replayed.shrines) {
+            assert_eq!(a.id, b.id);
+            assert_eq!(a.position, b.position);
+        }
+        assert_eq!(live.rng.state(), replayed.rng.state());
+        assert_eq!(live.compute_hash(), replayed.compute_hash());
+    }
+
+    /// Session with one-second countdown that records a transcript.
+    fn recording_session(config: SessionConfig) -> MatchSession {
+        MatchSession::new([7; 16], SessionConfig {
+            countdown_duration: Duration::from_secs(1),
+            generate_proof: true,
+            ..config
+        })
+    }
+
+    /// Play a session to the end with changing inputs.
+    ///
+    /// Returns the finished session and its decoded transcript.
+    fn play_recorded_session(mut session: MatchSession) -> (MatchSession, MatchTranscript) {
+        let ids: Vec<PlayerId> = (1..=3).map(|i| PlayerId::new([i; 16])).collect();
+        for id in &ids {
+            let (tx, _) = mpsc::channel(10);
+            session.add_player(*id, tx).unwrap();
+            session.set_player_ready(id, true);
+        }
+        session.set_block_hash([9; 32]);
+        session.start_match().unwrap();
+
+        let mut tick = 0u32;
+        while session.get_state() != SessionState::Ended {
+            for (i, id) in ids.iter().enumerate() {
+                if (tick / 40 + i as u32).is_multiple_of(3) {
+                    let dx = if (tick / 120).is_multiple_of(2) { 127 } else { -127 };
+                    let input = InputFrame::with_movement(dx, (i as i8 - 1) * 60);
+                    session.process_input(id, tick, input).unwrap();
+                }
+            }
+            session.run_tick().unwrap();
+            tick += 1;
+        }
+
+        let end = session.finalize().unwrap();
+        let transcript = MatchTranscript::from_bytes(&end.transcript.unwrap()).unwrap();
+        (session, transcript)
+    }
+
+    #[tokio::test]
+    async fn test_session_transcript_verifies() {
+        use crate::proof::verify::verify_transcript;
+
+        let (_, transcript) = play_recorded_session(recording_session(SessionConfig::default()));
+
+        assert_eq!(transcript.player_inputs.len(), 3);
+        assert!(transcript.player_inputs.iter().all(|r| r.deltas.len() > 1));
+        let result = verify_transcript(&transcript);
+        assert!(result.valid, "{:?}", result.error);
+
+        // Derived state-diff entries replay too
+        let (_, derived) = play_recorded_session(recording_session(SessionConfig {
+            derive_state_events: true,
+            ..Default::default()
+        }));
+        let result = verify_transcript(&derived);
+        assert!(result.valid, "{:?}", result.error);
+    }
+
+    #[tokio::test]
+    async fn test_survivor_session_transcript_verifies() {
+        use crate::proof::verify::verify_transcript;
+
+        let (session, transcript) = play_recorded_session(recording_session(SessionConfig {
+            mode: MatchMode::Survivor,
+            ..Default::default()
+        }));
+
+        // Enemies actually ran, under rules carried by the transcript
+        assert!(session.game_state.as_ref().unwrap().waves.next_enemy_id > 0);
+        assert_eq!(transcript.config, session.match_config.config_bytes());
+        let result = verify_transcript(&transcript);
+        assert!(result.valid, "{:?}", result.error);
+    }
+
+    #[tokio::test]
+    async fn test_team_session_transcript_verifies() {
+        use crate::proof::verify::verify_transcript;
+
+        let (session, transcript) = play_recorded_session(recording_session(SessionConfig {
+            team_count: 2,
+            ..Default::default()
+        }));
+
+        // Teams alternate in ID order and are recorded for the replay
+        assert!(session.match_config.revive.enabled);
+        let teams: Vec<_> = transcript.initial_state.players.iter().map(|p| p.team).collect();
+        assert_eq!(teams, vec![Some(0), Some(1), Some(0)]);
+        let result = verify_transcript(&transcript);
+        assert!(result.valid, "{:?}", result.error);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_joins_smallest_team() {
+        let config = SessionConfig { allow_backfill: true, team_count: 2, ..Default::default() };
+        let mut session = MatchSession::new([0; 16], config);
+        for i in 1..=3 {
+            let (tx, _) = mpsc::channel(10);
+            session.add_player(PlayerId::new([i; 16]), tx).unwrap();
+            session.set_player_ready(&PlayerId::new([i; 16]), true);
+        }
+        session.start_match().unwrap();
+        session.begin_playing();
+
+        let late = PlayerId::new([4; 16]);
+        let (tx, _) = mpsc::channel(10);
+        session.add_player(late, tx).unwrap();
+        let state = session.game_state.as_ref().unwrap();
+        assert_eq!(state.players[&late].team, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_custom_form_ladder_is_replayed() {
+        use crate::game::form_table::{FormTable, FormTier};
+        use crate::game::state::Form;
+        use crate::proof::verify::verify_transcript;
+
+        // Seven cheap tiers so players evolve during a short match
+        let forms = [
+            Form::Spark, Form::Spark, Form::Glyph, Form::Ward,
+            Form::Arcane, Form::Arcane, Form::Ancient,
+        ];
+        let ladder = FormTable::new(forms.iter()
+            .enumerate()
+            .map(|(i, form)| FormTier {
+                form: *form,
+                speed: 7 * crate::core::fixed::FIXED_ONE,
+                radius: crate::core::fixed::FIXED_ONE * (2 + i as i32) / 2,
+                entry_score: i as u32 * 5,
+            })
+            .collect())
+            .unwrap();
+
+        let mut session = recording_session(SessionConfig::default());
+        session.match_config.form_table = ladder.clone();
+        let (session, transcript) = play_recorded_session(session);
+
+        assert_eq!(session.game_state.as_ref().unwrap().form_table, ladder);
+        assert!(session.game_state.as_ref().unwrap().players.values().any(|p| p.tier > 0));
+        let result = verify_transcript(&transcript);
+        assert!(result.valid, "{:?}", result.error);
+
+        // Replaying under the classic ladder is caught by the config hash
+        let mut stock = transcript.clone();
+        stock.config = MatchConfig::default().config_bytes();
+        assert!(!verify_transcript(&stock).valid);
+    }
+
+    #[tokio::test]
+    async fn test_boost_pad_layout_is_replayed() {
+        use crate::core::fixed::FIXED_ONE;
+        use crate::core::vec2::FixedVec2;
+        use crate::game::map::BoostPad;
+        use crate::proof::verify::verify_transcript;
+
+        let with_pads = |pads: Vec<BoostPad>| {
+            let mut session = recording_session(SessionConfig::default());
+            session.match_config.boost_pads_enabled = true;
+            session.match_config.boost_pads = pads;
+            play_recorded_session(session).1
+        };
+
+        // One pad covering the middle of the map launches everyone upward
+        let padded = with_pads(vec![BoostPad {
+            id: 0,
+            center: FixedVec2::ZERO,
+            radius: 60 * FIXED_ONE,
+            impulse: FixedVec2::new(0, 20 * FIXED_ONE),
+        }]);
+        let bare = with_pads(Vec::new());
+
+        assert_ne!(
+            padded.result.as_ref().unwrap().final_state_hash,
+            bare.result.as_ref().unwrap().final_state_hash
+        );
+        let result = verify_transcript(&padded);
+        assert!(result.valid, "{:?}", result.error);
+    }
+
+    #[tokio::test]
+    async fn test_seeded_rune_schedule_is_replayed() {
+        use crate::core::fixed::FIXED_ONE;
+        use crate::core::vec2::FixedVec2;
+        use crate::game::rune::ScheduledRune;
+        use crate::game::state::RuneType;
+        use crate::proof::verify::verify_transcript;
+
+        // A rune on every spawn alcove, so players pick some up
+        let map = crate::game::map::ArcaneCircuitMap::new();
+        let schedule: Vec<ScheduledRune> = map.spawn_zones().iter()
+            .flat_map(|zone| (-2..=2).map(move |dx| ScheduledRune {
+                tick: 1,
+                rune_type: RuneType::Wisdom,
+                position: FixedVec2::new(zone.center.x + dx * 2 * FIXED_ONE, zone.center.y),
+            }))
+            .collect();
+        let spawned = schedule.len() as u32;
+        let mut session = recording_session(SessionConfig::default());
+        session.match_config.rune_spawn.seeded_schedule = Some(schedule);
+        let (session, transcript) = play_recorded_session(session);
+
+        // Only scheduled runes spawned, some were collected, and the
+        // recorded config replays them
+        let state = session.game_state.as_ref().unwrap();
+        assert_eq!(state.runes_spawned_by_type.iter().sum::<u32>(), spawned);
+        assert!(state.runes_collected_by_type[RuneType::Wisdom as usize] > 0);
+        let result = verify_transcript(&transcript);
+        assert!(result.valid, "{:?}", result.error);
+    }
+
+    #[tokio::test]
+    async fn test_leaderboard_matches_final_placements() {
+        let mut session = create_test_session();
+        let ids: Vec<PlayerId> = (1..=4).map(|i| PlayerId::new([i; 16])).collect();
+        for id in &ids {
+            let (tx, _) = mpsc::channel(10);
+            session.add_player(*id, tx).unwrap();
+            session.set_player_ready(id, true);
+        }
+        session.start_match().unwrap();
+        session.begin_playing();
+
+        // Score ties broken by kills, then by lower ID
+        let stats = [(100, 0), (200, 1), (100, 2), (100, 0)];
+        {
+            let state = session.game_state.as_mut().unwrap();
+            for (id, (score, kills)) in ids.iter().zip(stats) {
+                let player = state.players.get_mut(id).unwrap();
+                player.score = score;
+                player.kills = kills;
+            }
+        }
+
+        let update = session.generate_state_update().unwrap();
+        let hud_order: Vec<[u8; 16]> = update.leaderboard.iter().map(|e| e.player_id).collect();
+        assert_eq!(
+            hud_order,
+            vec![*ids[1].as_bytes(), *ids[2].as_bytes(), *ids[0].as_bytes(), *ids[3].as_bytes()]
+        );
+
+        session.state = SessionState::Ended;
+        let end = session.finalize().unwrap();
+        let final_order: Vec<[u8; 16]> = end.placements.iter().map(|p| p.player_id).collect();
+        assert_eq!(hud_order, final_order);
+    }
+
+    #[tokio::test]
+    async fn test_pause_freezes_tick() {
+        let config = SessionConfig {
+            mode: MatchMode::Private,
+            ..Default::default()
+        };
+        let mut session = MatchSession::new([0; 16], config);
+        let player1 = PlayerId::new([1; 16]);
+        let player2 = PlayerId::new([2; 16]);
+        let (tx1, _) = mpsc::channel(10);
+        let (tx2, _) = mpsc::channel(10);
+
+        session.add_player(player1, tx1).unwrap();
+        session.add_player(player2, tx2).unwrap();
+        session.set_player_ready(&player1, true);
+        session.set_player_ready(&player2, true);
+        session.start_match().unwrap();
+        session.begin_playing();
+
+        // Keep both players alive so the match cannot end on its own
+        for player in session.game_state.as_mut().unwrap().players.values_mut() {
+            player.invulnerable_ticks = 1000;
+        }
+
+        for _ in 0..10 {
+            session.run_tick();
+        }
+        assert!(matches!(session.pause(&player1), Ok(10)));
+        assert_eq!(session.get_state(), SessionState::Paused);
+
+        // Ticks and inputs are ignored while paused
+        for _ in 0..10 {
+            assert!(session.run_tick().is_none());
+        }
+        assert!(session.process_input(&player1, 10, InputFrame::new()).is_err());
+        assert_eq!(session.current_tick(), 10);
+
+        // Only the host (first to join) controls the pause
+        assert!(matches!(session.resume(&player2), Err(SessionError::NotHost)));
+        assert!(matches!(session.resume(&player1), Ok(10)));
+        session.run_tick();
+        assert_eq!(session.current_tick(), 11);
+        assert!(matches!(session.pause(&player2), Err(SessionError::NotHost)));
+        assert_eq!(session.get_state(), SessionState::Playing);
+
+        // A pause left running resumes on its own
+        session.pause(&player1).unwrap();
+        assert!(session.resume_expired_pause().is_none());
+        session.paused_at = Instant::now().checked_sub(MAX_PAUSE_DURATION);
+        assert_eq!(session.resume_expired_pause(), Some((11, player1)));
+        assert_eq!(session.get_state(), SessionState::Playing);
+
+        // Host passes on when the host leaves
+        session.remove_player(&player1);
+        assert_eq!(session.host(), Some(player2));
+        assert!(session.pause(&player2).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_pause_forbidden_outside_private() {
+        for mode in [MatchMode::Ranked, MatchMode::Casual] {
+            let config = SessionConfig { mode, ..Default::default() };
+            let mut session = MatchSession::new([0; 16], config);
+            let player1 = PlayerId::new([1; 16]);
+            let player2 = PlayerId::new([2; 16]);
+            let (tx1, _) = mpsc::channel(10);
+            let (tx2, _) = mpsc::channel(10);
+            session.add_player(player1, tx1).unwrap();
+            session.add_player(player2, tx2).unwrap();
+            session.set_player_ready(&player1, true);
+            session.set_player_ready(&player2, true);
+            session.start_match().unwrap();
+            session.begin_playing();
+
+            assert!(matches!(session.pause(&player1), Err(SessionError::PauseNotAllowed)));
+            assert_eq!(session.get_state(), SessionState::Playing);
+        }
+    }
+
     #[tokio::test]
     async fn test_generate_state_update() {
         let mut session = create_test_session();
@@ -915,6 +1961,29 @@ mod tests {
         assert_eq!(update.players.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_state_update_counts_from_start_tick() {
+        const START_TICK: u32 = 5000;
+
+        let config = SessionConfig { start_tick: START_TICK, ..Default::default() };
+        let mut session = MatchSession::new([0; 16], config);
+        for i in 1..=2 {
+            let id = PlayerId::new([i; 16]);
+            let (tx, _) = mpsc::channel(10);
+            session.add_player(id, tx).unwrap();
+            session.set_player_ready(&id, true);
+        }
+        session.start_match().unwrap();
+        session.begin_playing();
+        session.run_tick().unwrap();
+
+        // One tick into a late-starting match: full clock, initial runes sent
+        let update = session.generate_state_update().unwrap();
+        assert_eq!(update.tick, START_TICK + 1);
+        assert_eq!(update.time_remaining, session.config.match_duration_ticks - 1);
+        assert!(update.runes.is_some_and(|runes| !runes.is_empty()));
+    }
+
     #[tokio::test]
     async fn test_disconnect_marks_player() {
         let mut session = create_test_session();
@@ -957,6 +2026,75 @@ mod tests {
         assert!(session.players.get(&player1).unwrap().is_connected());
     }
 
+    #[tokio::test]
+    async fn test_reconnect_catches_up_on_missed_events() {
+        use crate::core::vec2::FixedVec2;
+        use crate::game::state::{Form, RuneState, RuneType};
+
+        let mut session = create_test_session();
+        let ids: Vec<PlayerId> = (1..=4).map(|i| PlayerId::new([i; 16])).collect();
+        for id in &ids {
+            let (tx, _) = mpsc::channel(10);
+            session.add_player(*id, tx).unwrap();
+            session.set_player_ready(id, true);
+        }
+        session.start_match().unwrap();
+        session.begin_playing();
+        let (absent, hunter, late_prey, early_prey) = (ids[0], ids[1], ids[2], ids[3]);
+
+        // Put `prey` on top of a Glyph hunter so it is eaten next tick
+        let feed = |session: &mut MatchSession, prey: PlayerId, x: i32| {
+            let state = session.game_state.as_mut().unwrap();
+            let table = state.form_table.clone();
+            for (id, player) in state.players.iter_mut() {
+                player.spawn_zone_active = false;
+                if *id == hunter || *id == prey {
+                    player.position = FixedVec2::from_ints(x, 0);
+                }
+            }
+            let hunter_state = state.players.get_mut(&hunter).unwrap();
+            hunter_state.set_tier(1, &table);
+            hunter_state.score = 150;
+        };
+
+        // Seen live: not part of the catch-up
+        feed(&mut session, early_prey, 10);
+        session.run_tick();
+        assert_eq!(session.eliminations.len(), 1);
+
+        session.mark_disconnected(&absent);
+        session.run_tick();
+        // Missed: an elimination, then a rune that evolves the hunter to Ward
+        feed(&mut session, late_prey, -10);
+        session.run_tick();
+        {
+            let state = session.game_state.as_mut().unwrap();
+            state.players.get_mut(&hunter).unwrap().score = 295;
+            state.runes.insert(999, RuneState::new(999, FixedVec2::from_ints(-10, 0), RuneType::Wisdom));
+        }
+        session.run_tick();
+
+        let (new_tx, mut new_rx) = mpsc::channel(10);
+        assert!(session.reconnect_player(&absent, new_tx).is_some());
+        let Ok(ServerMessage::CatchUp(catch_up)) = new_rx.try_recv() else {
+            panic!("reconnecting player should receive a catch-up");
+        };
+
+        let since_tick = catch_up.since_tick;
+        assert_eq!(catch_up.eliminations.len(), 1);
+        let elimination = &catch_up.eliminations[0];
+        assert!(elimination.tick > since_tick);
+        assert_eq!(elimination.victim_id, *late_prey.as_bytes());
+        assert_eq!(elimination.killer_id, Some(*hunter.as_bytes()));
+        assert_eq!(catch_up.form_changes, vec![FormChangeSummary {
+            tick: elimination.tick + 1,
+            player_id: *hunter.as_bytes(),
+            old_form: Form::Glyph as u8,
+            new_form: Form::Ward as u8,
+        }]);
+        assert_eq!(catch_up.state.tick, session.game_state.as_ref().unwrap().tick);
+    }
+
     #[tokio::test]
     async fn test_reconnect_can_check() {
         let mut session = create_test_session();
@@ -1006,4 +2144,71 @@ mod tests {
         assert_eq!(player.last_input.move_x, InputFrame::NO_INPUT);
         assert_eq!(player.last_input.move_y, InputFrame::NO_INPUT);
     }
+
+    #[tokio::test]
+    async fn test_duplicate_ratings_counted_once() {
+        let mut session = create_test_session();
+        let player1 = PlayerId::new([1; 16]);
+        let player2 = PlayerId::new([2; 16]);
+        let (tx1, _) = mpsc::channel(10);
+        let (tx2, _) = mpsc::channel(10);
+
+        session.add_player(player1, tx1).unwrap();
+        session.add_player(player2, tx2).unwrap();
+        session.set_player_ready(&player1, true);
+        session.set_player_ready(&player2, true);
+        session.start_match().unwrap();
+        session.begin_playing();
+
+        // Voting opens only after the match
+        assert!(matches!(session.rate_match(&player1, None, 5), Err(SessionError::InvalidState)));
+        session.state = SessionState::Ended;
+        session.finalize().unwrap();
+
+        assert!(matches!(session.rate_match(&player1, None, 0), Err(SessionError::InvalidRating)));
+        let outsider = PlayerId::new([9; 16]);
+        assert!(matches!(session.rate_match(&player1, Some(outsider), 4), Err(SessionError::InvalidRating)));
+        assert!(matches!(session.rate_match(&outsider, None, 4), Err(SessionError::PlayerNotFound)));
+
+        assert!(session.rate_match(&player1, Some(player2), 4).unwrap());
+        assert!(!session.rate_match(&player1, Some(player2), 5).unwrap());
+        assert!(!session.rate_match(&player1, Some(player1), 1).unwrap());
+        assert!(session.rate_match(&player2, Some(player2), 2).unwrap());
+
+        let ratings = session.ratings();
+        assert_eq!(ratings.votes, 2);
+        assert_eq!(ratings.total_stars, 6);
+        assert_eq!(ratings.average_stars(), 3.0);
+        assert_eq!(ratings.mvp_votes.get(&player2), Some(&2));
+        assert_eq!(ratings.mvp_votes.get(&player1), None);
+        assert_eq!(ratings.top_mvp(), Some((player2, 2)));
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_keeps_sessions_in_rating_window() {
+        let manager = SessionManager::new();
+        let id = manager.create_session(SessionConfig::default()).await;
+        let player = PlayerId::new([1; 16]);
+        {
+            let session = manager.get_session(&id).await.unwrap();
+            let mut session = session.write().await;
+            for id in [player, PlayerId::new([2; 16])] {
+                let (tx, _rx) = mpsc::channel(10);
+                session.add_player(id, tx).unwrap();
+                session.set_player_ready(&id, true);
+            }
+            session.start_match().unwrap();
+            session.begin_playing();
+            session.state = SessionState::Ended;
+            session.finalize().unwrap();
+        }
+
+        manager.cleanup().await;
+        let session = manager.get_session(&id).await.expect("kept for ratings");
+        assert!(session.write().await.rate_match(&player, None, 4).unwrap());
+
+        session.write().await.ended_at = Instant::now().checked_sub(RATING_WINDOW);
+        manager.cleanup().await;
+        assert!(manager.get_session(&id).await.is_none());
+    }
 }
@@ -203,6 +203,9 @@ pub enum CommitmentError {
 
     /// Player IDs in transcript don't match preimage.
     PlayerIdsMismatch,
+
+    /// Revealed transcript differs from the one being verified.
+    TranscriptMismatch,
 }
 
 impl std::fmt::Display for CommitmentError {
@@ -217,6 +220,7 @@ impl std::fmt::Display for CommitmentError {
             }
             Self::MatchIdMismatch => write!(f, "Match ID mismatch"),
             Self::PlayerIdsMismatch => write!(f, "Player IDs mismatch"),
+            Self::TranscriptMismatch => write!(f, "Revealed transcript doesn't match"),
         }
     }
 }
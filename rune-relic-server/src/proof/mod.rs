@@ -13,29 +13,37 @@
 //! │                    PROOF SYSTEM                             │
 //! ├─────────────────────────────────────────────────────────────┤
 //! │  transcript.rs   - Match transcript recording (~20KB/match) │
+//! │  portable.rs     - Stable JSON export of transcripts        │
 //! │  merkle.rs       - Binary Merkle tree for commitments       │
 //! │  public_inputs.rs- M31 field encoding for STWO proofs       │
 //! │  commitment.rs   - Pre-match commitment protocol            │
 //! │  verify.rs       - Verification by replay                   │
+//! │  state_diff.rs   - Derived buff/form events for transcripts │
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
 
 pub mod merkle;
 pub mod transcript;
+pub mod portable;
 pub mod public_inputs;
 pub mod commitment;
 pub mod verify;
+pub mod state_diff;
 
 // Re-export key types
 pub use merkle::{MerkleTree, MerkleProof};
 pub use transcript::{
     MatchTranscript, MatchMetadata, MatchResult,
     InitialMatchState, InitialPlayerState,
-    PlayerInputRecord, StateCheckpoint, TranscriptEvent,
+    PlayerInputRecord, StateCheckpoint, TranscriptEvent, TimelineEntry, IntegrityError,
 };
-pub use public_inputs::{ProofPublicInputs, M31};
+pub use portable::{PORTABLE_SCHEMA, PORTABLE_SCHEMA_VERSION};
+pub use public_inputs::{M31Encoding, ProofPublicInputs, M31};
+pub use state_diff::StateSnapshot;
 pub use commitment::{MatchCommitment, CommitmentPreimage, MatchReveal, CommitmentError};
 pub use verify::{
-    verify_transcript, VerificationResult, VerificationError,
+    verify_transcript, verify_transcript_with_configs, verify_transcript_with_artifacts,
+    persist_failure_artifact, verify_match,
+    VerificationResult, FullVerificationResult, VerificationError,
     CheckpointResult, ProofVerifier, ProofVerificationError,
 };
@@ -0,0 +1,637 @@
+//! Portable Transcript Export
+//!
+//! A stable JSON rendering of [`MatchTranscript`] for analytics tools and
+//! non-Rust verifiers. The binary (bincode) transcript stays canonical; this
+//! schema is a lossless view of it.
+//!
+//! ## Schema (`schema_version` 6)
+//!
+//! Version 2 added the player `score`, and the `player_joined`,
+//! `buff_gained`, `buff_lost` and `form_changed` events. Version 3 added
+//! the embedded `config`, version 4 the player `spawn_zone`, version 5
+//! the player `team`, and version 6 made `start_tick` required.
+//! Importers only accept the current version.
+//!
+//! ```text
+//! {
+//!   "schema": "rune-relic/transcript",
+//!   "schema_version": 4,
+//!   "version": 2,                      // transcript version
+//!   "config_version": 3,               // rules version
+//!   "config": "<hex>",                 // "" for stock rules
+//!   "metadata": {
+//!     "match_id": "<hex16>", "block_hash": "<hex32>",
+//!     "player_ids": ["<hex16>", ...],
+//!     "rng_seed": "<u64>", "start_timestamp": "<u64>",
+//!     "config_hash": "<hex32>"
+//!   },
+//!   "initial_state": {
+//!     "start_tick",
+//!     "players": [{ "player_id", "position": { "x", "y" }, "form", "score",
+//!                  "spawn_zone": null | <u8>, "team": null | <u8> }],
+//!     "rng_state": ["<u64>", "<u64>"], "state_hash": "<hex32>"
+//!   },
+//!   "player_inputs": [{
+//!     "player_id", "input_count",
+//!     "deltas": [{ "tick", "move_x", "move_y", "flags" }]
+//!   }],
+//!   "checkpoints": [{ "tick", "state_hash", "rng_state" }],
+//!   "result": null | {
+//!     "end_tick", "winner_id": null | "<hex16>",
+//!     "placements": [{ "player_id", "placement", "score" }],
+//!     "final_state_hash"
+//!   },
+//!   "events": [{ "type": "player_eliminated" | "form_evolved"
+//!                      | "rune_collected" | "shrine_activated"
+//!                      | "player_joined" | "buff_gained" | "buff_lost"
+//!                      | "form_changed", "tick", ... }]
+//! }
+//! ```
+//!
+//! - IDs and hashes are lowercase hex strings.
+//! - Positions are decimal numbers (Q16.16 values are exact in an f64).
+//! - 64-bit integers are decimal strings, since many JSON parsers only
+//!   keep 53 bits of precision.
+
+use serde::{Serialize, Deserialize};
+use crate::core::fixed::FIXED_ONE;
+use crate::core::vec2::FixedVec2;
+use crate::game::input::{InputDelta, InputFrame};
+use crate::game::state::Placement;
+use super::transcript::{
+    MatchTranscript, MatchMetadata, MatchResult, InitialMatchState, InitialPlayerState,
+    PlayerInputRecord, StateCheckpoint, TranscriptEvent, TranscriptError,
+};
+
+/// Schema identifier written to every portable transcript.
+pub const PORTABLE_SCHEMA: &str = "rune-relic/transcript";
+
+/// Current portable schema version.
+pub const PORTABLE_SCHEMA_VERSION: u32 = 6;
+
+#[derive(Serialize, Deserialize)]
+struct PortableTranscript {
+    schema: String,
+    schema_version: u32,
+    version: u8,
+    config_version: u32,
+    config: String,
+    metadata: PortableMetadata,
+    initial_state: PortableInitialState,
+    player_inputs: Vec<PortableInputRecord>,
+    checkpoints: Vec<PortableCheckpoint>,
+    result: Option<PortableResult>,
+    events: Vec<PortableEvent>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PortableMetadata {
+    match_id: String,
+    block_hash: String,
+    player_ids: Vec<String>,
+    rng_seed: String,
+    start_timestamp: String,
+    config_hash: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PortablePosition {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PortablePlayer {
+    player_id: String,
+    position: PortablePosition,
+    form: u8,
+    score: u32,
+    spawn_zone: Option<u8>,
+    team: Option<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PortableInitialState {
+    start_tick: u32,
+    players: Vec<PortablePlayer>,
+    rng_state: [String; 2],
+    state_hash: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PortableDelta {
+    tick: u32,
+    move_x: i8,
+    move_y: i8,
+    flags: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PortableInputRecord {
+    player_id: String,
+    input_count: u32,
+    deltas: Vec<PortableDelta>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PortableCheckpoint {
+    tick: u32,
+    state_hash: String,
+    rng_state: [String; 2],
+}
+
+#[derive(Serialize, Deserialize)]
+struct PortablePlacement {
+    player_id: String,
+    placement: Placement,
+    score: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PortableResult {
+    end_tick: u32,
+    winner_id: Option<String>,
+    placements: Vec<PortablePlacement>,
+    final_state_hash: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum PortableEvent {
+    PlayerEliminated {
+        tick: u32,
+        victim_id: String,
+        killer_id: Option<String>,
+        placement: Placement,
+    },
+    FormEvolved {
+        tick: u32,
+        player_id: String,
+        new_form: u8,
+    },
+    RuneCollected {
+        tick: u32,
+        player_id: String,
+        rune_id: u32,
+        points: u32,
+    },
+    ShrineActivated {
+        tick: u32,
+        player_id: String,
+        shrine_id: u8,
+    },
+    PlayerJoined {
+        tick: u32,
+        player_id: String,
+    },
+    BuffGained {
+        tick: u32,
+        player_id: String,
+        buff: u8,
+    },
+    BuffLost {
+        tick: u32,
+        player_id: String,
+        buff: u8,
+    },
+    FormChanged {
+        tick: u32,
+        player_id: String,
+        old_form: u8,
+        new_form: u8,
+    },
+}
+
+impl MatchTranscript {
+    /// Export to the portable JSON schema (see module docs).
+    pub fn to_portable_json(&self) -> serde_json::Value {
+        let portable = PortableTranscript {
+            schema: PORTABLE_SCHEMA.to_string(),
+            schema_version: PORTABLE_SCHEMA_VERSION,
+            version: self.version,
+            config_version: self.config_version,
+            config: hex::encode(&self.config),
+            metadata: PortableMetadata {
+                match_id: hex::encode(self.metadata.match_id),
+                block_hash: hex::encode(self.metadata.block_hash),
+                player_ids: self.metadata.player_ids.iter().map(hex::encode).collect(),
+                rng_seed: self.metadata.rng_seed.to_string(),
+                start_timestamp: self.metadata.start_timestamp.to_string(),
+                config_hash: hex::encode(self.metadata.config_hash),
+            },
+            initial_state: PortableInitialState {
+                start_tick: self.initial_state.start_tick,
+                players: self.initial_state.players.iter().map(|p| PortablePlayer {
+                    player_id: hex::encode(p.player_id),
+                    position: PortablePosition {
+                        x: fixed_to_decimal(p.position.x),
+                        y: fixed_to_decimal(p.position.y),
+                    },
+                    form: p.form,
+                    score: p.score,
+                    spawn_zone: p.spawn_zone,
+                    team: p.team,
+                }).collect(),
+                rng_state: rng_to_strings(self.initial_state.rng_state),
+                state_hash: hex::encode(self.initial_state.state_hash),
+            },
+            player_inputs: self.player_inputs.iter().map(|r| PortableInputRecord {
+                player_id: hex::encode(r.player_id),
+                input_count: r.input_count,
+                deltas: r.deltas.iter().map(|d| PortableDelta {
+                    tick: d.tick,
+                    move_x: d.frame.move_x,
+                    move_y: d.frame.move_y,
+                    flags: d.frame.flags,
+                }).collect(),
+            }).collect(),
+            checkpoints: self.checkpoints.iter().map(|c| PortableCheckpoint {
+                tick: c.tick,
+                state_hash: hex::encode(c.state_hash),
+                rng_state: rng_to_strings(c.rng_state),
+            }).collect(),
+            result: self.result.as_ref().map(|r| PortableResult {
+                end_tick: r.end_tick,
+                winner_id: r.winner_id.map(hex::encode),
+                placements: r.placements.iter().map(|(id, placement, score)| PortablePlacement {
+                    player_id: hex::encode(id),
+                    placement: *placement,
+                    score: *score,
+                }).collect(),
+                final_state_hash: hex::encode(r.final_state_hash),
+            }),
+            events: self.events.iter().map(event_to_portable).collect(),
+        };
+
+        serde_json::to_value(portable).expect("Portable transcript serialization should not fail")
+    }
+
+    /// Reconstruct a transcript from the portable JSON schema.
+    pub fn from_portable_json(value: &serde_json::Value) -> Result<Self, TranscriptError> {
+        let portable: PortableTranscript = serde_json::from_value(value.clone())
+            .map_err(|e| TranscriptError::DeserializationFailed(e.to_string()))?;
+
+        if portable.schema != PORTABLE_SCHEMA {
+            return Err(TranscriptError::DeserializationFailed(
+                format!("unknown schema: {}", portable.schema),
+            ));
+        }
+        if portable.schema_version != PORTABLE_SCHEMA_VERSION {
+            return Err(TranscriptError::DeserializationFailed(
+                format!("unsupported schema version: {}", portable.schema_version),
+            ));
+        }
+
+        let metadata = MatchMetadata {
+            match_id: decode_hex(&portable.metadata.match_id)?,
+            block_hash: decode_hex(&portable.metadata.block_hash)?,
+            player_ids: portable.metadata.player_ids.iter()
+                .map(|id| decode_hex(id))
+                .collect::<Result<_, _>>()?,
+            rng_seed: parse_u64(&portable.metadata.rng_seed)?,
+            start_timestamp: parse_u64(&portable.metadata.start_timestamp)?,
+            config_hash: decode_hex(&portable.metadata.config_hash)?,
+        };
+
+        let initial_state = InitialMatchState {
+            start_tick: portable.initial_state.start_tick,
+            players: portable.initial_state.players.iter().map(|p| {
+                Ok(InitialPlayerState {
+                    player_id: decode_hex(&p.player_id)?,
+                    position: FixedVec2::new(
+                        decimal_to_fixed(p.position.x)?,
+                        decimal_to_fixed(p.position.y)?,
+                    ),
+                    form: p.form,
+                    score: p.score,
+                    spawn_zone: p.spawn_zone,
+                    team: p.team,
+                })
+            }).collect::<Result<_, TranscriptError>>()?,
+            rng_state: rng_from_strings(&portable.initial_state.rng_state)?,
+            state_hash: decode_hex(&portable.initial_state.state_hash)?,
+        };
+
+        let player_inputs = portable.player_inputs.iter().map(|r| {
+            Ok(PlayerInputRecord {
+                player_id: decode_hex(&r.player_id)?,
+                deltas: r.deltas.iter().map(|d| InputDelta::new(
+                    d.tick,
+                    InputFrame { move_x: d.move_x, move_y: d.move_y, flags: d.flags },
+                )).collect(),
+                input_count: r.input_count,
+            })
+        }).collect::<Result<_, TranscriptError>>()?;
+
+        let checkpoints = portable.checkpoints.iter().map(|c| {
+            Ok(StateCheckpoint {
+                tick: c.tick,
+                state_hash: decode_hex(&c.state_hash)?,
+                rng_state: rng_from_strings(&c.rng_state)?,
+            })
+        }).collect::<Result<_, TranscriptError>>()?;
+
+        let result = portable.result.as_ref().map(|r| {
+            Ok(MatchResult {
+                end_tick: r.end_tick,
+                winner_id: r.winner_id.as_deref().map(decode_hex).transpose()?,
+                placements: r.placements.iter()
+                    .map(|p| Ok((decode_hex(&p.player_id)?, p.placement, p.score)))
+                    .collect::<Result<_, TranscriptError>>()?,
+                final_state_hash: decode_hex(&r.final_state_hash)?,
+            })
+        }).transpose()?;
+
+        let events = portable.events.iter()
+            .map(event_from_portable)
+            .collect::<Result<_, _>>()?;
+
+        Ok(MatchTranscript {
+            version: portable.version,
+            metadata,
+            config_version: portable.config_version,
+            config: hex::decode(&portable.config)
+                .map_err(|e| TranscriptError::DeserializationFailed(format!("invalid hex: {}", e)))?,
+            initial_state,
+            player_inputs,
+            checkpoints,
+            result,
+            events,
+        })
+    }
+}
+
+fn event_to_portable(event: &TranscriptEvent) -> PortableEvent {
+    match event {
+        TranscriptEvent::PlayerEliminated { tick, victim_id, killer_id, placement } => {
+            PortableEvent::PlayerEliminated {
+                tick: *tick,
+                victim_id: hex::encode(victim_id),
+                killer_id: killer_id.map(hex::encode),
+                placement: *placement,
+            }
+        }
+        TranscriptEvent::FormEvolved { tick, player_id, new_form } => {
+            PortableEvent::FormEvolved {
+                tick: *tick,
+                player_id: hex::encode(player_id),
+                new_form: *new_form,
+            }
+        }
+        TranscriptEvent::RuneCollected { tick, player_id, rune_id, points } => {
+            PortableEvent::RuneCollected {
+                tick: *tick,
+                player_id: hex::encode(player_id),
+                rune_id: *rune_id,
+                points: *points,
+            }
+        }
+        TranscriptEvent::ShrineActivated { tick, player_id, shrine_id } => {
+            PortableEvent::ShrineActivated {
+                tick: *tick,
+                player_id: hex::encode(player_id),
+                shrine_id: *shrine_id,
+            }
+        }
+        TranscriptEvent::PlayerJoined { tick, player_id } => {
+            PortableEvent::PlayerJoined {
+                tick: *tick,
+                player_id: hex::encode(player_id),
+            }
+        }
+        TranscriptEvent::BuffGained { tick, player_id, buff } => {
+            PortableEvent::BuffGained {
+                tick: *tick,
+                player_id: hex::encode(player_id),
+                buff: *buff,
+            }
+        }
+        TranscriptEvent::BuffLost { tick, player_id, buff } => {
+            PortableEvent::BuffLost {
+                tick: *tick,
+                player_id: hex::encode(player_id),
+                buff: *buff,
+            }
+        }
+        TranscriptEvent::FormChanged { tick, player_id, old_form, new_form } => {
+            PortableEvent::FormChanged {
+                tick: *tick,
+                player_id: hex::encode(player_id),
+                old_form: *old_form,
+                new_form: *new_form,
+            }
+        }
+    }
+}
+
+fn event_from_portable(event: &PortableEvent) -> Result<TranscriptEvent, TranscriptError> {
+    Ok(match event {
+        PortableEvent::PlayerEliminated { tick, victim_id, killer_id, placement } => {
+            TranscriptEvent::PlayerEliminated {
+                tick: *tick,
+                victim_id: decode_hex(victim_id)?,
+                killer_id: killer_id.as_deref().map(decode_hex).transpose()?,
+                placement: *placement,
+            }
+        }
+        PortableEvent::FormEvolved { tick, player_id, new_form } => {
+            TranscriptEvent::FormEvolved {
+                tick: *tick,
+                player_id: decode_hex(player_id)?,
+                new_form: *new_form,
+            }
+        }
+        PortableEvent::RuneCollected { tick, player_id, rune_id, points } => {
+            TranscriptEvent::RuneCollected {
+                tick: *tick,
+                player_id: decode_hex(player_id)?,
+                rune_id: *rune_id,
+                points: *points,
+            }
+        }
+        PortableEvent::ShrineActivated { tick, player_id, shrine_id } => {
+            TranscriptEvent::ShrineActivated {
+                tick: *tick,
+                player_id: decode_hex(player_id)?,
+                shrine_id: *shrine_id,
+            }
+        }
+        PortableEvent::PlayerJoined { tick, player_id } => {
+            TranscriptEvent::PlayerJoined {
+                tick: *tick,
+                player_id: decode_hex(player_id)?,
+            }
+        }
+        PortableEvent::BuffGained { tick, player_id, buff } => {
+            TranscriptEvent::BuffGained {
+                tick: *tick,
+                player_id: decode_hex(player_id)?,
+                buff: *buff,
+            }
+        }
+        PortableEvent::BuffLost { tick, player_id, buff } => {
+            TranscriptEvent::BuffLost {
+                tick: *tick,
+                player_id: decode_hex(player_id)?,
+                buff: *buff,
+            }
+        }
+        PortableEvent::FormChanged { tick, player_id, old_form, new_form } => {
+            TranscriptEvent::FormChanged {
+                tick: *tick,
+                player_id: decode_hex(player_id)?,
+                old_form: *old_form,
+                new_form: *new_form,
+            }
+        }
+    })
+}
+
+/// Q16.16 to decimal. Exact: every Q16.16 value is representable in an f64.
+fn fixed_to_decimal(value: i32) -> f64 {
+    value as f64 / FIXED_ONE as f64
+}
+
+/// Decimal back to Q16.16, rejecting values that are not on the fixed grid.
+fn decimal_to_fixed(value: f64) -> Result<i32, TranscriptError> {
+    let raw = value * FIXED_ONE as f64;
+    if raw.fract() != 0.0 || raw < i32::MIN as f64 || raw > i32::MAX as f64 {
+        return Err(TranscriptError::DeserializationFailed(
+            format!("not a Q16.16 value: {}", value),
+        ));
+    }
+    Ok(raw as i32)
+}
+
+fn rng_to_strings(state: [u64; 2]) -> [String; 2] {
+    [state[0].to_string(), state[1].to_string()]
+}
+
+fn rng_from_strings(state: &[String; 2]) -> Result<[u64; 2], TranscriptError> {
+    Ok([parse_u64(&state[0])?, parse_u64(&state[1])?])
+}
+
+fn parse_u64(s: &str) -> Result<u64, TranscriptError> {
+    s.parse()
+        .map_err(|_| TranscriptError::DeserializationFailed(format!("invalid u64: {}", s)))
+}
+
+fn decode_hex<const N: usize>(s: &str) -> Result<[u8; N], TranscriptError> {
+    let bytes = hex::decode(s)
+        .map_err(|e| TranscriptError::DeserializationFailed(format!("invalid hex: {}", e)))?;
+    bytes.try_into().map_err(|_| {
+        TranscriptError::DeserializationFailed(format!("expected {} bytes: {}", N, s))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_transcript() -> MatchTranscript {
+        let mut transcript = MatchTranscript::new(MatchMetadata {
+            match_id: [1; 16],
+            block_hash: [2; 32],
+            player_ids: vec![[3; 16], [4; 16]],
+            rng_seed: u64::MAX - 7,
+            start_timestamp: 1700000000,
+            config_hash: [5; 32],
+        });
+        transcript.set_initial_state(InitialMatchState {
+            start_tick: 0,
+            players: vec![
+                InitialPlayerState {
+                    player_id: [3; 16],
+                    position: FixedVec2::new(-98304, 1), // -1.5, smallest step
+                    form: 0,
+                    score: 0,
+                    spawn_zone: None,
+                    team: None,
+                },
+                InitialPlayerState {
+                    player_id: [4; 16],
+                    position: FixedVec2::new(i32::MAX, i32::MIN),
+                    form: 2,
+                    score: 150,
+                    spawn_zone: Some(3),
+                    team: Some(1),
+                },
+            ],
+            rng_state: [u64::MAX, 42],
+            state_hash: [6; 32],
+        });
+        transcript.add_player_inputs(PlayerInputRecord {
+            player_id: [3; 16],
+            deltas: vec![
+                InputDelta::new(0, InputFrame { move_x: -128, move_y: 127, flags: 0x03 }),
+                InputDelta::new(30, InputFrame::new()),
+            ],
+            input_count: 60,
+        });
+        transcript.add_checkpoint(600, [7; 32], [8, 9]);
+        transcript.events.push(TranscriptEvent::RuneCollected {
+            tick: 12, player_id: [3; 16], rune_id: 5, points: 10,
+        });
+        transcript.events.push(TranscriptEvent::PlayerEliminated {
+            tick: 40, victim_id: [4; 16], killer_id: Some([3; 16]), placement: 2,
+        });
+        transcript.finalize(MatchResult {
+            end_tick: 40,
+            winner_id: Some([3; 16]),
+            placements: vec![([3; 16], 1, 110), ([4; 16], 2, 0)],
+            final_state_hash: [10; 32],
+        });
+        transcript
+    }
+
+    #[test]
+    fn test_portable_json_roundtrip() {
+        let mut transcript = sample_transcript();
+        transcript.set_config(&crate::game::tick::MatchConfig::default());
+        let json = transcript.to_portable_json();
+
+        // Survive a trip through text, as a third-party tool would see it
+        let text = serde_json::to_string(&json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&text).unwrap();
+        let restored = MatchTranscript::from_portable_json(&parsed).unwrap();
+
+        assert_eq!(restored.to_bytes(), transcript.to_bytes());
+    }
+
+    #[test]
+    fn test_portable_schema_field_names() {
+        let json = sample_transcript().to_portable_json();
+
+        assert_eq!(json["schema"], PORTABLE_SCHEMA);
+        assert_eq!(json["schema_version"], PORTABLE_SCHEMA_VERSION);
+        assert_eq!(json["config_version"], 3);
+        assert_eq!(json["config"], "");
+        assert_eq!(json["metadata"]["match_id"], "01".repeat(16));
+        assert_eq!(json["metadata"]["rng_seed"], (u64::MAX - 7).to_string());
+        assert_eq!(json["initial_state"]["players"][0]["position"]["x"], -1.5);
+        assert_eq!(json["initial_state"]["players"][1]["team"], 1);
+        assert_eq!(json["initial_state"]["rng_state"][1], "42");
+        assert_eq!(json["player_inputs"][0]["deltas"][0]["move_x"], -128);
+        assert_eq!(json["player_inputs"][0]["deltas"][0]["flags"], 3);
+        assert_eq!(json["checkpoints"][0]["tick"], 600);
+        assert_eq!(json["result"]["winner_id"], "03".repeat(16));
+        assert_eq!(json["result"]["placements"][0]["score"], 110);
+        assert_eq!(json["events"][0]["type"], "rune_collected");
+        assert_eq!(json["events"][1]["type"], "player_eliminated");
+        assert_eq!(json["events"][1]["killer_id"], "03".repeat(16));
+    }
+
+    #[test]
+    fn test_portable_requires_start_tick() {
+        let mut json = sample_transcript().to_portable_json();
+        json["initial_state"].as_object_mut().unwrap().remove("start_tick");
+        assert!(MatchTranscript::from_portable_json(&json).is_err());
+    }
+
+    #[test]
+    fn test_portable_rejects_off_grid_position() {
+        let mut json = sample_transcript().to_portable_json();
+        json["initial_state"]["players"][0]["position"]["x"] = serde_json::json!(0.1);
+        assert!(MatchTranscript::from_portable_json(&json).is_err());
+    }
+}
@@ -3,16 +3,65 @@
 //! Converts match transcript data into M31 field elements for STWO proofs.
 //! Uses Merkle commitments for large data to keep public input size bounded.
 
+use crate::core::fixed::MAX_SCORE;
 use crate::core::hash::{StateHash, M31_PRIME};
+use crate::game::state::Placement;
 use crate::proof::transcript::MatchTranscript;
 use crate::proof::merkle::MerkleTree;
 
 /// M31 field element (u32 < 2^31 - 1).
 pub type M31 = u32;
 
-/// Total number of M31 field elements in public inputs.
+/// Total number of M31 field elements in the default public input layout.
+///
+/// The default layout is untagged and uses [`M31Encoding::Reduced`]: 8
+/// elements per 32-byte hash and 4 per UUID.
 pub const PUBLIC_INPUT_ELEMENT_COUNT: usize = 66;
 
+/// Total number of M31 field elements in the limb layout.
+///
+/// One [`LIMB_LAYOUT_VERSION`] element, then the default layout's fields
+/// with 16 elements per hash and 8 per UUID.
+pub const LIMB_PUBLIC_INPUT_ELEMENT_COUNT: usize = 131;
+
+/// Leading element of the limb layout, checked on decode.
+///
+/// The default layout is version 1 and carries no version element.
+pub const LIMB_LAYOUT_VERSION: M31 = 2;
+
+/// How 32-bit words are mapped into M31 field elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum M31Encoding {
+    /// One element per word, reduced mod `M31_PRIME`.
+    ///
+    /// Lossy: words `>= M31_PRIME` alias smaller values, so two distinct
+    /// hashes can produce identical public inputs. This is the default
+    /// [`PUBLIC_INPUT_ELEMENT_COUNT`]-element layout.
+    #[default]
+    Reduced,
+    /// Two 16-bit limbs per word (low limb first).
+    ///
+    /// Every limb is below `M31_PRIME`, so the original bytes are fully
+    /// recoverable and the commitment is binding. Serialized in the
+    /// versioned [`LIMB_PUBLIC_INPUT_ELEMENT_COUNT`]-element layout.
+    Limbs,
+}
+
+impl M31Encoding {
+    /// Elements used per 32-byte hash.
+    fn hash_width(self) -> usize {
+        match self {
+            M31Encoding::Reduced => 8,
+            M31Encoding::Limbs => 16,
+        }
+    }
+
+    /// Elements used per UUID.
+    fn uuid_width(self) -> usize {
+        self.hash_width() / 2
+    }
+}
+
 /// Public inputs for STWO proof verification.
 ///
 /// This structure is what goes on-chain or to the verifier.
@@ -20,11 +69,18 @@ pub const PUBLIC_INPUT_ELEMENT_COUNT: usize = 66;
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[derive(Default)]
 pub struct ProofPublicInputs {
-    /// Match ID (encoded as 4 M31 elements).
-    pub match_id: [M31; 4],
+    /// Encoding used for every hash and UUID field below.
+    ///
+    /// With [`M31Encoding::Reduced`] only the first half of each field is
+    /// populated and the values are lossy; with [`M31Encoding::Limbs`] the
+    /// full field is used and decodes back to the exact bytes.
+    pub encoding: M31Encoding,
+
+    /// Match ID (4 M31 elements reduced, 8 as limbs).
+    pub match_id: [M31; 8],
 
-    /// Block hash commitment (8 M31 elements, 256 bits).
-    pub block_hash: [M31; 8],
+    /// Block hash commitment (8 M31 elements reduced, 16 as limbs).
+    pub block_hash: [M31; 16],
 
     /// Player count.
     pub player_count: M31,
@@ -32,49 +88,54 @@ pub struct ProofPublicInputs {
     /// Match duration in ticks.
     pub duration_ticks: M31,
 
-    /// Winner player ID (4 M31 elements, or zeros if no winner).
-    pub winner_id: [M31; 4],
+    /// Winner player ID (encoded like `match_id`, or zeros if no winner).
+    pub winner_id: [M31; 8],
 
-    /// Final placements Merkle root (32 bytes as 8 M31).
-    pub placements_root: [M31; 8],
+    /// Final placements Merkle root (encoded like `block_hash`).
+    pub placements_root: [M31; 16],
 
     /// Input commitments Merkle root.
-    pub inputs_root: [M31; 8],
+    pub inputs_root: [M31; 16],
 
-    /// Initial state hash (8 M31 elements).
-    pub initial_state_hash: [M31; 8],
+    /// Initial state hash.
+    pub initial_state_hash: [M31; 16],
 
-    /// Final state hash (8 M31 elements).
-    pub final_state_hash: [M31; 8],
+    /// Final state hash.
+    pub final_state_hash: [M31; 16],
 
     /// Checkpoint Merkle root (for intermediate state verification).
-    pub checkpoints_root: [M31; 8],
+    pub checkpoints_root: [M31; 16],
 
     /// Events Merkle root (for replay verification).
-    pub events_root: [M31; 8],
+    pub events_root: [M31; 16],
 }
 
 
 impl ProofPublicInputs {
-    /// Generate public inputs from a transcript.
+    /// Generate public inputs from a transcript using the default
+    /// (reduced) encoding.
     pub fn from_transcript(transcript: &MatchTranscript) -> Self {
+        Self::from_transcript_with_encoding(transcript, M31Encoding::default())
+    }
+
+    /// Generate public inputs from a transcript with an explicit encoding.
+    pub fn from_transcript_with_encoding(
+        transcript: &MatchTranscript,
+        encoding: M31Encoding,
+    ) -> Self {
+        let encode_hash = |hash: &StateHash| encode_hash(hash, encoding);
+        let encode_uuid = |uuid: &[u8; 16]| encode_uuid(uuid, encoding);
         let result = transcript.result.as_ref();
 
         // Build Merkle trees for variable-size data
         let placements_root = if let Some(r) = result {
             let placement_bytes: Vec<Vec<u8>> = r.placements.iter()
-                .map(|(id, placement, score)| {
-                    let mut bytes = Vec::with_capacity(21);
-                    bytes.extend_from_slice(id);
-                    bytes.push(*placement);
-                    bytes.extend_from_slice(&score.to_le_bytes());
-                    bytes
-                })
+                .map(|(id, placement, score)| placement_leaf(id, *placement, *score))
                 .collect();
             let mut tree = MerkleTree::from_leaves(&placement_bytes);
-            hash_to_m31(&tree.root())
+            encode_hash(&tree.root())
         } else {
-            [0; 8]
+            [0; 16]
         };
 
         let inputs_root = {
@@ -93,7 +154,7 @@ impl ProofPublicInputs {
                 })
                 .collect();
             let mut tree = MerkleTree::from_leaves(&input_hashes);
-            hash_to_m31(&tree.root())
+            encode_hash(&tree.root())
         };
 
         let checkpoints_root = {
@@ -108,7 +169,7 @@ impl ProofPublicInputs {
                 })
                 .collect();
             let mut tree = MerkleTree::from_leaves(&checkpoint_bytes);
-            hash_to_m31(&tree.root())
+            encode_hash(&tree.root())
         };
 
         let events_root = {
@@ -116,204 +177,273 @@ impl ProofPublicInputs {
                 .map(|event| bincode::serialize(event).unwrap_or_default())
                 .collect();
             let mut tree = MerkleTree::from_leaves(&event_bytes);
-            hash_to_m31(&tree.root())
+            encode_hash(&tree.root())
         };
 
         Self {
-            match_id: uuid_to_m31(&transcript.metadata.match_id),
-            block_hash: hash_to_m31(&transcript.metadata.block_hash),
+            encoding,
+            match_id: encode_uuid(&transcript.metadata.match_id),
+            block_hash: encode_hash(&transcript.metadata.block_hash),
             player_count: transcript.player_count() as M31,
-            duration_ticks: result.map(|r| r.end_tick).unwrap_or(0),
+            duration_ticks: result
+                .map(|r| r.end_tick.saturating_sub(transcript.initial_state.start_tick))
+                .unwrap_or(0),
             winner_id: result
                 .and_then(|r| r.winner_id)
-                .map(|id| uuid_to_m31(&id))
-                .unwrap_or([0; 4]),
+                .map(|id| encode_uuid(&id))
+                .unwrap_or([0; 8]),
             placements_root,
             inputs_root,
-            initial_state_hash: hash_to_m31(&transcript.initial_state.state_hash),
+            initial_state_hash: encode_hash(&transcript.initial_state.state_hash),
             final_state_hash: result
-                .map(|r| hash_to_m31(&r.final_state_hash))
-                .unwrap_or([0; 8]),
+                .map(|r| encode_hash(&r.final_state_hash))
+                .unwrap_or([0; 16]),
             checkpoints_root,
             events_root,
         }
     }
 
-    /// Encode to flat M31 array (for STWO).
+    /// Encode to the default flat M31 array (for STWO).
+    ///
+    /// This is the reduced layout; inputs built with [`M31Encoding::Limbs`]
+    /// must use [`Self::to_m31_limbs_array`] instead.
     pub fn to_m31_array(&self) -> [M31; PUBLIC_INPUT_ELEMENT_COUNT] {
+        debug_assert_eq!(self.encoding, M31Encoding::Reduced);
         let mut arr = [0u32; PUBLIC_INPUT_ELEMENT_COUNT];
-        let mut idx = 0;
-
-        // match_id: 4 elements
-        for &v in &self.match_id {
-            arr[idx] = v;
-            idx += 1;
-        }
-
-        // block_hash: 8 elements
-        for &v in &self.block_hash {
-            arr[idx] = v;
-            idx += 1;
-        }
-
-        // player_count: 1 element
-        arr[idx] = self.player_count;
-        idx += 1;
-
-        // duration_ticks: 1 element
-        arr[idx] = self.duration_ticks;
-        idx += 1;
-
-        // winner_id: 4 elements
-        for &v in &self.winner_id {
-            arr[idx] = v;
-            idx += 1;
-        }
-
-        // placements_root: 8 elements
-        for &v in &self.placements_root {
-            arr[idx] = v;
-            idx += 1;
-        }
-
-        // inputs_root: 8 elements
-        for &v in &self.inputs_root {
-            arr[idx] = v;
-            idx += 1;
-        }
-
-        // initial_state_hash: 8 elements
-        for &v in &self.initial_state_hash {
-            arr[idx] = v;
-            idx += 1;
-        }
-
-        // final_state_hash: 8 elements
-        for &v in &self.final_state_hash {
-            arr[idx] = v;
-            idx += 1;
-        }
-
-        // checkpoints_root: 8 elements
-        for &v in &self.checkpoints_root {
-            arr[idx] = v;
-            idx += 1;
-        }
-
-        // events_root: 8 elements
-        for &v in &self.events_root {
-            arr[idx] = v;
-            idx += 1;
-        }
-
+        let idx = self.write_fields(&mut arr, M31Encoding::Reduced);
         debug_assert_eq!(idx, PUBLIC_INPUT_ELEMENT_COUNT);
         arr
     }
 
-    /// Decode from flat M31 array.
+    /// Decode from the default flat M31 array.
     pub fn from_m31_array(arr: &[M31; PUBLIC_INPUT_ELEMENT_COUNT]) -> Self {
-        let mut idx = 0;
-
-        let mut match_id = [0u32; 4];
-        for slot in &mut match_id {
-            *slot = arr[idx];
-            idx += 1;
-        }
-
-        let mut block_hash = [0u32; 8];
-        for slot in &mut block_hash {
-            *slot = arr[idx];
-            idx += 1;
-        }
-
-        let player_count = arr[idx];
-        idx += 1;
-
-        let duration_ticks = arr[idx];
-        idx += 1;
+        Self::read_fields(arr, M31Encoding::Reduced)
+    }
 
-        let mut winner_id = [0u32; 4];
-        for slot in &mut winner_id {
-            *slot = arr[idx];
-            idx += 1;
-        }
+    /// Encode to the versioned limb layout.
+    pub fn to_m31_limbs_array(&self) -> [M31; LIMB_PUBLIC_INPUT_ELEMENT_COUNT] {
+        debug_assert_eq!(self.encoding, M31Encoding::Limbs);
+        let mut arr = [0u32; LIMB_PUBLIC_INPUT_ELEMENT_COUNT];
+        arr[0] = LIMB_LAYOUT_VERSION;
+        let idx = 1 + self.write_fields(&mut arr[1..], M31Encoding::Limbs);
+        debug_assert_eq!(idx, LIMB_PUBLIC_INPUT_ELEMENT_COUNT);
+        arr
+    }
 
-        let mut placements_root = [0u32; 8];
-        for slot in &mut placements_root {
-            *slot = arr[idx];
-            idx += 1;
+    /// Decode from the versioned limb layout.
+    ///
+    /// Returns `None` if the layout version is not [`LIMB_LAYOUT_VERSION`].
+    pub fn from_m31_limbs_array(arr: &[M31; LIMB_PUBLIC_INPUT_ELEMENT_COUNT]) -> Option<Self> {
+        if arr[0] != LIMB_LAYOUT_VERSION {
+            return None;
         }
+        Some(Self::read_fields(&arr[1..], M31Encoding::Limbs))
+    }
 
-        let mut inputs_root = [0u32; 8];
-        for slot in &mut inputs_root {
-            *slot = arr[idx];
-            idx += 1;
-        }
+    /// Write every field in order with `encoding`'s slot widths.
+    ///
+    /// Returns the number of elements written.
+    fn write_fields(&self, out: &mut [M31], encoding: M31Encoding) -> usize {
+        let hash = encoding.hash_width();
+        let uuid = encoding.uuid_width();
+        let fields: [&[M31]; 11] = [
+            &self.match_id[..uuid],
+            &self.block_hash[..hash],
+            std::slice::from_ref(&self.player_count),
+            std::slice::from_ref(&self.duration_ticks),
+            &self.winner_id[..uuid],
+            &self.placements_root[..hash],
+            &self.inputs_root[..hash],
+            &self.initial_state_hash[..hash],
+            &self.final_state_hash[..hash],
+            &self.checkpoints_root[..hash],
+            &self.events_root[..hash],
+        ];
 
-        let mut initial_state_hash = [0u32; 8];
-        for slot in &mut initial_state_hash {
-            *slot = arr[idx];
-            idx += 1;
+        let mut idx = 0;
+        for field in fields {
+            out[idx..idx + field.len()].copy_from_slice(field);
+            idx += field.len();
         }
+        idx
+    }
 
-        let mut final_state_hash = [0u32; 8];
-        for slot in &mut final_state_hash {
-            *slot = arr[idx];
-            idx += 1;
-        }
+    /// Read every field in order with `encoding`'s slot widths.
+    fn read_fields(arr: &[M31], encoding: M31Encoding) -> Self {
+        let hash = encoding.hash_width();
+        let uuid = encoding.uuid_width();
+        let mut inputs = Self { encoding, ..Default::default() };
+        let mut idx = 0;
+        let mut take = |slot: &mut [M31]| {
+            slot.copy_from_slice(&arr[idx..idx + slot.len()]);
+            idx += slot.len();
+        };
 
-        let mut checkpoints_root = [0u32; 8];
-        for slot in &mut checkpoints_root {
-            *slot = arr[idx];
-            idx += 1;
-        }
+        take(&mut inputs.match_id[..uuid]);
+        take(&mut inputs.block_hash[..hash]);
+        take(std::slice::from_mut(&mut inputs.player_count));
+        take(std::slice::from_mut(&mut inputs.duration_ticks));
+        take(&mut inputs.winner_id[..uuid]);
+        take(&mut inputs.placements_root[..hash]);
+        take(&mut inputs.inputs_root[..hash]);
+        take(&mut inputs.initial_state_hash[..hash]);
+        take(&mut inputs.final_state_hash[..hash]);
+        take(&mut inputs.checkpoints_root[..hash]);
+        take(&mut inputs.events_root[..hash]);
+        inputs
+    }
 
-        let mut events_root = [0u32; 8];
-        for slot in &mut events_root {
-            *slot = arr[idx];
-            idx += 1;
-        }
+    /// Decode the block hash back to bytes.
+    ///
+    /// Exact for [`M31Encoding::Limbs`]; approximate for reduced encoding.
+    pub fn decode_block_hash(&self) -> StateHash {
+        decode_hash(&self.block_hash, self.encoding)
+    }
 
-        Self {
-            match_id,
-            block_hash,
-            player_count,
-            duration_ticks,
-            winner_id,
-            placements_root,
-            inputs_root,
-            initial_state_hash,
-            final_state_hash,
-            checkpoints_root,
-            events_root,
-        }
+    /// Decode the final state hash back to bytes.
+    ///
+    /// Exact for [`M31Encoding::Limbs`]; approximate for reduced encoding.
+    pub fn decode_final_state_hash(&self) -> StateHash {
+        decode_hash(&self.final_state_hash, self.encoding)
     }
 
     /// Serialize to bytes (for on-chain submission).
+    ///
+    /// Reduced inputs use the default layout; limb inputs the versioned one.
     pub fn to_bytes(&self) -> Vec<u8> {
-        let arr = self.to_m31_array();
-        let mut bytes = Vec::with_capacity(PUBLIC_INPUT_ELEMENT_COUNT * 4);
-        for val in arr {
+        let elements: Vec<M31> = match self.encoding {
+            M31Encoding::Reduced => self.to_m31_array().to_vec(),
+            M31Encoding::Limbs => self.to_m31_limbs_array().to_vec(),
+        };
+        let mut bytes = Vec::with_capacity(elements.len() * 4);
+        for val in elements {
             bytes.extend_from_slice(&val.to_le_bytes());
         }
         bytes
     }
 
     /// Deserialize from bytes.
+    ///
+    /// The layout is told apart by length.
     pub fn from_bytes(data: &[u8]) -> Option<Self> {
-        if data.len() != PUBLIC_INPUT_ELEMENT_COUNT * 4 {
-            return None;
+        let elements: Vec<M31> = data.chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect();
+
+        match data.len() {
+            len if len == PUBLIC_INPUT_ELEMENT_COUNT * 4 => {
+                let arr: [M31; PUBLIC_INPUT_ELEMENT_COUNT] = elements.try_into().ok()?;
+                Some(Self::from_m31_array(&arr))
+            }
+            len if len == LIMB_PUBLIC_INPUT_ELEMENT_COUNT * 4 => {
+                let arr: [M31; LIMB_PUBLIC_INPUT_ELEMENT_COUNT] = elements.try_into().ok()?;
+                Self::from_m31_limbs_array(&arr)
+            }
+            _ => None,
         }
+    }
+}
 
-        let mut arr = [0u32; PUBLIC_INPUT_ELEMENT_COUNT];
-        for (i, chunk) in data.chunks_exact(4).enumerate() {
-            arr[i] = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+/// Encode a 32-byte hash into a public input slot using `encoding`.
+fn encode_hash(hash: &StateHash, encoding: M31Encoding) -> [M31; 16] {
+    let mut result = [0u32; 16];
+    match encoding {
+        M31Encoding::Reduced => result[..8].copy_from_slice(&hash_to_m31(hash)),
+        M31Encoding::Limbs => result = hash_to_m31_limbs(hash),
+    }
+    result
+}
+
+/// Decode a public input hash slot written by [`encode_hash`].
+fn decode_hash(encoded: &[M31; 16], encoding: M31Encoding) -> StateHash {
+    match encoding {
+        M31Encoding::Reduced => {
+            let mut reduced = [0u32; 8];
+            reduced.copy_from_slice(&encoded[..8]);
+            m31_to_hash(&reduced)
         }
+        M31Encoding::Limbs => m31_limbs_to_hash(encoded),
+    }
+}
+
+/// Encode a UUID into a public input slot using `encoding`.
+fn encode_uuid(uuid: &[u8; 16], encoding: M31Encoding) -> [M31; 8] {
+    let mut result = [0u32; 8];
+    match encoding {
+        M31Encoding::Reduced => result[..4].copy_from_slice(&uuid_to_m31(uuid)),
+        M31Encoding::Limbs => result = uuid_to_m31_limbs(uuid),
+    }
+    result
+}
+
+/// Encode a score as a single M31 element.
+///
+/// Returns `None` above [`MAX_SCORE`]; scoring saturates there, so such a
+/// score cannot come from a real match.
+pub fn score_to_m31(score: u32) -> Option<M31> {
+    (score <= MAX_SCORE).then_some(score)
+}
 
-        Some(Self::from_m31_array(&arr))
+/// Merkle leaf for one placement: `id || placement || score` with the
+/// placement as a little-endian `u16` and the score as a little-endian M31
+/// element.
+///
+/// Out-of-range scores are clamped to [`MAX_SCORE`]; verification rejects
+/// such transcripts, so a verified result never aliases.
+pub fn placement_leaf(id: &[u8; 16], placement: Placement, score: u32) -> Vec<u8> {
+    let score = score_to_m31(score).unwrap_or(MAX_SCORE);
+    let mut bytes = Vec::with_capacity(22);
+    bytes.extend_from_slice(id);
+    bytes.extend_from_slice(&placement.to_le_bytes());
+    bytes.extend_from_slice(&score.to_le_bytes());
+    bytes
+}
+
+/// Split 32-bit little-endian words into 16-bit limbs (low limb first).
+fn words_to_limbs(bytes: &[u8], out: &mut [M31]) {
+    for (i, chunk) in bytes.chunks_exact(4).enumerate() {
+        let word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        out[i * 2] = word & 0xFFFF;
+        out[i * 2 + 1] = word >> 16;
     }
 }
 
+/// Reassemble 16-bit limbs into little-endian 32-bit words.
+fn limbs_to_words(limbs: &[M31], out: &mut [u8]) {
+    for (i, pair) in limbs.chunks_exact(2).enumerate() {
+        let word = (pair[0] & 0xFFFF) | ((pair[1] & 0xFFFF) << 16);
+        out[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+    }
+}
+
+/// Encode a 32-byte hash to 16 M31 limbs without loss.
+pub fn hash_to_m31_limbs(hash: &StateHash) -> [M31; 16] {
+    let mut result = [0u32; 16];
+    words_to_limbs(hash, &mut result);
+    result
+}
+
+/// Decode 16 M31 limbs back to the exact hash.
+pub fn m31_limbs_to_hash(encoded: &[M31; 16]) -> StateHash {
+    let mut hash = [0u8; 32];
+    limbs_to_words(encoded, &mut hash);
+    hash
+}
+
+/// Encode a UUID (16 bytes) to 8 M31 limbs without loss.
+pub fn uuid_to_m31_limbs(uuid: &[u8; 16]) -> [M31; 8] {
+    let mut result = [0u32; 8];
+    words_to_limbs(uuid, &mut result);
+    result
+}
+
+/// Decode 8 M31 limbs back to the exact UUID bytes.
+pub fn m31_limbs_to_uuid(encoded: &[M31; 8]) -> [u8; 16] {
+    let mut uuid = [0u8; 16];
+    limbs_to_words(encoded, &mut uuid);
+    uuid
+}
+
 /// Encode a 32-byte hash to 8 M31 elements.
 ///
 /// Splits hash into 8 chunks of 4 bytes, takes lower 31 bits of each.
@@ -437,7 +567,7 @@ mod tests {
 
         assert_eq!(inputs.player_count, 2);
         assert_eq!(inputs.duration_ticks, 5400);
-        assert_ne!(inputs.winner_id, [0; 4]); // Winner was set
+        assert_ne!(inputs.winner_id, [0; 8]); // Winner was set
     }
 
     #[test]
@@ -471,4 +601,89 @@ mod tests {
         assert_eq!(bytes.len(), PUBLIC_INPUT_ELEMENT_COUNT * 4);
         assert_eq!(bytes.len(), 264); // 66 * 4 = 264 bytes
     }
+
+    #[test]
+    fn test_default_layout_is_unversioned() {
+        let transcript = create_test_transcript();
+        let arr = ProofPublicInputs::from_transcript(&transcript).to_m31_array();
+
+        assert_eq!(arr[..4], uuid_to_m31(&[1; 16]));
+        assert_eq!(arr[4..12], hash_to_m31(&[2; 32]));
+        assert_eq!(arr[12], 2);
+        assert_eq!(arr[13], 5400);
+        assert_eq!(arr[14..18], uuid_to_m31(&[3; 16]));
+        assert_eq!(arr[42..50], hash_to_m31(&[7; 32]));
+    }
+
+    #[test]
+    fn test_limb_encoding_roundtrips_exactly() {
+        // Every word is >= M31_PRIME, so reduction must alias it.
+        let hash: StateHash = [0xFF; 32];
+
+        let limbs = hash_to_m31_limbs(&hash);
+        assert!(limbs.iter().all(|&v| v < M31_PRIME));
+        assert_eq!(m31_limbs_to_hash(&limbs), hash);
+
+        let reduced = hash_to_m31(&hash);
+        assert_ne!(m31_to_hash(&reduced), hash);
+
+        let uuid: [u8; 16] = [0xFF; 16];
+        assert_eq!(m31_limbs_to_uuid(&uuid_to_m31_limbs(&uuid)), uuid);
+        assert_ne!(m31_to_uuid(&uuid_to_m31(&uuid)), uuid);
+    }
+
+    #[test]
+    fn test_score_near_u32_max_is_canonical() {
+        use crate::game::state::{PlayerId, PlayerState};
+        use crate::core::vec2::FixedVec2;
+
+        // Scoring saturates at the bound instead of wrapping past M31
+        let mut player = PlayerState::new(PlayerId::new([1; 16]), FixedVec2::ZERO);
+        let table = crate::game::form_table::FormTable::default();
+        player.add_score(u32::MAX - 5, &table);
+        player.add_score(100, &table);
+        assert_eq!(player.score, MAX_SCORE);
+        assert!(player.score < M31_PRIME);
+
+        assert_eq!(score_to_m31(MAX_SCORE), Some(MAX_SCORE));
+        assert_eq!(score_to_m31(MAX_SCORE + 1), None);
+
+        // Neighbouring in-range scores stay distinct leaves and roots
+        let id = [3; 16];
+        assert_ne!(placement_leaf(&id, 1, MAX_SCORE), placement_leaf(&id, 1, MAX_SCORE - 1));
+        let root_for = |score| {
+            let mut transcript = create_test_transcript();
+            transcript.result.as_mut().unwrap().placements[0].2 = score;
+            ProofPublicInputs::from_transcript(&transcript).placements_root
+        };
+        assert_ne!(root_for(MAX_SCORE), root_for(MAX_SCORE - 1));
+    }
+
+    #[test]
+    fn test_limb_encoded_public_inputs() {
+        let mut transcript = create_test_transcript();
+        transcript.metadata.block_hash = [0xFF; 32];
+
+        let inputs = ProofPublicInputs::from_transcript_with_encoding(
+            &transcript,
+            M31Encoding::Limbs,
+        );
+        assert_eq!(inputs.decode_block_hash(), [0xFF; 32]);
+        assert_eq!(inputs.decode_final_state_hash(), [7; 32]);
+
+        let bytes = inputs.to_bytes();
+        assert_eq!(bytes.len(), LIMB_PUBLIC_INPUT_ELEMENT_COUNT * 4);
+        let decoded = ProofPublicInputs::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, inputs);
+
+        // An unknown layout version is rejected
+        let mut arr = inputs.to_m31_limbs_array();
+        arr[0] = LIMB_LAYOUT_VERSION + 1;
+        assert!(ProofPublicInputs::from_m31_limbs_array(&arr).is_none());
+
+        // Lossy default cannot recover the block hash.
+        let reduced = ProofPublicInputs::from_transcript(&transcript);
+        assert_eq!(reduced.encoding, M31Encoding::Reduced);
+        assert_ne!(reduced.decode_block_hash(), [0xFF; 32]);
+    }
 }
@@ -0,0 +1,172 @@
+//! Derived Transcript Events
+//!
+//! Some state changes never raise a `GameEvent`: a rune or shrine buff
+//! running out, or a form lost to damage. With
+//! `SessionConfig::derive_state_events` set, the session snapshots every
+//! player before each tick and diffs the result, recording `BuffGained`,
+//! `BuffLost` and `FormChanged` transcript entries for what changed.
+//!
+//! The diff only reads simulated state, so a replay re-derives exactly the
+//! same entries; verification checks them when a transcript carries any.
+//!
+//! Entries for a tick are ordered by player ID, then form change, lost
+//! buffs and gained buffs, each in buff code order.
+
+use std::collections::{BTreeMap, BTreeSet};
+use crate::game::state::{MatchState, PlayerId, PlayerState};
+use crate::proof::transcript::TranscriptEvent;
+
+/// Buff code for the Speed rune buff.
+pub const BUFF_SPEED_RUNE: u8 = 0;
+
+/// Buff code for the Shield rune buff.
+pub const BUFF_SHIELD_RUNE: u8 = 1;
+
+/// Shrine buffs use `BUFF_SHRINE_BASE + ShrineType as u8`.
+pub const BUFF_SHRINE_BASE: u8 = 16;
+
+/// The parts of a player the diff looks at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct PlayerSnapshot {
+    form: u8,
+    buffs: BTreeSet<u8>,
+}
+
+impl PlayerSnapshot {
+    fn capture(player: &PlayerState) -> Self {
+        let mut buffs = BTreeSet::new();
+        if player.speed_buff_ticks > 0 {
+            buffs.insert(BUFF_SPEED_RUNE);
+        }
+        if player.shield_buff_ticks > 0 {
+            buffs.insert(BUFF_SHIELD_RUNE);
+        }
+        for (shrine_type, ticks) in player.shrine_buffs.iter().zip(&player.shrine_buff_ticks) {
+            if *ticks > 0 {
+                buffs.insert(BUFF_SHRINE_BASE + *shrine_type as u8);
+            }
+        }
+        Self { form: player.form as u8, buffs }
+    }
+}
+
+/// Per-player buffs and forms at one point in a match.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateSnapshot {
+    players: BTreeMap<PlayerId, PlayerSnapshot>,
+}
+
+impl StateSnapshot {
+    /// Snapshot every player in `state`.
+    pub fn capture(state: &MatchState) -> Self {
+        Self {
+            players: state.players.iter()
+                .map(|(id, player)| (*id, PlayerSnapshot::capture(player)))
+                .collect(),
+        }
+    }
+
+    /// Entries describing how `state` differs from this snapshot.
+    ///
+    /// Players missing from the snapshot (backfilled joiners) start with no
+    /// buffs and report no form change.
+    pub fn derive_events(&self, state: &MatchState) -> Vec<TranscriptEvent> {
+        let tick = state.tick;
+        let mut events = Vec::new();
+
+        for (id, player) in &state.players {
+            let after = PlayerSnapshot::capture(player);
+            let player_id = *id.as_bytes();
+            let before = self.players.get(id);
+
+            if let Some(before) = before.filter(|b| b.form != after.form) {
+                events.push(TranscriptEvent::FormChanged {
+                    tick,
+                    player_id,
+                    old_form: before.form,
+                    new_form: after.form,
+                });
+            }
+
+            let no_buffs = BTreeSet::new();
+            let before_buffs = before.map_or(&no_buffs, |b| &b.buffs);
+            for &buff in before_buffs.difference(&after.buffs) {
+                events.push(TranscriptEvent::BuffLost { tick, player_id, buff });
+            }
+            for &buff in after.buffs.difference(before_buffs) {
+                events.push(TranscriptEvent::BuffGained { tick, player_id, buff });
+            }
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use crate::core::vec2::FixedVec2;
+    use crate::game::state::{MatchPhase, ShrineType};
+    use crate::game::tick::MatchConfig;
+
+    #[test]
+    fn test_buff_expiry_derives_buff_lost() {
+        let run = || {
+            let mut state = MatchState::new([0; 16], 12345);
+            state.phase = MatchPhase::Playing;
+            for (i, x) in [(1, -20), (2, 20)] {
+                let id = PlayerId::new([i; 16]);
+                state.players.insert(id, PlayerState::new(id, FixedVec2::new(x * 65536, 0)));
+            }
+            state.alive_count = 2;
+            let holder = state.players.get_mut(&PlayerId::new([1; 16])).unwrap();
+            holder.shield_buff_ticks = 2;
+            holder.add_shrine_buff(ShrineType::Speed, 5);
+
+            let config = MatchConfig::default();
+            let mut derived = Vec::new();
+            for _ in 0..6 {
+                let before = StateSnapshot::capture(&state);
+                state.tick_once(&BTreeMap::new(), &config);
+                derived.extend(before.derive_events(&state));
+            }
+            derived
+        };
+
+        let derived = run();
+        let lost: Vec<(u32, u8)> = derived.iter().filter_map(|e| match *e {
+            TranscriptEvent::BuffLost { tick, player_id, buff } if player_id == [1; 16] => {
+                Some((tick, buff))
+            }
+            _ => None,
+        }).collect();
+        assert_eq!(
+            lost,
+            vec![(2, BUFF_SHIELD_RUNE), (5, BUFF_SHRINE_BASE + ShrineType::Speed as u8)]
+        );
+        assert!(!derived.iter().any(|e| matches!(e, TranscriptEvent::BuffGained { .. })));
+
+        // Same match, same entries
+        assert_eq!(derived, run());
+    }
+
+    #[test]
+    fn test_form_change_and_new_buff_are_derived() {
+        let mut state = MatchState::new([0; 16], 12345);
+        let id = PlayerId::new([1; 16]);
+        state.players.insert(id, PlayerState::new(id, FixedVec2::ZERO));
+        let before = StateSnapshot::capture(&state);
+
+        let table = state.form_table.clone();
+        let player = state.players.get_mut(&id).unwrap();
+        player.set_tier(1, &table);
+        player.speed_buff_ticks = 10;
+
+        assert_eq!(before.derive_events(&state), vec![
+            TranscriptEvent::FormChanged { tick: 0, player_id: [1; 16], old_form: 0, new_form: 1 },
+            TranscriptEvent::BuffGained { tick: 0, player_id: [1; 16], buff: BUFF_SPEED_RUNE },
+        ]);
+        assert!(StateSnapshot::capture(&state).derive_events(&state).is_empty());
+    }
+}
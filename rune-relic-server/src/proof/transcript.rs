@@ -3,14 +3,17 @@
 //! Records all data needed to deterministically verify a match outcome.
 //! Optimized for compact serialization (~20KB for a 90-second match).
 
+use std::collections::BTreeSet;
 use serde::{Serialize, Deserialize};
 use crate::core::hash::StateHash;
 use crate::core::vec2::FixedVec2;
 use crate::game::input::InputDelta;
 use crate::game::events::{GameEvent, GameEventData};
+use crate::game::state::Placement;
+use crate::game::tick::{MatchConfig, MATCH_CONFIG_VERSION};
 
-/// Current transcript version.
-pub const TRANSCRIPT_VERSION: u8 = 1;
+/// Current transcript version: embedded config, start tick and per-player start state.
+pub const TRANSCRIPT_VERSION: u8 = 2;
 
 /// Checkpoint interval in ticks (every 10 seconds = 600 ticks at 60Hz).
 pub const CHECKPOINT_INTERVAL: u32 = 600;
@@ -29,6 +32,18 @@ pub struct MatchTranscript {
     /// Match metadata (public, can be committed before match starts).
     pub metadata: MatchMetadata,
 
+    /// Rules version the match was played under.
+    ///
+    /// Verifiers resolve this through `MatchConfig::for_version` so older
+    /// transcripts replay with the parameters they were recorded with.
+    pub config_version: u32,
+
+    /// Exact `MatchConfig::config_bytes` the match was played with.
+    ///
+    /// Hashes to `metadata.config_hash`. Empty when the match used the
+    /// stock rules of `config_version`.
+    pub config: Vec<u8>,
+
     /// Initial match state (snapshot at tick 0).
     pub initial_state: InitialMatchState,
 
@@ -67,9 +82,12 @@ pub struct MatchMetadata {
     pub config_hash: StateHash,
 }
 
-/// Initial state snapshot at tick 0.
+/// Initial state snapshot at `start_tick`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct InitialMatchState {
+    /// Tick the match clock starts from; replay begins at the next tick.
+    pub start_tick: u32,
+
     /// Initial player states (positions, forms).
     pub players: Vec<InitialPlayerState>,
 
@@ -89,8 +107,17 @@ pub struct InitialPlayerState {
     /// Initial position.
     pub position: FixedVec2,
 
-    /// Initial form (as u8).
+    /// Initial ladder tier (the form index on the classic ladder).
     pub form: u8,
+
+    /// Initial score (non-zero only for handicap starts).
+    pub score: u32,
+
+    /// Spawn zone the player starts protected in (None if the map has none).
+    pub spawn_zone: Option<u8>,
+
+    /// Team the player starts on (None outside team matches).
+    pub team: Option<u8>,
 }
 
 /// Per-player input recording.
@@ -129,14 +156,14 @@ pub struct MatchResult {
     pub winner_id: Option<[u8; 16]>,
 
     /// Final placements: (player_id, placement, score).
-    pub placements: Vec<([u8; 16], u8, u32)>,
+    pub placements: Vec<([u8; 16], Placement, u32)>,
 
     /// Final state hash.
     pub final_state_hash: StateHash,
 }
 
 /// Transcript event (subset of GameEvent for compact storage).
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TranscriptEvent {
     /// Player was eliminated.
     PlayerEliminated {
@@ -147,7 +174,7 @@ pub enum TranscriptEvent {
         /// Player who caused elimination (if any).
         killer_id: Option<[u8; 16]>,
         /// Final placement.
-        placement: u8,
+        placement: Placement,
     },
 
     /// Player evolved to new form.
@@ -181,6 +208,108 @@ pub enum TranscriptEvent {
         /// Shrine identifier.
         shrine_id: u8,
     },
+
+    /// Player joined mid-match (backfill), after `tick` was simulated.
+    PlayerJoined {
+        /// Last tick simulated before the join.
+        tick: u32,
+        /// Joining player.
+        player_id: [u8; 16],
+    },
+
+    /// Player gained a buff (derived from a state diff).
+    BuffGained {
+        /// Tick the buff was first active.
+        tick: u32,
+        /// Buffed player.
+        player_id: [u8; 16],
+        /// Buff code (see `state_diff`).
+        buff: u8,
+    },
+
+    /// Player's buff ran out or was removed (derived from a state diff).
+    BuffLost {
+        /// Tick the buff was first gone.
+        tick: u32,
+        /// Player who lost it.
+        player_id: [u8; 16],
+        /// Buff code (see `state_diff`).
+        buff: u8,
+    },
+
+    /// Player's form changed for any reason (derived from a state diff).
+    FormChanged {
+        /// Tick of the change.
+        tick: u32,
+        /// Player whose form changed.
+        player_id: [u8; 16],
+        /// Form before the tick (as u8).
+        old_form: u8,
+        /// Form after the tick (as u8).
+        new_form: u8,
+    },
+}
+
+/// Entry in a merged transcript timeline.
+#[derive(Clone, Debug)]
+pub enum TimelineEntry {
+    /// A recorded gameplay event.
+    Event(TranscriptEvent),
+    /// A state hash checkpoint.
+    Checkpoint(StateCheckpoint),
+}
+
+/// Short player ID for timeline lines (first 4 bytes in hex).
+fn short_id(player_id: &[u8; 16]) -> String {
+    hex::encode(&player_id[..4])
+}
+
+/// One line per entry, e.g. `tick 120: 0a0b0c0d eliminated by 01020304 (place 3)`.
+impl std::fmt::Display for TimelineEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let event = match self {
+            Self::Checkpoint(checkpoint) => {
+                return write!(
+                    f,
+                    "tick {}: checkpoint {}",
+                    checkpoint.tick,
+                    hex::encode(&checkpoint.state_hash[..8])
+                );
+            }
+            Self::Event(event) => event,
+        };
+        write!(f, "tick {}: ", event.tick())?;
+        match event {
+            TranscriptEvent::PlayerEliminated { victim_id, killer_id, placement, .. } => {
+                write!(f, "{} eliminated", short_id(victim_id))?;
+                if let Some(killer_id) = killer_id {
+                    write!(f, " by {}", short_id(killer_id))?;
+                }
+                write!(f, " (place {})", placement)
+            }
+            TranscriptEvent::FormEvolved { player_id, new_form, .. } => {
+                write!(f, "{} evolved to form {}", short_id(player_id), new_form)
+            }
+            TranscriptEvent::RuneCollected { player_id, rune_id, points, .. } => {
+                write!(f, "{} collected rune {} (+{})", short_id(player_id), rune_id, points)
+            }
+            TranscriptEvent::ShrineActivated { player_id, shrine_id, .. } => {
+                write!(f, "{} activated shrine {}", short_id(player_id), shrine_id)
+            }
+            TranscriptEvent::PlayerJoined { player_id, .. } => {
+                write!(f, "{} joined", short_id(player_id))
+            }
+            TranscriptEvent::BuffGained { player_id, buff, .. } => {
+                write!(f, "{} gained buff {}", short_id(player_id), buff)
+            }
+            TranscriptEvent::BuffLost { player_id, buff, .. } => {
+                write!(f, "{} lost buff {}", short_id(player_id), buff)
+            }
+            TranscriptEvent::FormChanged { player_id, old_form, new_form, .. } => {
+                write!(f, "{} changed form {} -> {}", short_id(player_id), old_form, new_form)
+            }
+        }
+    }
 }
 
 impl MatchTranscript {
@@ -189,7 +318,10 @@ impl MatchTranscript {
         Self {
             version: TRANSCRIPT_VERSION,
             metadata,
+            config_version: MATCH_CONFIG_VERSION,
+            config: Vec::new(),
             initial_state: InitialMatchState {
+                start_tick: 0,
                 players: Vec::new(),
                 rng_state: [0, 0],
                 state_hash: [0; 32],
@@ -201,6 +333,12 @@ impl MatchTranscript {
         }
     }
 
+    /// Embed the config the match is played with and commit to its hash.
+    pub fn set_config(&mut self, config: &MatchConfig) {
+        self.config = config.config_bytes();
+        self.metadata.config_hash = config.config_hash();
+    }
+
     /// Record initial state snapshot.
     pub fn set_initial_state(&mut self, state: InitialMatchState) {
         self.initial_state = state;
@@ -239,6 +377,50 @@ impl MatchTranscript {
         self.result.is_some()
     }
 
+    /// Check the transcript is well-formed before spending time on replay.
+    ///
+    /// The version must be `TRANSCRIPT_VERSION`, every player in the
+    /// metadata needs exactly one input record, records may only belong to
+    /// those players or to backfilled joiners, and checkpoint ticks must
+    /// strictly increase.
+    pub fn check_integrity(&self) -> Result<(), IntegrityError> {
+        if self.version != TRANSCRIPT_VERSION {
+            return Err(IntegrityError::VersionMismatch {
+                expected: TRANSCRIPT_VERSION,
+                got: self.version,
+            });
+        }
+
+        let joined: BTreeSet<[u8; 16]> = self.events.iter()
+            .filter_map(|e| match e {
+                TranscriptEvent::PlayerJoined { player_id, .. } => Some(*player_id),
+                _ => None,
+            })
+            .collect();
+        let mut recorded = BTreeSet::new();
+        for record in &self.player_inputs {
+            let player_id = record.player_id;
+            if !self.metadata.player_ids.contains(&player_id) && !joined.contains(&player_id) {
+                return Err(IntegrityError::UnknownInputRecord { player_id });
+            }
+            if !recorded.insert(player_id) {
+                return Err(IntegrityError::DuplicateInputRecord { player_id });
+            }
+        }
+        if let Some(&player_id) = self.metadata.player_ids.iter().find(|id| !recorded.contains(*id)) {
+            return Err(IntegrityError::MissingInputRecord { player_id });
+        }
+
+        if let Some(pair) = self.checkpoints.windows(2).find(|pair| pair[1].tick <= pair[0].tick) {
+            return Err(IntegrityError::UnsortedCheckpoints {
+                previous_tick: pair[0].tick,
+                tick: pair[1].tick,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Serialize to bytes using bincode.
     pub fn to_bytes(&self) -> Vec<u8> {
         bincode::serialize(self).expect("Transcript serialization should not fail")
@@ -268,9 +450,57 @@ impl MatchTranscript {
     pub fn player_count(&self) -> usize {
         self.metadata.player_ids.len()
     }
+
+    /// Merge events and checkpoints into a single chronological timeline.
+    ///
+    /// Checkpoints hash the state at the end of their tick, so on equal
+    /// ticks the events of that tick come first. Recording order is kept
+    /// within each kind.
+    pub fn timeline(&self) -> Vec<(u32, TimelineEntry)> {
+        let mut timeline: Vec<(u32, TimelineEntry)> = self
+            .events
+            .iter()
+            .map(|e| (e.tick(), TimelineEntry::Event(e.clone())))
+            .chain(
+                self.checkpoints
+                    .iter()
+                    .map(|c| (c.tick, TimelineEntry::Checkpoint(c.clone()))),
+            )
+            .collect();
+
+        // Stable sort keeps recording order for entries on the same tick
+        timeline.sort_by_key(|(tick, entry)| {
+            (*tick, matches!(entry, TimelineEntry::Checkpoint(_)))
+        });
+        timeline
+    }
 }
 
 impl TranscriptEvent {
+    /// Tick at which the event occurred.
+    pub fn tick(&self) -> u32 {
+        match self {
+            TranscriptEvent::PlayerEliminated { tick, .. }
+            | TranscriptEvent::FormEvolved { tick, .. }
+            | TranscriptEvent::RuneCollected { tick, .. }
+            | TranscriptEvent::ShrineActivated { tick, .. }
+            | TranscriptEvent::PlayerJoined { tick, .. }
+            | TranscriptEvent::BuffGained { tick, .. }
+            | TranscriptEvent::BuffLost { tick, .. }
+            | TranscriptEvent::FormChanged { tick, .. } => *tick,
+        }
+    }
+
+    /// Whether the entry comes from a state diff rather than a game event.
+    pub fn is_derived(&self) -> bool {
+        matches!(
+            self,
+            TranscriptEvent::BuffGained { .. }
+                | TranscriptEvent::BuffLost { .. }
+                | TranscriptEvent::FormChanged { .. }
+        )
+    }
+
     /// Convert a GameEvent to TranscriptEvent (if relevant).
     pub fn from_game_event(event: &GameEvent) -> Option<Self> {
         match &event.data {
@@ -304,6 +534,12 @@ impl TranscriptEvent {
                     shrine_id: *shrine_id,
                 })
             }
+            GameEventData::PlayerJoined { player_id, .. } => {
+                Some(TranscriptEvent::PlayerJoined {
+                    tick: event.tick,
+                    player_id: *player_id.as_bytes(),
+                })
+            }
             // Other events are not recorded in transcript
             _ => None,
         }
@@ -335,6 +571,64 @@ impl std::fmt::Display for TranscriptError {
 
 impl std::error::Error for TranscriptError {}
 
+/// Structural problems found by [`MatchTranscript::check_integrity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrityError {
+    /// Transcript format differs from the one this build reads.
+    VersionMismatch {
+        /// Supported version.
+        expected: u8,
+        /// Recorded version.
+        got: u8,
+    },
+    /// A player in the metadata has no input record.
+    MissingInputRecord {
+        /// Player without inputs.
+        player_id: [u8; 16],
+    },
+    /// An input record belongs to no player in the match.
+    UnknownInputRecord {
+        /// Player named by the record.
+        player_id: [u8; 16],
+    },
+    /// A player has more than one input record.
+    DuplicateInputRecord {
+        /// Player recorded twice.
+        player_id: [u8; 16],
+    },
+    /// Checkpoint ticks do not strictly increase.
+    UnsortedCheckpoints {
+        /// Tick of the earlier checkpoint.
+        previous_tick: u32,
+        /// Tick of the checkpoint that follows it.
+        tick: u32,
+    },
+}
+
+impl std::fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::VersionMismatch { expected, got } => {
+                write!(f, "Version mismatch: expected {}, got {}", expected, got)
+            }
+            Self::MissingInputRecord { player_id } => {
+                write!(f, "No input record for player {:02x?}", &player_id[..4])
+            }
+            Self::UnknownInputRecord { player_id } => {
+                write!(f, "Input record for unknown player {:02x?}", &player_id[..4])
+            }
+            Self::DuplicateInputRecord { player_id } => {
+                write!(f, "Duplicate input record for player {:02x?}", &player_id[..4])
+            }
+            Self::UnsortedCheckpoints { previous_tick, tick } => {
+                write!(f, "Checkpoint at tick {} follows tick {}", tick, previous_tick)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IntegrityError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -414,6 +708,82 @@ mod tests {
         assert!(transcript.is_complete());
     }
 
+    /// Transcript with one idle input record per player.
+    fn create_recorded_transcript() -> MatchTranscript {
+        let mut transcript = MatchTranscript::new(create_test_metadata());
+        for player_id in [[3; 16], [4; 16]] {
+            transcript.add_player_inputs(PlayerInputRecord {
+                player_id,
+                deltas: vec![],
+                input_count: 0,
+            });
+        }
+        transcript.add_checkpoint(600, [1; 32], [10, 20]);
+        transcript.add_checkpoint(1200, [2; 32], [30, 40]);
+        transcript
+    }
+
+    #[test]
+    fn test_integrity_check_passes_clean_transcript() {
+        assert_eq!(create_recorded_transcript().check_integrity(), Ok(()));
+    }
+
+    #[test]
+    fn test_integrity_check_rejects_version_mismatch() {
+        let mut transcript = create_recorded_transcript();
+        transcript.version = TRANSCRIPT_VERSION + 1;
+        assert_eq!(
+            transcript.check_integrity(),
+            Err(IntegrityError::VersionMismatch { expected: TRANSCRIPT_VERSION, got: TRANSCRIPT_VERSION + 1 })
+        );
+    }
+
+    #[test]
+    fn test_integrity_check_rejects_bad_input_records() {
+        let mut missing = create_recorded_transcript();
+        missing.player_inputs.pop();
+        assert_eq!(
+            missing.check_integrity(),
+            Err(IntegrityError::MissingInputRecord { player_id: [4; 16] })
+        );
+
+        let mut duplicate = create_recorded_transcript();
+        duplicate.player_inputs.push(duplicate.player_inputs[0].clone());
+        assert_eq!(
+            duplicate.check_integrity(),
+            Err(IntegrityError::DuplicateInputRecord { player_id: [3; 16] })
+        );
+
+        let mut unknown = create_recorded_transcript();
+        unknown.player_inputs[1].player_id = [9; 16];
+        assert_eq!(
+            unknown.check_integrity(),
+            Err(IntegrityError::UnknownInputRecord { player_id: [9; 16] })
+        );
+
+        // Backfilled joiners may carry their own record
+        unknown.player_inputs.push(PlayerInputRecord { player_id: [4; 16], deltas: vec![], input_count: 0 });
+        unknown.events.push(TranscriptEvent::PlayerJoined { tick: 10, player_id: [9; 16] });
+        assert_eq!(unknown.check_integrity(), Ok(()));
+    }
+
+    #[test]
+    fn test_integrity_check_rejects_unsorted_checkpoints() {
+        let mut transcript = create_recorded_transcript();
+        transcript.add_checkpoint(900, [3; 32], [50, 60]);
+        assert_eq!(
+            transcript.check_integrity(),
+            Err(IntegrityError::UnsortedCheckpoints { previous_tick: 1200, tick: 900 })
+        );
+
+        let mut repeated = create_recorded_transcript();
+        repeated.add_checkpoint(1200, [3; 32], [50, 60]);
+        assert!(matches!(
+            repeated.check_integrity(),
+            Err(IntegrityError::UnsortedCheckpoints { tick: 1200, .. })
+        ));
+    }
+
     #[test]
     fn test_estimated_size() {
         let metadata = create_test_metadata();
@@ -437,4 +807,80 @@ mod tests {
         // Should be well under 100KB
         assert!(size < 100_000, "Size {} should be under 100KB", size);
     }
+
+    #[test]
+    fn test_timeline_interleaves_checkpoints_and_events() {
+        let metadata = create_test_metadata();
+        let mut transcript = MatchTranscript::new(metadata);
+
+        transcript.add_checkpoint(600, [1; 32], [10, 20]);
+        transcript.add_checkpoint(1200, [2; 32], [30, 40]);
+        transcript.events.push(TranscriptEvent::PlayerEliminated {
+            tick: 300,
+            victim_id: [3; 16],
+            killer_id: Some([4; 16]),
+            placement: 4,
+        });
+        transcript.events.push(TranscriptEvent::PlayerEliminated {
+            tick: 900,
+            victim_id: [5; 16],
+            killer_id: None,
+            placement: 3,
+        });
+        transcript.events.push(TranscriptEvent::PlayerEliminated {
+            tick: 1200,
+            victim_id: [6; 16],
+            killer_id: Some([4; 16]),
+            placement: 2,
+        });
+
+        let timeline = transcript.timeline();
+        let ticks: Vec<u32> = timeline.iter().map(|(t, _)| *t).collect();
+        assert_eq!(ticks, vec![300, 600, 900, 1200, 1200]);
+
+        assert!(matches!(timeline[0].1, TimelineEntry::Event(_)));
+        assert!(matches!(timeline[1].1, TimelineEntry::Checkpoint(_)));
+        assert!(matches!(timeline[2].1, TimelineEntry::Event(_)));
+        // Same-tick event precedes the checkpoint that hashes its result
+        assert!(matches!(timeline[3].1, TimelineEntry::Event(_)));
+        assert!(matches!(timeline[4].1, TimelineEntry::Checkpoint(_)));
+    }
+
+    #[test]
+    fn test_timeline_renders_readable_lines() {
+        let mut transcript = MatchTranscript::new(create_test_metadata());
+        transcript.add_checkpoint(600, [0xAB; 32], [10, 20]);
+        transcript.events.push(TranscriptEvent::PlayerEliminated {
+            tick: 300,
+            victim_id: [3; 16],
+            killer_id: Some([4; 16]),
+            placement: 4,
+        });
+        transcript.events.push(TranscriptEvent::PlayerEliminated {
+            tick: 900,
+            victim_id: [5; 16],
+            killer_id: None,
+            placement: 3,
+        });
+        transcript.events.push(TranscriptEvent::FormEvolved { tick: 120, player_id: [4; 16], new_form: 1 });
+        transcript.events.push(TranscriptEvent::RuneCollected {
+            tick: 60,
+            player_id: [4; 16],
+            rune_id: 7,
+            points: 10,
+        });
+        transcript.events.push(TranscriptEvent::ShrineActivated { tick: 450, player_id: [6; 16], shrine_id: 2 });
+
+        let lines: Vec<String> = transcript.timeline().iter()
+            .map(|(_, entry)| entry.to_string())
+            .collect();
+        assert_eq!(lines, vec![
+            "tick 60: 04040404 collected rune 7 (+10)",
+            "tick 120: 04040404 evolved to form 1",
+            "tick 300: 03030303 eliminated by 04040404 (place 4)",
+            "tick 450: 06060606 activated shrine 2",
+            "tick 600: checkpoint abababababababab",
+            "tick 900: 05050505 eliminated (place 3)",
+        ]);
+    }
 }
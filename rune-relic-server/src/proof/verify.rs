@@ -3,13 +3,27 @@
 //! Verify matches by deterministic replay.
 //! Interface for external STWO proof verification (BitSage/Obelysk).
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use serde::Serialize;
+use crate::core::fixed::MAX_SCORE;
 use crate::core::hash::StateHash;
-use crate::game::state::{MatchState, MatchPhase, PlayerId, PlayerState, Form};
+use crate::game::state::{MatchState, PlayerId, PlayerState};
+use crate::game::events::GameEventData;
 use crate::game::input::InputFrame;
-use crate::game::tick::{tick, MatchConfig};
-use crate::proof::transcript::MatchTranscript;
+use crate::game::tick::{prepare_match, start_playing, MatchConfig};
+use crate::proof::commitment::{CommitmentError, MatchCommitment, MatchReveal};
+use crate::proof::state_diff::StateSnapshot;
+use crate::proof::transcript::{IntegrityError, MatchTranscript, TranscriptEvent};
 use crate::proof::public_inputs::ProofPublicInputs;
+use crate::MATCH_DURATION_TICKS;
+
+/// Grace ticks allowed past `MATCH_DURATION_TICKS` before a transcript is
+/// rejected as too long (10 seconds at 60Hz).
+pub const MAX_OVERTIME_TICKS: u32 = 600;
+
+/// Longest match, in ticks after its start tick, a transcript may claim.
+pub const MAX_VERIFIABLE_TICKS: u32 = MATCH_DURATION_TICKS + MAX_OVERTIME_TICKS;
 
 /// Verification result.
 #[derive(Debug)]
@@ -31,7 +45,7 @@ pub struct VerificationResult {
 }
 
 /// Result of verifying a single checkpoint.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CheckpointResult {
     /// Tick number.
     pub tick: u32,
@@ -99,6 +113,66 @@ pub enum VerificationError {
 
     /// Transcript is incomplete.
     IncompleteTranscript,
+
+    /// Transcript failed its structural self-check.
+    Integrity(IntegrityError),
+
+    /// Transcript was recorded under a config version this verifier
+    /// cannot reconstruct.
+    UnknownConfigVersion {
+        /// Recorded config version.
+        version: u32,
+    },
+
+    /// Embedded config could not be decoded.
+    InvalidConfig,
+
+    /// Config used for replay does not hash to `metadata.config_hash`.
+    ConfigHashMismatch {
+        /// Hash committed in the metadata.
+        expected: StateHash,
+        /// Hash of the config being replayed.
+        computed: StateHash,
+    },
+
+    /// A placement claims a score above `MAX_SCORE`.
+    ScoreOutOfRange {
+        /// Claimed score.
+        score: u32,
+    },
+
+    /// Start tick is not before the end tick, or leaves no room for a
+    /// full-length match before the tick counter overflows.
+    InvalidStartTick {
+        /// Claimed start tick.
+        start_tick: u32,
+        /// Claimed end tick.
+        end_tick: u32,
+    },
+
+    /// Claimed match length exceeds the maximum verifiable duration.
+    TooLong {
+        /// Claimed end tick.
+        end_tick: u32,
+        /// Maximum allowed end tick.
+        max_ticks: u32,
+    },
+
+    /// Recorded state-diff entries differ from the ones the replay derives.
+    DerivedEventMismatch {
+        /// First tick where they disagree.
+        tick: u32,
+    },
+
+    /// A recorded evolution did not happen in the replayed match.
+    UnjustifiedEvolution {
+        /// Tick of the recorded evolution.
+        tick: u32,
+        /// Player claimed to have evolved.
+        player_id: [u8; 16],
+        /// Claimed new form (as u8).
+        new_form: u8,
+    },
 }
 
 impl std::fmt::Display for VerificationError {
@@ -124,6 +198,33 @@ impl std::fmt::Display for VerificationError {
                 write!(f, "RNG seed mismatch: expected {}, got {}", expected, got)
             }
             Self::IncompleteTranscript => write!(f, "Transcript is incomplete"),
+            Self::Integrity(error) => write!(f, "Malformed transcript: {}", error),
+            Self::UnknownConfigVersion { version } => {
+                write!(f, "Unknown config version {}", version)
+            }
+            Self::InvalidConfig => write!(f, "Embedded match config is malformed"),
+            Self::ConfigHashMismatch { .. } => {
+                write!(f, "Match config does not match the committed config hash")
+            }
+            Self::ScoreOutOfRange { score } => {
+                write!(f, "Score {} exceeds the canonical bound {}", score, MAX_SCORE)
+            }
+            Self::InvalidStartTick { start_tick, end_tick } => {
+                write!(f, "Invalid start tick {} for end tick {}", start_tick, end_tick)
+            }
+            Self::TooLong { end_tick, max_ticks } => {
+                write!(f, "Match too long: end tick {} exceeds {}", end_tick, max_ticks)
+            }
+            Self::DerivedEventMismatch { tick } => {
+                write!(f, "Derived event mismatch at tick {}", tick)
+            }
+            Self::UnjustifiedEvolution { tick, player_id, new_form } => {
+                write!(
+                    f,
+                    "Unjustified evolution of player {:02x?} to form {} at tick {}",
+                    &player_id[..4], new_form, tick
+                )
+            }
         }
     }
 }
@@ -133,8 +234,37 @@ impl std::error::Error for VerificationError {}
 /// Verify a match transcript by full replay.
 ///
 /// This is the authoritative verification method.
-/// Replays the entire match and compares state hashes.
+/// Replays the entire match and compares state hashes, then checks that
+/// every recorded evolution event happened in the replay.
 pub fn verify_transcript(transcript: &MatchTranscript) -> VerificationResult {
+    verify_transcript_with_configs(transcript, MatchConfig::for_version)
+}
+
+/// Verify a transcript, resolving its config version with `resolve_config`.
+///
+/// Transcripts that embed their config replay with it. Otherwise the
+/// config comes from `resolve_config(config_version)`; `verify_transcript`
+/// uses [`MatchConfig::for_version`], and this entry point lets callers
+/// supply their own version table. Either way the config must hash to
+/// `metadata.config_hash`.
+pub fn verify_transcript_with_configs<F>(
+    transcript: &MatchTranscript,
+    resolve_config: F,
+) -> VerificationResult
+where
+    F: Fn(u32) -> Option<MatchConfig>,
+{
+    // Fail fast on structurally broken transcripts
+    if let Err(error) = transcript.check_integrity() {
+        return VerificationResult {
+            valid: false,
+            computed_final_hash: [0; 32],
+            expected_final_hash: transcript.result.as_ref().map_or([0; 32], |r| r.final_state_hash),
+            checkpoint_results: vec![],
+            error: Some(VerificationError::Integrity(error)),
+        };
+    }
+
     // Check transcript is complete
     let result = match &transcript.result {
         Some(r) => r,
@@ -149,8 +279,93 @@ pub fn verify_transcript(transcript: &MatchTranscript) -> VerificationResult {
         }
     };
 
+    // Replay runs from the tick after start_tick through end_tick, so the
+    // start must come first and a full match must fit in the tick counter
+    let start_tick = transcript.initial_state.start_tick;
+    let max_end_tick = match start_tick.checked_add(MAX_VERIFIABLE_TICKS) {
+        Some(max_end_tick) if start_tick < result.end_tick => max_end_tick,
+        _ => {
+            return VerificationResult {
+                valid: false,
+                computed_final_hash: [0; 32],
+                expected_final_hash: result.final_state_hash,
+                checkpoint_results: vec![],
+                error: Some(VerificationError::InvalidStartTick {
+                    start_tick,
+                    end_tick: result.end_tick,
+                }),
+            };
+        }
+    };
+
+    // Reject absurd lengths before spending time on replay
+    if result.end_tick > max_end_tick {
+        return VerificationResult {
+            valid: false,
+            computed_final_hash: [0; 32],
+            expected_final_hash: result.final_state_hash,
+            checkpoint_results: vec![],
+            error: Some(VerificationError::TooLong {
+                end_tick: result.end_tick,
+                max_ticks: max_end_tick,
+            }),
+        };
+    }
+
+    // Scores must fit the canonical M31 bound
+    if let Some(&(_, _, score)) = result.placements.iter().find(|(_, _, s)| *s > MAX_SCORE) {
+        return VerificationResult {
+            valid: false,
+            computed_final_hash: [0; 32],
+            expected_final_hash: result.final_state_hash,
+            checkpoint_results: vec![],
+            error: Some(VerificationError::ScoreOutOfRange { score }),
+        };
+    }
+
+    // Input records must be internally consistent
+    if let Err(error) = validate_input_buffers(transcript) {
+        return VerificationResult {
+            valid: false,
+            computed_final_hash: [0; 32],
+            expected_final_hash: result.final_state_hash,
+            checkpoint_results: vec![],
+            error: Some(error),
+        };
+    }
+
+    // Resolve the rules the match was recorded under
+    let config = if transcript.config.is_empty() {
+        resolve_config(transcript.config_version)
+            .ok_or(VerificationError::UnknownConfigVersion { version: transcript.config_version })
+    } else {
+        MatchConfig::from_config_bytes(&transcript.config).ok_or(VerificationError::InvalidConfig)
+    };
+    let config = match config.and_then(|config| {
+        let computed = config.config_hash();
+        if computed == transcript.metadata.config_hash {
+            Ok(config)
+        } else {
+            Err(VerificationError::ConfigHashMismatch {
+                expected: transcript.metadata.config_hash,
+                computed,
+            })
+        }
+    }) {
+        Ok(config) => config,
+        Err(error) => {
+            return VerificationResult {
+                valid: false,
+                computed_final_hash: [0; 32],
+                expected_final_hash: result.final_state_hash,
+                checkpoint_results: vec![],
+                error: Some(error),
+            };
+        }
+    };
+
     // 1. Reconstruct initial state
-    let mut state = reconstruct_initial_state(transcript);
+    let mut state = reconstruct_initial_state(transcript, &config);
 
     // 2. Verify initial state hash
     let initial_hash = state.compute_hash();
@@ -171,19 +386,37 @@ pub fn verify_transcript(transcript: &MatchTranscript) -> VerificationResult {
     let player_inputs = build_input_lookup(transcript);
 
     // 4. Replay tick by tick with checkpoint verification
-    let config = MatchConfig::default();
     let mut checkpoint_results = Vec::new();
     let mut checkpoint_idx = 0;
+    // Evolutions that actually happened, as (tick, player, new form)
+    let mut replayed_evolutions: BTreeSet<(u32, [u8; 16], u8)> = BTreeSet::new();
+    // State-diff entries, re-derived only if the transcript recorded them
+    let derive_events = transcript.events.iter().any(TranscriptEvent::is_derived);
+    let mut replayed_derived: Vec<TranscriptEvent> = Vec::new();
 
     // Start playing
-    state.phase = MatchPhase::Playing;
+    start_playing(&mut state, &config);
+    apply_backfills(&mut state, transcript);
 
-    for tick_num in 1..=result.end_tick {
+    for tick_num in start_tick + 1..=result.end_tick {
         // Get inputs for this tick
         let tick_inputs = get_inputs_at_tick(&player_inputs, tick_num);
 
+        let snapshot = derive_events.then(|| StateSnapshot::capture(&state));
+
         // Run tick
-        let _tick_result = tick(&mut state, &tick_inputs, &config);
+        let tick_result = state.tick_once(&tick_inputs, &config);
+        if let Some(snapshot) = snapshot {
+            replayed_derived.extend(snapshot.derive_events(&state));
+        }
+        for event in &tick_result.events {
+            if let GameEventData::FormEvolved { player_id, new_form, .. } = &event.data {
+                replayed_evolutions.insert((event.tick, *player_id.as_bytes(), *new_form as u8));
+            }
+        }
+
+        // Roster changes made between this tick and the next
+        apply_backfills(&mut state, transcript);
 
         // Check if we hit a checkpoint
         if checkpoint_idx < transcript.checkpoints.len()
@@ -220,21 +453,142 @@ pub fn verify_transcript(transcript: &MatchTranscript) -> VerificationResult {
 
     // 5. Verify final state
     let final_hash = state.compute_hash();
-    let valid = final_hash == result.final_state_hash;
+    let mut error = if final_hash == result.final_state_hash {
+        None
+    } else {
+        Some(VerificationError::FinalStateMismatch {
+            expected: result.final_state_hash,
+            computed: final_hash,
+        })
+    };
+
+    // 6. Every recorded evolution must have crossed its threshold in replay
+    if error.is_none() {
+        error = transcript.events.iter().find_map(|event| match *event {
+            TranscriptEvent::FormEvolved { tick, player_id, new_form }
+                if !replayed_evolutions.contains(&(tick, player_id, new_form)) =>
+            {
+                Some(VerificationError::UnjustifiedEvolution { tick, player_id, new_form })
+            }
+            _ => None,
+        });
+    }
+
+    // 7. Derived entries must be exactly the ones the replay produces
+    if error.is_none() && derive_events {
+        let recorded: Vec<&TranscriptEvent> =
+            transcript.events.iter().filter(|e| e.is_derived()).collect();
+        error = first_derived_mismatch(&recorded, &replayed_derived)
+            .map(|tick| VerificationError::DerivedEventMismatch { tick });
+    }
 
     VerificationResult {
-        valid,
+        valid: error.is_none(),
         computed_final_hash: final_hash,
         expected_final_hash: result.final_state_hash,
         checkpoint_results,
-        error: if valid {
-            None
-        } else {
-            Some(VerificationError::FinalStateMismatch {
-                expected: result.final_state_hash,
-                computed: final_hash,
-            })
-        },
+        error,
+    }
+}
+
+/// Debug artifact written for a transcript that failed verification.
+#[derive(Serialize)]
+struct VerificationArtifact<'a> {
+    error: Option<String>,
+    computed_final_hash: StateHash,
+    expected_final_hash: StateHash,
+    checkpoint_results: &'a [CheckpointResult],
+    transcript: &'a MatchTranscript,
+}
+
+/// Verify a transcript, persisting it for debugging if verification fails.
+///
+/// When `artifact_dir` is set, a failing transcript is written there as
+/// JSON alongside the verification result. Write errors are logged and do
+/// not affect the returned result.
+pub fn verify_transcript_with_artifacts(
+    transcript: &MatchTranscript,
+    artifact_dir: Option<&Path>,
+) -> VerificationResult {
+    let result = verify_transcript(transcript);
+
+    if !result.valid {
+        if let Some(dir) = artifact_dir {
+            match persist_failure_artifact(dir, transcript, &result) {
+                Ok(path) => tracing::warn!("Verification failed, artifact written to {}", path.display()),
+                Err(e) => tracing::error!("Failed to persist verification artifact: {}", e),
+            }
+        }
+    }
+
+    result
+}
+
+/// Write a failing transcript and its verification result to `dir`.
+///
+/// The file is named after the match ID so repeated failures of the same
+/// match overwrite a single artifact.
+pub fn persist_failure_artifact(
+    dir: &Path,
+    transcript: &MatchTranscript,
+    result: &VerificationResult,
+) -> std::io::Result<PathBuf> {
+    let artifact = VerificationArtifact {
+        error: result.error.as_ref().map(|e| e.to_string()),
+        computed_final_hash: result.computed_final_hash,
+        expected_final_hash: result.expected_final_hash,
+        checkpoint_results: &result.checkpoint_results,
+        transcript,
+    };
+
+    let json = serde_json::to_vec_pretty(&artifact)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!(
+        "verify_failed_{}.json",
+        hex::encode(transcript.metadata.match_id)
+    ));
+    std::fs::write(&path, json)?;
+    Ok(path)
+}
+
+/// Replay verification combined with an optional commit-reveal check.
+#[derive(Debug)]
+pub struct FullVerificationResult {
+    /// Result of replaying the transcript.
+    pub replay: VerificationResult,
+
+    /// Commit-reveal check, if a commitment and reveal were supplied.
+    pub commitment: Option<Result<(), CommitmentError>>,
+}
+
+impl FullVerificationResult {
+    /// Did replay and (when present) the commitment check both pass?
+    pub fn is_valid(&self) -> bool {
+        self.replay.valid && self.commitment.as_ref().is_none_or(|c| c.is_ok())
+    }
+}
+
+/// Verify a transcript by replay and, optionally, against its commitment.
+///
+/// The reveal must carry the same transcript that is being replayed, so a
+/// valid reveal cannot vouch for a different match outcome.
+pub fn verify_match(
+    transcript: &MatchTranscript,
+    reveal: Option<(&MatchCommitment, &MatchReveal)>,
+) -> FullVerificationResult {
+    let commitment = reveal.map(|(commitment, reveal)| {
+        reveal.verify(commitment)?;
+        if reveal.transcript.to_bytes() != transcript.to_bytes() {
+            return Err(CommitmentError::TranscriptMismatch);
+        }
+        Ok(())
+    });
+
+    FullVerificationResult {
+        replay: verify_transcript(transcript),
+        commitment,
     }
 }
 
@@ -331,11 +685,13 @@ impl ProofVerifier for BitSageProofVerifier {
 // =============================================================================
 
 /// Reconstruct initial match state from transcript.
-fn reconstruct_initial_state(transcript: &MatchTranscript) -> MatchState {
-    let mut state = MatchState::new(
+fn reconstruct_initial_state(transcript: &MatchTranscript, config: &MatchConfig) -> MatchState {
+    let mut state = MatchState::with_start_tick(
         transcript.metadata.match_id,
         transcript.metadata.rng_seed,
+        transcript.initial_state.start_tick,
     );
+    prepare_match(&mut state, config);
 
     // Set RNG state from initial snapshot
     state.rng.set_state(transcript.initial_state.rng_state);
@@ -344,7 +700,11 @@ fn reconstruct_initial_state(transcript: &MatchTranscript) -> MatchState {
     for player in &transcript.initial_state.players {
         let player_id = PlayerId::new(player.player_id);
         let mut player_state = PlayerState::new(player_id, player.position);
-        player_state.form = Form::from_index(player.form).unwrap_or_default();
+        player_state.set_tier(player.form, &state.form_table);
+        player_state.score = player.score;
+        player_state.spawn_zone_id = player.spawn_zone;
+        player_state.spawn_zone_active = player.spawn_zone.is_some();
+        player_state.team = player.team;
         state.players.insert(player_id, player_state);
         state.alive_count += 1;
     }
@@ -352,6 +712,45 @@ fn reconstruct_initial_state(transcript: &MatchTranscript) -> MatchState {
     state
 }
 
+/// Add players recorded as joining right after the current tick.
+fn apply_backfills(state: &mut MatchState, transcript: &MatchTranscript) {
+    for event in &transcript.events {
+        if let TranscriptEvent::PlayerJoined { tick, player_id } = event {
+            if *tick == state.tick {
+                state.backfill_player(PlayerId::new(*player_id));
+            }
+        }
+    }
+}
+
+/// Check every player's input record before replay.
+///
+/// `input_count` must equal the number of deltas and delta ticks must be
+/// strictly increasing.
+pub fn validate_input_buffers(transcript: &MatchTranscript) -> Result<(), VerificationError> {
+    for record in &transcript.player_inputs {
+        let count_matches = record.input_count as usize == record.deltas.len();
+        let monotonic = record.deltas.windows(2).all(|pair| pair[0].tick < pair[1].tick);
+        if !count_matches || !monotonic {
+            return Err(VerificationError::InvalidInputBuffer { player_id: record.player_id });
+        }
+    }
+    Ok(())
+}
+
+/// Tick of the first disagreement between recorded and replayed entries.
+fn first_derived_mismatch(recorded: &[&TranscriptEvent], replayed: &[TranscriptEvent]) -> Option<u32> {
+    (0..recorded.len().max(replayed.len())).find_map(|i| {
+        match (recorded.get(i), replayed.get(i)) {
+            (Some(a), Some(b)) if *a == b => None,
+            (Some(a), Some(b)) => Some(a.tick().min(b.tick())),
+            (Some(e), None) => Some(e.tick()),
+            (None, Some(e)) => Some(e.tick()),
+            (None, None) => None,
+        }
+    })
+}
+
 /// Build input lookup from transcript records.
 fn build_input_lookup(transcript: &MatchTranscript) -> BTreeMap<PlayerId, Vec<(u32, InputFrame)>> {
     let mut lookup = BTreeMap::new();
@@ -408,17 +807,21 @@ mod tests {
             player_ids: vec![[3; 16]],
             rng_seed: 12345,
             start_timestamp: 1700000000,
-            config_hash: [4; 32],
+            config_hash: MatchConfig::default().config_hash(),
         };
 
         let mut transcript = MatchTranscript::new(metadata);
 
         // Set initial state
         transcript.initial_state = InitialMatchState {
+            start_tick: 0,
             players: vec![InitialPlayerState {
                 player_id: [3; 16],
                 position: FixedVec2::ZERO,
                 form: 0,
+                score: 0,
+                spawn_zone: None,
+                team: None,
             }],
             rng_state: [100, 200],
             state_hash: [0; 32], // Will need to compute
@@ -444,6 +847,150 @@ mod tests {
         assert!(matches!(result.error, Some(VerificationError::IncompleteTranscript)));
     }
 
+    #[test]
+    fn test_score_above_bound_rejected() {
+        let mut transcript = create_minimal_transcript();
+        transcript.finalize(MatchResult {
+            end_tick: 1,
+            winner_id: Some([3; 16]),
+            placements: vec![([3; 16], 1, u32::MAX)],
+            final_state_hash: [0; 32],
+        });
+
+        let result = verify_transcript(&transcript);
+        assert!(!result.valid);
+        assert!(matches!(
+            result.error,
+            Some(VerificationError::ScoreOutOfRange { score: u32::MAX })
+        ));
+    }
+
+    #[test]
+    fn test_input_buffer_validation() {
+        let mut transcript = create_minimal_transcript();
+        transcript.player_inputs[0] = PlayerInputRecord {
+            player_id: [3; 16],
+            deltas: vec![
+                InputDelta::new(0, InputFrame::new()),
+                InputDelta::new(5, InputFrame::with_movement(10, 0)),
+                InputDelta::new(9, InputFrame::new()),
+            ],
+            input_count: 3,
+        };
+        assert!(validate_input_buffers(&transcript).is_ok());
+
+        let finalize = |mut transcript: MatchTranscript| {
+            transcript.finalize(MatchResult {
+                end_tick: 1,
+                winner_id: Some([3; 16]),
+                placements: vec![([3; 16], 1, 0)],
+                final_state_hash: [0; 32],
+            });
+            verify_transcript(&transcript)
+        };
+
+        // Count disagrees with the deltas
+        let mut mismatched = transcript.clone();
+        mismatched.player_inputs[0].input_count = 4;
+        let result = finalize(mismatched);
+        assert!(!result.valid);
+        assert!(matches!(
+            result.error,
+            Some(VerificationError::InvalidInputBuffer { player_id }) if player_id == [3; 16]
+        ));
+
+        // Ticks go backwards
+        let mut reordered = transcript.clone();
+        reordered.player_inputs[0].deltas[2].tick = 5;
+        assert!(validate_input_buffers(&reordered).is_err());
+        let result = finalize(reordered);
+        assert!(matches!(
+            result.error,
+            Some(VerificationError::InvalidInputBuffer { player_id }) if player_id == [3; 16]
+        ));
+    }
+
+    #[test]
+    fn test_malformed_transcript_fails_before_replay() {
+        let mut transcript = create_minimal_transcript();
+        transcript.player_inputs.clear();
+        transcript.finalize(MatchResult {
+            end_tick: 1,
+            winner_id: Some([3; 16]),
+            placements: vec![([3; 16], 1, 0)],
+            final_state_hash: [0; 32],
+        });
+
+        let result = verify_transcript(&transcript);
+        assert!(!result.valid);
+        assert!(matches!(
+            result.error,
+            Some(VerificationError::Integrity(IntegrityError::MissingInputRecord { player_id }))
+                if player_id == [3; 16]
+        ));
+    }
+
+    #[test]
+    fn test_absurd_end_tick_rejected() {
+        let mut transcript = create_minimal_transcript();
+        transcript.finalize(MatchResult {
+            end_tick: u32::MAX,
+            winner_id: Some([3; 16]),
+            placements: vec![([3; 16], 1, 0)],
+            final_state_hash: [5; 32],
+        });
+
+        let start = std::time::Instant::now();
+        let result = verify_transcript(&transcript);
+
+        assert!(!result.valid);
+        assert!(matches!(
+            result.error,
+            Some(VerificationError::TooLong { end_tick: u32::MAX, max_ticks: MAX_VERIFIABLE_TICKS })
+        ));
+        // No replay should have happened
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_invalid_start_tick_rejected() {
+        let config = MatchConfig::default();
+        let verify_from = |start_tick: u32, end_tick: u32| {
+            let mut transcript = create_minimal_transcript();
+            transcript.initial_state.start_tick = start_tick;
+            // Self-consistent initial hash, so only the tick range is wrong
+            transcript.initial_state.state_hash =
+                reconstruct_initial_state(&transcript, &config).compute_hash();
+            transcript.finalize(MatchResult {
+                end_tick,
+                winner_id: Some([3; 16]),
+                placements: vec![([3; 16], 1, 0)],
+                final_state_hash: [5; 32],
+            });
+            verify_transcript(&transcript).error
+        };
+
+        // Would overflow (or wrap to a four-billion-tick replay) otherwise
+        assert!(matches!(
+            verify_from(u32::MAX, u32::MAX),
+            Some(VerificationError::InvalidStartTick { start_tick: u32::MAX, end_tick: u32::MAX })
+        ));
+        let late = u32::MAX - MAX_VERIFIABLE_TICKS + 1;
+        assert!(matches!(
+            verify_from(late, late + 1),
+            Some(VerificationError::InvalidStartTick { .. })
+        ));
+        assert!(matches!(verify_from(50, 50), Some(VerificationError::InvalidStartTick { .. })));
+        assert!(matches!(verify_from(50, 10), Some(VerificationError::InvalidStartTick { .. })));
+
+        // The latest start that still fits is replayed normally
+        let latest = u32::MAX - MAX_VERIFIABLE_TICKS;
+        assert!(!matches!(
+            verify_from(latest, latest + 1),
+            Some(VerificationError::InvalidStartTick { .. } | VerificationError::TooLong { .. })
+        ));
+    }
+
     #[test]
     fn test_export_public_inputs() {
         let mut transcript = create_minimal_transcript();
@@ -460,6 +1007,302 @@ mod tests {
         assert_eq!(inputs.duration_ticks, 100);
     }
 
+    #[test]
+    fn test_failed_verification_writes_artifact() {
+        let mut transcript = create_minimal_transcript();
+        transcript.initial_state.state_hash = reconstruct_initial_state(&transcript, &MatchConfig::default()).compute_hash();
+        // Corrupt: bogus checkpoint hash at the first tick
+        transcript.add_checkpoint(1, [9; 32], [0, 0]);
+        transcript.finalize(MatchResult {
+            end_tick: 10,
+            winner_id: Some([3; 16]),
+            placements: vec![([3; 16], 1, 0)],
+            final_state_hash: [5; 32],
+        });
+
+        let dir = std::env::temp_dir().join(format!("rune_relic_verify_{}", std::process::id()));
+        let result = verify_transcript_with_artifacts(&transcript, Some(&dir));
+        assert!(!result.valid);
+
+        let path = dir.join(format!("verify_failed_{}.json", hex::encode([1u8; 16])));
+        let contents = std::fs::read_to_string(&path).expect("artifact should be written");
+        let json: serde_json::Value = serde_json::from_str(&contents).unwrap();
+
+        assert_eq!(json["error"], "Checkpoint mismatch at tick 1");
+        let checkpoints = json["checkpoint_results"].as_array().unwrap();
+        assert_eq!(checkpoints.len(), 1);
+        assert_eq!(checkpoints[0]["valid"], false);
+        assert!(json["transcript"].is_object());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// Finalize `transcript` by replaying it under `config`.
+    fn record_under(transcript: &mut MatchTranscript, config: &MatchConfig, end_tick: u32) {
+        let mut state = reconstruct_initial_state(transcript, config);
+        transcript.initial_state.state_hash = state.compute_hash();
+        start_playing(&mut state, config);
+        let lookup = build_input_lookup(transcript);
+        for tick_num in 1..=end_tick {
+            state.tick_once(&get_inputs_at_tick(&lookup, tick_num), config);
+        }
+        transcript.finalize(MatchResult {
+            end_tick,
+            winner_id: Some([3; 16]),
+            placements: vec![([3; 16], 1, 0)],
+            final_state_hash: state.compute_hash(),
+        });
+    }
+
+    #[test]
+    fn test_unjustified_evolution_fails_verification() {
+        let mut transcript = create_minimal_transcript();
+        record_under(&mut transcript, &MatchConfig::default(), 10);
+        assert!(verify_transcript(&transcript).valid);
+
+        // Claim the idle player jumped straight to Ancient
+        transcript.events.push(TranscriptEvent::FormEvolved {
+            tick: 5,
+            player_id: [3; 16],
+            new_form: crate::game::state::Form::Ancient as u8,
+        });
+        let result = verify_transcript(&transcript);
+        assert!(!result.valid);
+        assert!(matches!(
+            result.error,
+            Some(VerificationError::UnjustifiedEvolution { tick: 5, .. })
+        ));
+    }
+
+    /// Add a second, idle player far away so the match keeps running.
+    fn add_idle_rival(transcript: &mut MatchTranscript) {
+        transcript.metadata.player_ids.push([5; 16]);
+        transcript.initial_state.players.push(InitialPlayerState {
+            player_id: [5; 16],
+            position: FixedVec2::new(-100 * 65536, 0),
+            form: 0,
+            score: 0,
+            spawn_zone: None,
+            team: None,
+        });
+        transcript.player_inputs.push(PlayerInputRecord {
+            player_id: [5; 16],
+            deltas: vec![],
+            input_count: 0,
+        });
+    }
+
+    #[test]
+    fn test_match_starting_at_tick_1000_runs_full_duration_and_verifies() {
+        const START_TICK: u32 = 1000;
+        let config = MatchConfig::default();
+        let mut transcript = create_minimal_transcript();
+        add_idle_rival(&mut transcript);
+        transcript.initial_state.start_tick = START_TICK;
+
+        let mut state = reconstruct_initial_state(&transcript, &config);
+        transcript.initial_state.state_hash = state.compute_hash();
+        start_playing(&mut state, &config);
+        let lookup = build_input_lookup(&transcript);
+        while state.phase != crate::game::state::MatchPhase::Ended {
+            let next_tick = state.tick + 1;
+            state.tick_once(&get_inputs_at_tick(&lookup, next_tick), &config);
+        }
+        assert_eq!(state.tick, START_TICK + MATCH_DURATION_TICKS);
+        assert_eq!(state.elapsed_ticks(), MATCH_DURATION_TICKS);
+
+        transcript.finalize(MatchResult {
+            end_tick: state.tick,
+            winner_id: None,
+            placements: vec![],
+            final_state_hash: state.compute_hash(),
+        });
+        let result = verify_transcript(&transcript);
+        assert!(result.valid, "{:?}", result.error);
+        assert_eq!(export_public_inputs(&transcript).duration_ticks, MATCH_DURATION_TICKS);
+
+        // Counted from zero the same match runs past the duration limit
+        transcript.initial_state.start_tick = 0;
+        assert!(matches!(
+            verify_transcript(&transcript).error,
+            Some(VerificationError::TooLong { end_tick, .. }) if end_tick == START_TICK + MATCH_DURATION_TICKS
+        ));
+    }
+
+    #[test]
+    fn test_derived_events_are_reproduced_on_replay() {
+        const END_TICK: u32 = 600;
+        let config = MatchConfig::default();
+        let mut transcript = create_minimal_transcript();
+        // Run through the rune field picking up buffs
+        transcript.player_inputs[0].deltas = vec![InputDelta::new(0, InputFrame::with_movement(127, 40))];
+        add_idle_rival(&mut transcript);
+        let mut state = reconstruct_initial_state(&transcript, &config);
+        transcript.initial_state.state_hash = state.compute_hash();
+        start_playing(&mut state, &config);
+
+        // Record like a session with `derive_state_events` set
+        let lookup = build_input_lookup(&transcript);
+        for tick_num in 1..=END_TICK {
+            let snapshot = StateSnapshot::capture(&state);
+            state.tick_once(&get_inputs_at_tick(&lookup, tick_num), &config);
+            transcript.events.extend(snapshot.derive_events(&state));
+        }
+        assert!(transcript.events.iter().any(TranscriptEvent::is_derived));
+        transcript.finalize(MatchResult {
+            end_tick: END_TICK,
+            winner_id: Some([3; 16]),
+            placements: vec![([3; 16], 1, 0)],
+            final_state_hash: state.compute_hash(),
+        });
+        let result = verify_transcript(&transcript);
+        assert!(result.valid, "{:?}", result.error);
+
+        // An invented buff loss is caught
+        transcript.events.push(TranscriptEvent::BuffLost { tick: END_TICK, player_id: [3; 16], buff: 99 });
+        let result = verify_transcript(&transcript);
+        assert!(matches!(
+            result.error,
+            Some(VerificationError::DerivedEventMismatch { tick: END_TICK })
+        ));
+    }
+
+    #[test]
+    fn test_backfilled_player_is_replayed() {
+        const END_TICK: u32 = 20;
+        let config = MatchConfig::default();
+        let late = PlayerId::new([9; 16]);
+
+        let mut transcript = create_minimal_transcript();
+        let mut state = reconstruct_initial_state(&transcript, &config);
+        transcript.initial_state.state_hash = state.compute_hash();
+        start_playing(&mut state, &config);
+
+        // Late joiner arrives before the first tick is simulated
+        assert!(state.backfill_player(late));
+        let lookup = build_input_lookup(&transcript);
+        for tick_num in 1..=END_TICK {
+            let result = state.tick_once(&get_inputs_at_tick(&lookup, tick_num), &config);
+            for event in &result.events {
+                transcript.record_event(event);
+            }
+        }
+        assert!(state.players[&late].alive);
+        assert_eq!(state.tick, END_TICK, "two players keep the match running");
+
+        transcript.finalize(MatchResult {
+            end_tick: END_TICK,
+            winner_id: None,
+            placements: vec![],
+            final_state_hash: state.compute_hash(),
+        });
+        let result = verify_transcript(&transcript);
+        assert!(result.valid, "{:?}", result.error);
+
+        // Dropping the roster change breaks verification
+        transcript.events.retain(|e| !matches!(e, TranscriptEvent::PlayerJoined { .. }));
+        assert!(!verify_transcript(&transcript).valid);
+    }
+
+    #[test]
+    fn test_old_config_version_still_verifies() {
+        // A verifier that knows the stock versions and a retuned v3
+        let resolve = |version: u32| match version {
+            3 => Some(MatchConfig {
+                rune_spawn: crate::game::rune::RuneSpawnConfig {
+                    initial_spawn_count: 3000,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            version => MatchConfig::for_version(version),
+        };
+
+        let mut transcript = create_minimal_transcript();
+        transcript.config_version = 1;
+        transcript.metadata.config_hash = resolve(1).unwrap().config_hash();
+        record_under(&mut transcript, &resolve(1).unwrap(), 1);
+
+        let result = verify_transcript_with_configs(&transcript, resolve);
+        assert!(result.valid, "{:?}", result.error);
+
+        // Claiming v3 rules conflicts with the committed config hash...
+        transcript.config_version = 3;
+        let result = verify_transcript_with_configs(&transcript, resolve);
+        assert!(matches!(result.error, Some(VerificationError::ConfigHashMismatch { .. })));
+
+        // ...and even with a matching commitment the replay diverges
+        transcript.metadata.config_hash = resolve(3).unwrap().config_hash();
+        let result = verify_transcript_with_configs(&transcript, resolve);
+        assert!(matches!(result.error, Some(VerificationError::FinalStateMismatch { .. })));
+    }
+
+    #[test]
+    fn test_embedded_config_is_replayed() {
+        let config = MatchConfig {
+            rune_spawn: crate::game::rune::RuneSpawnConfig {
+                initial_spawn_count: 3000,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Without the config the stock rules cannot reproduce the match
+        let mut transcript = create_minimal_transcript();
+        transcript.metadata.config_hash = config.config_hash();
+        record_under(&mut transcript, &config, 10);
+        assert!(matches!(
+            verify_transcript(&transcript).error,
+            Some(VerificationError::ConfigHashMismatch { .. })
+        ));
+
+        transcript.set_config(&config);
+        let result = verify_transcript(&transcript);
+        assert!(result.valid, "{:?}", result.error);
+
+        // The embedded config must be the committed one
+        transcript.metadata.config_hash = MatchConfig::default().config_hash();
+        assert!(matches!(
+            verify_transcript(&transcript).error,
+            Some(VerificationError::ConfigHashMismatch { .. })
+        ));
+
+        transcript.config = vec![0xFF; 3];
+        assert!(matches!(verify_transcript(&transcript).error, Some(VerificationError::InvalidConfig)));
+    }
+
+    #[test]
+    fn test_older_embedded_config_still_verifies() {
+        // Embedded by a build that predates milestones: the field is absent
+        let embedded = br#"{"rune_spawn":{"initial_spawn_count":3000}}"#.to_vec();
+        let config = MatchConfig::from_config_bytes(&embedded).unwrap();
+        assert!(!config.milestones_enabled);
+        assert_eq!(config.rune_spawn.initial_spawn_count, 3000);
+        assert_eq!(config.config_bytes(), embedded);
+
+        let mut transcript = create_minimal_transcript();
+        transcript.config = embedded;
+        transcript.metadata.config_hash = config.config_hash();
+        record_under(&mut transcript, &config, 10);
+
+        let result = verify_transcript(&transcript);
+        assert!(result.valid, "{:?}", result.error);
+    }
+
+    #[test]
+    fn test_unknown_config_version_rejected() {
+        let mut transcript = create_minimal_transcript();
+        record_under(&mut transcript, &MatchConfig::default(), 10);
+        transcript.config_version = 99;
+
+        let result = verify_transcript(&transcript);
+        assert!(!result.valid);
+        assert!(matches!(
+            result.error,
+            Some(VerificationError::UnknownConfigVersion { version: 99 })
+        ));
+    }
+
     #[test]
     fn test_stub_verifier() {
         let verifier = StubProofVerifier;
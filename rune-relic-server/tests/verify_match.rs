@@ -0,0 +1,147 @@
+//! Exit-code tests for the `verify-match` binary.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use rune_relic::core::rng::derive_match_seed;
+use rune_relic::game::tick::{start_playing, MatchConfig};
+use rune_relic::proof::commitment::CommitmentBuilder;
+use rune_relic::proof::transcript::{MatchResult, PlayerInputRecord};
+use rune_relic::proof::{
+    InitialMatchState, InitialPlayerState, MatchMetadata, MatchReveal, MatchTranscript,
+};
+use rune_relic::{FixedVec2, InputDelta, InputFrame, MatchState, PlayerId, PlayerState, FIXED_ONE};
+
+const MATCH_ID: [u8; 16] = [7; 16];
+const BLOCK_HASH: [u8; 32] = [9; 32];
+const END_TICK: u32 = 30;
+
+/// Record a short two-player match with idle inputs.
+fn record_transcript() -> MatchTranscript {
+    let player_ids = vec![[1; 16], [2; 16]];
+    let seed = derive_match_seed(&BLOCK_HASH, &MATCH_ID, &player_ids);
+    let mut transcript = MatchTranscript::new(MatchMetadata {
+        match_id: MATCH_ID,
+        block_hash: BLOCK_HASH,
+        player_ids: player_ids.clone(),
+        rng_seed: seed,
+        start_timestamp: 1_700_000_000,
+        config_hash: MatchConfig::default().config_hash(),
+    });
+
+    let mut state = MatchState::new(MATCH_ID, seed);
+    let mut initial_players = Vec::new();
+    for (i, raw_id) in player_ids.iter().enumerate() {
+        let position = FixedVec2::new((i as i32 * 20 - 10) * FIXED_ONE, 0);
+        let id = PlayerId::new(*raw_id);
+        state.players.insert(id, PlayerState::new(id, position));
+        state.alive_count += 1;
+        initial_players.push(InitialPlayerState { player_id: *raw_id, position, form: 0, score: 0, spawn_zone: None, team: None });
+        transcript.add_player_inputs(PlayerInputRecord {
+            player_id: *raw_id,
+            deltas: vec![InputDelta::new(0, InputFrame::new())],
+            input_count: 1,
+        });
+    }
+    transcript.set_initial_state(InitialMatchState {
+        start_tick: 0,
+        players: initial_players,
+        rng_state: state.rng.state(),
+        state_hash: state.compute_hash(),
+    });
+
+    let config = MatchConfig::default();
+    start_playing(&mut state, &config);
+    let idle: BTreeMap<PlayerId, InputFrame> =
+        state.players.keys().map(|id| (*id, InputFrame::new())).collect();
+    for _ in 0..END_TICK {
+        state.tick_once(&idle, &config);
+    }
+
+    transcript.finalize(MatchResult {
+        end_tick: END_TICK,
+        winner_id: None,
+        placements: vec![],
+        final_state_hash: state.compute_hash(),
+    });
+    transcript
+}
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("verify_match_{}_{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn run(args: &[&Path]) -> Option<i32> {
+    Command::new(env!("CARGO_BIN_EXE_verify-match"))
+        .args(args)
+        .output()
+        .expect("failed to run verify-match")
+        .status
+        .code()
+}
+
+#[test]
+fn test_recorded_transcript_verifies() {
+    let dir = scratch_dir("valid");
+    let bincode_path = dir.join("match.bin");
+    let json_path = dir.join("match.json");
+    let transcript = record_transcript();
+    std::fs::write(&bincode_path, transcript.to_bytes()).unwrap();
+    std::fs::write(&json_path, transcript.to_portable_json().to_string()).unwrap();
+
+    assert_eq!(run(&[&bincode_path]), Some(0));
+    assert_eq!(run(&[&json_path]), Some(0));
+}
+
+#[test]
+fn test_tampered_transcript_fails() {
+    let dir = scratch_dir("tampered");
+    let path = dir.join("match.bin");
+    let mut transcript = record_transcript();
+    transcript.result.as_mut().unwrap().final_state_hash[0] ^= 0xFF;
+    std::fs::write(&path, transcript.to_bytes()).unwrap();
+
+    assert_eq!(run(&[&path]), Some(1));
+}
+
+#[test]
+fn test_commitment_is_checked() {
+    let dir = scratch_dir("commitment");
+    let transcript_path = dir.join("match.bin");
+    let commitment_path = dir.join("commitment.json");
+    let reveal_path = dir.join("reveal.json");
+
+    let transcript = record_transcript();
+    let (preimage, commitment) = CommitmentBuilder::new(MATCH_ID)
+        .add_player([1; 16], [3; 32])
+        .add_player([2; 16], [4; 32])
+        .build(100, 200);
+    let reveal = MatchReveal::new(preimage, BLOCK_HASH, 150, transcript.clone());
+    std::fs::write(&transcript_path, transcript.to_bytes()).unwrap();
+    std::fs::write(&commitment_path, serde_json::to_vec(&commitment).unwrap()).unwrap();
+    std::fs::write(&reveal_path, serde_json::to_vec(&reveal).unwrap()).unwrap();
+
+    assert_eq!(run(&[&transcript_path, &commitment_path, &reveal_path]), Some(0));
+
+    // The reveal must carry the transcript being verified
+    let mut other = transcript.clone();
+    other.metadata.start_timestamp += 1;
+    let swapped = MatchReveal { transcript: other, ..reveal.clone() };
+    std::fs::write(&reveal_path, serde_json::to_vec(&swapped).unwrap()).unwrap();
+    assert_eq!(run(&[&transcript_path, &commitment_path, &reveal_path]), Some(1));
+
+    // A reveal from outside the committed block range is rejected
+    let late = MatchReveal { block_height: 500, ..reveal };
+    std::fs::write(&reveal_path, serde_json::to_vec(&late).unwrap()).unwrap();
+    assert_eq!(run(&[&transcript_path, &commitment_path, &reveal_path]), Some(1));
+}
+
+#[test]
+fn test_unreadable_input_is_a_usage_error() {
+    let dir = scratch_dir("missing");
+    assert_eq!(run(&[&dir.join("does_not_exist.bin")]), Some(2));
+    assert_eq!(run(&[]), Some(2));
+}